@@ -0,0 +1,48 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Compare deserializing `[u64; N]` on the stack with `serde_arrays::boxed`
+//!
+//!     cargo bench --bench boxed
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Stack<const N: usize>(#[serde(with = "serde_arrays")] [u64; N]);
+
+#[derive(Deserialize)]
+struct Heap<const N: usize>(#[serde(with = "serde_arrays::boxed")] Box<[u64; N]>);
+
+fn bench_size<const N: usize>(c: &mut Criterion) {
+    let data = bincode::serialize(&vec![7u64; N]).unwrap();
+    // `Vec` is prefixed with its length, which a fixed-size array is not
+    let data = &data[8..];
+
+    let mut group = c.benchmark_group("bincode");
+    group.bench_with_input(BenchmarkId::new("stack", N), data, |b, data| {
+        b.iter(|| {
+            bincode::deserialize::<Box<Stack<N>>>(black_box(data))
+                .unwrap()
+                .0
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("boxed", N), data, |b, data| {
+        b.iter(|| bincode::deserialize::<Heap<N>>(black_box(data)).unwrap().0)
+    });
+    group.finish();
+}
+
+fn boxed(c: &mut Criterion) {
+    bench_size::<16>(c);
+    bench_size::<256>(c);
+    bench_size::<4096>(c);
+    bench_size::<65536>(c);
+}
+
+criterion_group!(benches, boxed);
+criterion_main!(benches);
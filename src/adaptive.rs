@@ -0,0 +1,132 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize arrays bare for human-readable formats, but length-prefixed for binary ones
+//!
+//! Human-readable formats such as JSON get a plain array, exactly as with
+//! [`serde_arrays`](crate). Binary formats such as bincode instead get a struct with the fields
+//! `len` and `elements`, so the encoded data carries explicit framing that is checked against `N`
+//! when decoding. The same `with` path handles both:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Packet {
+//!     #[serde(with = "serde_arrays::adaptive")]
+//!     payload: [u8; 40],
+//! }
+//! # let data = Packet { payload: [7; 40] };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{field::FieldSeed, wrapper::ArrayWrap, ArraySeed};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+const FIELDS: &[&str] = &["len", "elements"];
+
+/// Serialize an array, bare or as a `len`/`elements` struct depending on the format
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    if ser.is_human_readable() {
+        return crate::serialize(data, ser);
+    }
+
+    let mut s = ser.serialize_struct("Array", 2)?;
+    s.serialize_field("len", &(N as u64))?;
+    s.serialize_field("elements", &ArrayWrap::new(data))?;
+    s.end()
+}
+
+/// Deserialize an array, bare or from a `len`/`elements` struct depending on the format
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    if deserializer.is_human_readable() {
+        return crate::deserialize(deserializer);
+    }
+
+    deserializer.deserialize_struct(
+        "Array",
+        FIELDS,
+        FramedVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for length-prefixed [T; N] arrays
+struct FramedVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for FramedVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a length-prefixed array of size {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let len = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        check_len::<_, N>(len, &self)?;
+        seq.next_element_seed(ArraySeed::<T, N>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut len = None;
+        let mut elements = None;
+        while let Some(key) = map.next_key_seed(FieldSeed(FIELDS))? {
+            match key.map(|idx| FIELDS[idx]) {
+                Some("len") if len.is_some() => return Err(de::Error::duplicate_field("len")),
+                Some("len") => len = Some(map.next_value()?),
+                Some("elements") if elements.is_some() => {
+                    return Err(de::Error::duplicate_field("elements"))
+                }
+                Some("elements") => elements = Some(map.next_value_seed(ArraySeed::<T, N>::new())?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let len = len.ok_or_else(|| de::Error::missing_field("len"))?;
+        check_len::<_, N>(len, &self)?;
+        elements.ok_or_else(|| de::Error::missing_field("elements"))
+    }
+}
+
+/// Check the decoded length prefix against the array's length `N`
+fn check_len<E: de::Error, const N: usize>(len: u64, expected: &dyn de::Expected) -> Result<(), E> {
+    if len == N as u64 {
+        Ok(())
+    } else {
+        Err(de::Error::invalid_length(len as usize, expected))
+    }
+}
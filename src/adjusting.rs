@@ -0,0 +1,119 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Leniently deserialize arrays of any length, reporting how the input was adjusted
+//!
+//! Short inputs are padded with `T::default()` and long inputs are truncated, and a callback is
+//! invoked exactly once per array with the [`Adjustment`] that was made, e.g. for logging. Since
+//! Serde's `deserialize_with` attribute only accepts a path, wrap [`deserialize`] in a function
+//! that supplies your callback:
+//!
+//! ```
+//! use serde::{Deserialize, Deserializer};
+//! use serde_arrays::adjusting::Adjustment;
+//!
+//! fn log_adjustment(adjustment: Adjustment) {
+//!     if adjustment != Adjustment::Exact {
+//!         eprintln!("readings were adjusted: {:?}", adjustment);
+//!     }
+//! }
+//!
+//! fn lenient<'de, D: Deserializer<'de>>(de: D) -> Result<[u32; 4], D::Error> {
+//!     serde_arrays::adjusting::deserialize(de, log_adjustment)
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Sensor {
+//!     #[serde(deserialize_with = "lenient")]
+//!     readings: [u32; 4],
+//! }
+//!
+//! let sensor: Sensor = serde_json::from_str(r#"{"readings":[1,2]}"#)?;
+//! assert_eq!(sensor.readings, [1, 2, 0, 0]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::{fmt, marker::PhantomData};
+use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+
+/// How an input sequence was adjusted to fit the array
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjustment {
+    /// The input was too short; this many default elements were appended
+    Padded(usize),
+    /// The input was too long; this many trailing elements were discarded
+    Truncated(usize),
+    /// The input was exactly the right length
+    Exact,
+}
+
+/// Deserialize a sequence of any length into `[T; N]`, padding or truncating as needed
+///
+/// `on_adjust` is called once, after the whole sequence has been read successfully.
+pub fn deserialize<'de, D, T, const N: usize>(
+    deserializer: D,
+    on_adjust: fn(Adjustment),
+) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    deserializer.deserialize_seq(AdjustingVisitor::<T, N> {
+        on_adjust,
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays that pads or truncates its input
+struct AdjustingVisitor<T, const N: usize> {
+    on_adjust: fn(Adjustment),
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for AdjustingVisitor<T, N>
+where
+    T: Deserialize<'de> + Default,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of up to {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut padded = 0;
+        let arr = crate::try_build_array(|_| {
+            if padded == 0 {
+                if let Some(val) = seq.next_element()? {
+                    return Ok(val);
+                }
+            }
+            padded += 1;
+            Ok::<_, A::Error>(T::default())
+        })?;
+
+        let mut truncated = 0;
+        if padded == 0 {
+            while seq.next_element::<IgnoredAny>()?.is_some() {
+                truncated += 1;
+            }
+        }
+
+        (self.on_adjust)(if padded > 0 {
+            Adjustment::Padded(padded)
+        } else if truncated > 0 {
+            Adjustment::Truncated(truncated)
+        } else {
+            Adjustment::Exact
+        });
+
+        Ok(arr)
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::ops::{Deref, DerefMut};
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+/// An array that implements `Serialize` and `Deserialize` itself, for any size `N`
+///
+/// The `with` attribute only applies to a field as a whole, so it can't reach arrays nested inside
+/// other types such as `Option`, `Vec`, or map values. `Arr` needs no attribute at all and can be
+/// used anywhere Serde accepts a type, while dereferencing to the `[T; N]` it wraps:
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrays::Arr;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Packets {
+///     packets: Vec<Arr<u8, 64>>,
+///     checksum: Option<Arr<u32, 40>>,
+/// }
+///
+/// let packets = Packets {
+///     packets: vec![Arr([1; 64]), [2; 64].into()],
+///     checksum: None,
+/// };
+/// assert_eq!(packets.packets[1][0], 2);
+/// # let json = serde_json::to_string(&packets)?;
+/// # assert_eq!(packets, serde_json::from_str(&json)?);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// It is serialized exactly as [`serde_arrays`](crate) serializes a `[T; N]` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Arr<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> Arr<T, N> {
+    /// Length of the array, usable in const contexts without a value
+    pub const LEN: usize = N;
+
+    /// Length of the array
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Whether the array is empty, i.e. `N` is 0
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Unwrap the array
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T: Default, const N: usize> Default for Arr<T, N> {
+    fn default() -> Self {
+        Arr(crate::default_array())
+    }
+}
+
+impl<T, const N: usize> Deref for Arr<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for Arr<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for Arr<T, N> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for Arr<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Arr<T, N> {
+    fn from(arr: [T; N]) -> Self {
+        Arr(arr)
+    }
+}
+
+impl<T, const N: usize> From<Arr<T, N>> for [T; N] {
+    fn from(arr: Arr<T, N>) -> Self {
+        arr.0
+    }
+}
+
+impl<T: Serialize, const N: usize> Serialize for Arr<T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Arr<T, N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::deserialize(deserializer).map(Arr)
+    }
+}
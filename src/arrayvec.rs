@@ -0,0 +1,96 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize partially-filled fixed-capacity buffers, using [`arrayvec`]
+//!
+//! Requires the `arrayvec` feature. An [`ArrayVec<T, N>`] is written as a sequence of however
+//! many elements it currently holds, and read back from a sequence of up to `N` elements:
+//!
+//! ```
+//! use arrayvec::ArrayVec;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Buffer {
+//!     #[serde(with = "serde_arrays::arrayvec")]
+//!     samples: ArrayVec<u32, 64>,
+//! }
+//!
+//! let buffer: Buffer = serde_json::from_str(r#"{"samples":[1,2,3]}"#)?;
+//! assert_eq!(buffer.samples.as_slice(), &[1, 2, 3]);
+//! # assert_eq!(serde_json::to_string(&buffer)?, r#"{"samples":[1,2,3]}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`arrayvec`]: https://docs.rs/arrayvec
+//! [`ArrayVec<T, N>`]: ::arrayvec::ArrayVec
+
+use ::arrayvec::ArrayVec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+/// Serialize an `ArrayVec` as a sequence of its current elements
+pub fn serialize<S, T, const N: usize>(data: &ArrayVec<T, N>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_seq(Some(data.len()))?;
+    for item in data {
+        s.serialize_element(item)?;
+    }
+    s.end()
+}
+
+/// Deserialize an `ArrayVec` from a sequence of at most `N` elements
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<ArrayVec<T, N>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(ArrayVecVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for ArrayVec<T, N>
+struct ArrayVecVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for ArrayVecVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ArrayVec<T, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of at most {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut data = ArrayVec::new();
+        while !data.is_full() {
+            match seq.next_element()? {
+                Some(item) => data.push(item),
+                None => return Ok(data),
+            }
+        }
+
+        // Make sure there aren't any elements left over
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(crate::too_long(seq, N, &self));
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::ser::{Serialize, Serializer};
+
+/// Serialize anything that can be borrowed as a `[T; N]`, such as a newtype around an array
+///
+/// The output is identical to that of [`serialize`](crate::serialize) for the array itself. A
+/// blanket [`Serializable`](crate::Serializable) impl for `AsRef` types would conflict with the
+/// existing ones, so this is a separate function, usable with `serialize_with`:
+///
+/// ```
+/// use serde::Serialize;
+///
+/// struct Key([u8; 32]);
+///
+/// impl AsRef<[u8; 32]> for Key {
+///     fn as_ref(&self) -> &[u8; 32] {
+///         &self.0
+///     }
+/// }
+///
+/// #[derive(Serialize)]
+/// struct Session {
+///     #[serde(serialize_with = "serde_arrays::serialize_ref")]
+///     key: Key,
+/// }
+///
+/// let json = serde_json::to_string(&Session { key: Key([7; 32]) })?;
+/// assert!(json.starts_with(r#"{"key":[7,7,"#));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn serialize_ref<W, S, T, const N: usize>(data: &W, ser: S) -> Result<S::Ok, S::Error>
+where
+    W: AsRef<[T; N]> + ?Sized,
+    S: Serializer,
+    T: Serialize,
+{
+    crate::serialize(data.as_ref(), ser)
+}
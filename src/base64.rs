@@ -0,0 +1,166 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize byte arrays as base64 strings
+//!
+//! Requires the `base64` feature. Many JSON APIs carry binary data as base64, so this module
+//! writes `[u8; N]` as a single base64 string, using the standard alphabet with padding, for
+//! human-readable formats such as JSON. Other formats get a byte string, exactly as with
+//! [`bytes`](crate::bytes):
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Key {
+//!     #[serde(with = "serde_arrays::base64")]
+//!     public: [u8; 4],
+//! }
+//!
+//! let key = Key { public: [0xDE, 0xAD, 0xBE, 0xEF] };
+//! let json = serde_json::to_string(&key)?;
+//! assert_eq!(json, r#"{"public":"3q2+7w=="}"#);
+//! assert_eq!(key, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! For the URL-safe alphabet, or to leave out the padding, use one of the submodules instead,
+//! e.g. `#[serde(with = "serde_arrays::base64::url_safe_no_pad")]`. Input must use the same
+//! alphabet and padding it would be written with. Invalid base64, or base64 that decodes to the
+//! wrong number of bytes, is an error.
+
+use ::base64::{
+    display::Base64Display,
+    engine::{general_purpose, GeneralPurpose},
+    Engine,
+};
+use core::fmt;
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize a byte array as a standard, padded base64 string, or as a byte string for binary
+/// formats
+pub fn serialize<S, const N: usize>(data: &[u8; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_with(&general_purpose::STANDARD, data, ser)
+}
+
+/// Deserialize a byte array from a standard, padded base64 string, or from a byte string for
+/// binary formats
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_with(&general_purpose::STANDARD, deserializer)
+}
+
+macro_rules! alphabet_module {
+    ($(#[$doc:meta])* $name:ident, $engine:ident) => {
+        $(#[$doc])*
+        pub mod $name {
+            use serde::{Deserializer, Serializer};
+
+            /// Serialize a byte array as a base64 string, or as a byte string for binary formats
+            pub fn serialize<S, const N: usize>(data: &[u8; N], ser: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                super::serialize_with(&super::general_purpose::$engine, data, ser)
+            }
+
+            /// Deserialize a byte array from a base64 string, or from a byte string for binary
+            /// formats
+            pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::deserialize_with(&super::general_purpose::$engine, deserializer)
+            }
+        }
+    };
+}
+
+alphabet_module!(
+    /// Base64 with the standard alphabet and no padding
+    standard_no_pad,
+    STANDARD_NO_PAD
+);
+alphabet_module!(
+    /// Base64 with the URL-safe alphabet and padding
+    url_safe,
+    URL_SAFE
+);
+alphabet_module!(
+    /// Base64 with the URL-safe alphabet and no padding
+    url_safe_no_pad,
+    URL_SAFE_NO_PAD
+);
+
+/// Serialize a byte array as base64 using `engine`, or as a byte string for binary formats
+fn serialize_with<S, const N: usize>(
+    engine: &'static GeneralPurpose,
+    data: &[u8; N],
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if ser.is_human_readable() {
+        ser.collect_str(&Base64Display::new(data, engine))
+    } else {
+        ser.serialize_bytes(data)
+    }
+}
+
+/// Deserialize a byte array from base64 using `engine`, or from a byte string for binary formats
+fn deserialize_with<'de, D, const N: usize>(
+    engine: &'static GeneralPurpose,
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(Base64Visitor::<N> { engine })
+    } else {
+        deserializer.deserialize_bytes(crate::bytes::BytesVisitor::<N>)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [u8; N] arrays encoded as base64 strings
+struct Base64Visitor<const N: usize> {
+    engine: &'static GeneralPurpose,
+}
+
+impl<'de, const N: usize> Visitor<'de> for Base64Visitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a base64 string of {} bytes", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let bytes = self
+            .engine
+            .decode(v)
+            .map_err(|err| de::Error::custom(format_args!("invalid base64 string: {}", err)))?;
+        let found = bytes.len();
+        crate::collect_array(bytes).map_err(|_| {
+            de::Error::custom(format_args!(
+                "invalid base64 string: expected {} bytes, found {}",
+                N, found
+            ))
+        })
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize bit arrays as packed bytes, using [`bitvec`]
+//!
+//! Requires the `bitvec` feature. Bits are packed least-significant bit first (`bitvec`'s
+//! [`Lsb0`] ordering), so bit `i` lives in byte `i / 8` under the mask `1 << (i % 8)`, and are
+//! written with `serialize_bytes`.
+//!
+//! A [`BitArray`] of `M` bytes is handled by this module directly:
+//!
+//! ```
+//! use bitvec::prelude::*;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Flags {
+//!     #[serde(with = "serde_arrays::bit_array")]
+//!     flags: BitArray<[u8; 64], Lsb0>,
+//! }
+//! # let mut flags = Flags { flags: BitArray::ZERO };
+//! # flags.flags.set(9, true);
+//! # let json = serde_json::to_string(&flags)?;
+//! # assert!(json.starts_with(r#"{"flags":[0,2,0,"#));
+//! # assert_eq!(flags, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! and plain `[bool; N]` arrays can be packed the same way with [`bools`].
+//!
+//! [`bitvec`]: https://docs.rs/bitvec
+//! [`BitArray`]: bitvec::array::BitArray
+//! [`Lsb0`]: bitvec::order::Lsb0
+
+use crate::byte_buf::ByteBufVisitor;
+use ::bitvec::{array::BitArray, order::Lsb0, slice::BitSlice, vec::BitVec};
+use serde::{de::Deserializer, ser::Serializer};
+
+/// Serialize a `BitArray` as its `M` packed bytes
+pub fn serialize<S, const M: usize>(
+    data: &BitArray<[u8; M], Lsb0>,
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.serialize_bytes(data.as_raw_slice())
+}
+
+/// Deserialize a `BitArray` from exactly `M` packed bytes
+pub fn deserialize<'de, D, const M: usize>(
+    deserializer: D,
+) -> Result<BitArray<[u8; M], Lsb0>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bytes = deserializer.deserialize_bytes(ByteBufVisitor {
+        len: M,
+        expected: format_args!("{} bits packed into {} bytes", M * 8, M),
+    })?;
+    let mut raw = [0u8; M];
+    raw.copy_from_slice(&bytes);
+    Ok(BitArray::new(raw))
+}
+
+/// Serialize and deserialize `[bool; N]` packed into `(N + 7) / 8` bytes
+///
+/// Use as `#[serde(with = "serde_arrays::bit_array::bools")]`. Any padding bits in the final byte
+/// are written as zeros and ignored when reading.
+pub mod bools {
+    use super::*;
+
+    /// Serialize an array of `bool`s as packed bytes
+    pub fn serialize<S, const N: usize>(data: &[bool; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bits: BitVec<u8, Lsb0> = data.iter().copied().collect();
+        ser.serialize_bytes(bits.as_raw_slice())
+    }
+
+    /// Deserialize an array of `bool`s from packed bytes
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[bool; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // `usize::div_ceil` is newer than this crate's MSRV
+        #[allow(clippy::manual_div_ceil)]
+        let len = (N + 7) / 8;
+        let bytes = deserializer.deserialize_bytes(ByteBufVisitor {
+            len,
+            expected: format_args!("{} bits packed into {} bytes", N, len),
+        })?;
+        let bits = BitSlice::<u8, Lsb0>::from_slice(&bytes);
+        crate::try_build_array(|idx| Ok(bits[idx]))
+    }
+}
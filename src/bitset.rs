@@ -0,0 +1,114 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize `[bool; N]` packed into a byte string of `(N + 7) / 8` bytes
+//!
+//! Formats that are not human-readable get the packed bytes, least-significant bit first, so bit
+//! `i` lives in byte `i / 8` under the mask `1 << (i % 8)`. Human-readable formats such as JSON
+//! still get an ordinary array of `true`/`false`:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Flags {
+//!     #[serde(with = "serde_arrays::bitset")]
+//!     flags: [bool; 10],
+//! }
+//!
+//! let mut flags = Flags { flags: [false; 10] };
+//! flags.flags[9] = true;
+//! let json = serde_json::to_string(&flags)?;
+//! assert!(json.starts_with(r#"{"flags":[false,false,"#));
+//! # assert_eq!(flags, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Any padding bits in the final byte are written as zeros and ignored when reading. Unlike
+//! `bit_array::bools`, this needs neither the `bitvec` feature nor an allocator.
+
+use core::fmt;
+use serde::{
+    de::{self, Deserializer, SeqAccess, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize an array of `bool`s as packed bytes, or as an array for human-readable formats
+pub fn serialize<S, const N: usize>(data: &[bool; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if ser.is_human_readable() {
+        return crate::serialize(data, ser);
+    }
+
+    // A byte per bit is always enough room to pack into, without needing to allocate
+    let mut packed = [0u8; N];
+    for (idx, _) in data.iter().enumerate().filter(|(_, bit)| **bit) {
+        packed[idx / 8] |= 1 << (idx % 8);
+    }
+    ser.serialize_bytes(&packed[..packed_len(N)])
+}
+
+/// Deserialize an array of `bool`s from packed bytes, or from an array for human-readable formats
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[bool; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_tuple(N, BitsetVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(BitsetVisitor::<N>)
+    }
+}
+
+/// Number of bytes needed to pack `bits` bits
+// `usize::div_ceil` is newer than this crate's MSRV
+#[allow(clippy::manual_div_ceil)]
+const fn packed_len(bits: usize) -> usize {
+    (bits + 7) / 8
+}
+
+/// A Serde Deserializer `Visitor` for [bool; N] arrays, unpacking byte strings bit by bit
+struct BitsetVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for BitsetVisitor<N> {
+    type Value = [bool; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} bits packed into {} bytes, or an array of {} bools",
+            N,
+            packed_len(N),
+            N
+        )
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.len() != packed_len(N) {
+            return Err(de::Error::invalid_length(v.len(), &self));
+        }
+
+        let mut arr = [false; N];
+        for (idx, bit) in arr.iter_mut().enumerate() {
+            *bit = v[idx / 8] & (1 << (idx % 8)) != 0;
+        }
+        Ok(arr)
+    }
+
+    /// Human-readable formats represent the array as a sequence of `bool`s
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| core::marker::PhantomData)
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize boxed arrays, keeping large arrays off the stack
+//!
+//! Deserializing a `[T; N]` builds the whole array on the stack before it can be moved anywhere
+//! else, which overflows the stack for large enough `N`. This module instead builds the elements
+//! directly in a heap allocation and returns a `Box<[T; N]>`:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Samples {
+//!     #[serde(with = "serde_arrays::boxed")]
+//!     data: Box<[u64; 100_000]>,
+//! }
+//! # let json = serde_json::to_string(&vec![7u64; 100_000]).unwrap();
+//! # let json = format!(r#"{{"data":{}}}"#, json);
+//! # let samples: Samples = serde_json::from_str(&json)?;
+//! # assert!(samples.data.iter().all(|&x| x == 7));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The serialized form is identical to that of [`serde_arrays`](crate).
+//!
+//! # Performance
+//!
+//! Elements are pushed onto a `Vec` one at a time, which costs a little more per element than
+//! building the array in place. In the `boxed` benchmark (`cargo bench --bench boxed`), reading
+//! `[u64; N]` with bincode and then boxing it was up to twice as fast as this module for arrays of
+//! a few KiB, but this module was around a third faster by `N = 65536` (512 KiB), where copying the
+//! finished array off the stack dominates. Beyond avoiding stack overflows, then, this module pays
+//! off only for arrays of hundreds of KiB or more.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize a boxed array exactly as the array itself
+// Serde's `with` attribute passes a reference to the field, so this must take `&Box`
+#[allow(clippy::borrowed_box)]
+pub fn serialize<S, T, const N: usize>(data: &Box<[T; N]>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::serialize(&**data, ser)
+}
+
+/// Deserialize an array directly into a heap allocation
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<Box<[T; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N,
+        BoxedVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for Box<[T; N]> arrays
+struct BoxedVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for BoxedVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Box<[T; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_boxed_array(seq, &self, |_| PhantomData)
+    }
+}
+
+/// Deserialize a boxed slice of exactly `len` elements, where `len` is only known at runtime
+///
+/// This is the dynamically sized counterpart to [`deserialize`]: the sequence is held to exactly
+/// `len` elements with the same errors as for an array of that size, which suits lengths read from
+/// an earlier header field:
+///
+/// ```
+/// let mut de = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// let data: Box<[u32]> = serde_arrays::deserialize_boxed_slice(&mut de, 3)?;
+/// assert_eq!(&data[..], &[1, 2, 3]);
+///
+/// let mut de = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// let err = serde_arrays::deserialize_boxed_slice::<_, u32>(&mut de, 4).unwrap_err();
+/// assert!(err.to_string().starts_with("invalid length 3, expected an array of size 4"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// Since `len` may come from untrusted input, memory is reserved for at most a bounded number of
+/// elements up front; beyond that the slice grows as elements are actually read.
+///
+/// This is not usable with `#[serde(with)]`, but rather intended for manual `Deserialize`
+/// implementations or direct use with a `Deserializer`.
+pub fn deserialize_boxed_slice<'de, D, T>(deserializer: D, len: usize) -> Result<Box<[T]>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        len,
+        SliceVisitor::<T> {
+            len,
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for Box<[T]> slices of a runtime length
+struct SliceVisitor<T> {
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for SliceVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Box<[T]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_len::<T>(formatter, self.len)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        match seq.size_hint() {
+            Some(found) if found != self.len => {
+                return Err(de::Error::invalid_length(found, &self))
+            }
+            _ => {}
+        }
+
+        // If anything fails, the `Vec` drops whatever has been built so far
+        let mut data = Vec::with_capacity(crate::cautious_capacity::<T>(Some(self.len)));
+        for idx in 0..self.len {
+            match seq.next_element()? {
+                Some(val) => data.push(val),
+                None => return Err(de::Error::invalid_length(idx, &self)),
+            }
+        }
+
+        // Make sure there aren't any elements left over
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(crate::too_long(seq, self.len, &self));
+        }
+
+        Ok(data.into_boxed_slice())
+    }
+}
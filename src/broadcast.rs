@@ -0,0 +1,134 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays from either `N` elements or a single value broadcast to every element
+//!
+//! A lone scalar (number, `bool`, or string) in place of the array is deserialized as a `T` and
+//! cloned into all `N` slots, so `0.5` reads as `[0.5, 0.5, 0.5, 0.5]`. A full array is read as
+//! usual, and must have exactly `N` elements. Serialization always writes the full array.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Uniforms {
+//!     #[serde(with = "serde_arrays::broadcast")]
+//!     color: [f32; 4],
+//! }
+//!
+//! let uniforms: Uniforms = serde_json::from_str(r#"{"color":0.5}"#)?;
+//! assert_eq!(uniforms.color, [0.5; 4]);
+//!
+//! let uniforms: Uniforms = serde_json::from_str(r#"{"color":[1.0,0.5,0.5,1.0]}"#)?;
+//! assert_eq!(uniforms.color, [1.0, 0.5, 0.5, 1.0]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Telling the two apart requires a self-describing format such as JSON or YAML.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        self,
+        value::{BorrowedStrDeserializer, StrDeserializer},
+        Deserialize, Deserializer, IntoDeserializer, SeqAccess, Visitor,
+    },
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize the full array
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::serialize(data, ser)
+}
+
+/// Deserialize an array of `N` elements, or a single value to fill every element with
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Clone,
+{
+    deserializer.deserialize_any(BroadcastVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays that broadcasts scalars
+struct BroadcastVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de> + Clone, const N: usize> BroadcastVisitor<T, N> {
+    /// Deserialize a single `T` from `deserializer`, and fill the array with it
+    fn broadcast<D, E>(self, deserializer: D) -> Result<[T; N], E>
+    where
+        D: Deserializer<'de, Error = E>,
+    {
+        let value = T::deserialize(deserializer)?;
+        crate::try_build_array(|_| Ok(value.clone()))
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone, const N: usize> Visitor<'de> for BroadcastVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of size {} or a single value", N)
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.broadcast(v.into_deserializer())
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.broadcast(v.into_deserializer())
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.broadcast(v.into_deserializer())
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.broadcast(v.into_deserializer())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.broadcast(StrDeserializer::new(v))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.broadcast(BorrowedStrDeserializer::new(v))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| PhantomData)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize B-tree maps whose values are arrays
+//!
+//! This is the ordered counterpart to [`map_values`](crate::map_values): entries are always
+//! written in key order, so the same map always serializes to the same output, as is needed for
+//! reproducible builds or signing.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Tables {
+//!     #[serde(with = "serde_arrays::btree_map_values")]
+//!     tables: BTreeMap<String, [u32; 36]>,
+//! }
+//! # let mut tables = BTreeMap::new();
+//! # tables.insert("a".to_string(), [1; 36]);
+//! # let data = Tables { tables };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Should a value fail to deserialize, the error names the key it belongs to.
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use alloc::collections::BTreeMap;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+
+/// Serialize a map with array values, in key order
+pub fn serialize<S, K, T, const N: usize>(
+    data: &BTreeMap<K, [T; N]>,
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize,
+    T: Serialize,
+{
+    let mut map = ser.serialize_map(Some(data.len()))?;
+    for (key, value) in data {
+        map.serialize_entry(key, &ArrayWrap::new(value))?;
+    }
+    map.end()
+}
+
+/// Deserialize a map with array values
+pub fn deserialize<'de, D, K, T, const N: usize>(
+    deserializer: D,
+) -> Result<BTreeMap<K, [T; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Ord + fmt::Debug,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_map(BTreeMapValuesVisitor::<K, T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for B-tree maps of [T; N] arrays
+struct BTreeMapValuesVisitor<K, T, const N: usize> {
+    _marker: PhantomData<(K, T)>,
+}
+
+impl<'de, K, T, const N: usize> Visitor<'de> for BTreeMapValuesVisitor<K, T, N>
+where
+    K: Deserialize<'de> + Ord + fmt::Debug,
+    T: Deserialize<'de>,
+{
+    type Value = BTreeMap<K, [T; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map with arrays of size {} as values", N)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut data = BTreeMap::new();
+        while let Some(key) = map.next_key::<K>()? {
+            let value = map
+                .next_value_seed(ArraySeed::<T, N>::new())
+                .map_err(|err| de::Error::custom(format_args!("for key {:?}: {}", key, err)))?;
+            data.insert(key, value);
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Shared helpers for arrays encoded as a single byte string
+
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{self, SeqAccess, Visitor};
+
+/// A Serde Deserializer `Visitor` for a byte string of exactly `len` bytes
+///
+/// `expected` describes the encoded value in error messages.
+pub(crate) struct ByteBufVisitor<X> {
+    pub(crate) len: usize,
+    pub(crate) expected: X,
+}
+
+impl<X: fmt::Display> ByteBufVisitor<X> {
+    fn check<E: de::Error>(&self, bytes: Vec<u8>) -> Result<Vec<u8>, E> {
+        if bytes.len() == self.len {
+            Ok(bytes)
+        } else {
+            Err(de::Error::invalid_length(bytes.len(), self))
+        }
+    }
+}
+
+impl<'de, X: fmt::Display> Visitor<'de> for ByteBufVisitor<X> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.expected)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.check(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.check(v)
+    }
+
+    /// Human-readable formats such as JSON represent bytes as a sequence of integers
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(self.len);
+        while bytes.len() < self.len {
+            match seq.next_element()? {
+                Some(byte) => bytes.push(byte),
+                None => return Err(de::Error::invalid_length(bytes.len(), &self)),
+            }
+        }
+
+        // Make sure there aren't any bytes left over, without collecting them
+        match seq.next_element::<de::IgnoredAny>()? {
+            Some(_) => Err(crate::too_long(seq, self.len, &self)),
+            None => Ok(bytes),
+        }
+    }
+}
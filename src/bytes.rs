@@ -0,0 +1,111 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize byte arrays as a single byte string
+//!
+//! Serde provides a dedicated byte string type that binary formats can encode far more compactly
+//! than a sequence of individual integers. This module writes `[u8; N]` as such a byte string for
+//! formats that are not human-readable, while human-readable formats such as JSON still get an
+//! ordinary array of numbers:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Key {
+//!     #[serde(with = "serde_arrays::bytes")]
+//!     key: [u8; 4],
+//! }
+//!
+//! let key = Key { key: [0xDE, 0xAD, 0xBE, 0xEF] };
+//! let json = serde_json::to_string(&key)?;
+//! assert_eq!(json, r#"{"key":[222,173,190,239]}"#);
+//! # assert_eq!(key, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! When deserializing from a byte string, the bytes are copied into the array all at once with
+//! no per-element work; formats that borrow from their input hand over a slice of it directly.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserializer, SeqAccess, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize a byte array as a byte string, or as an array for human-readable formats
+pub fn serialize<S, const N: usize>(data: &[u8; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if ser.is_human_readable() {
+        crate::serialize(data, ser)
+    } else {
+        ser.serialize_bytes(data)
+    }
+}
+
+/// Deserialize a byte array from a byte string, or from an array for human-readable formats
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_tuple(N, BytesVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::<N>)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [u8; N] arrays, copying byte strings directly into the array
+pub(crate) struct BytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte string or array of {} bytes", N)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.len() != N {
+            return Err(de::Error::invalid_length(v.len(), &self));
+        }
+
+        let mut arr = [0; N];
+        arr.copy_from_slice(v);
+        Ok(arr)
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(v)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(&v)
+    }
+
+    /// Human-readable formats such as JSON represent bytes as a sequence of integers
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| PhantomData)
+    }
+}
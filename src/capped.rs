@@ -0,0 +1,126 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize `Vec<[T; N]>` with a maximum number of arrays
+//!
+//! When reading untrusted input, [`Capped`] stops with an error as soon as the sequence turns out
+//! to hold more than `MAX` arrays, rather than growing the `Vec` for as long as the input goes on:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Upload {
+//!     #[serde(with = "serde_arrays::capped::Capped::<1000>")]
+//!     blocks: Vec<[u8; 64]>,
+//! }
+//! # let upload = Upload { blocks: vec![[1; 64]; 1000] };
+//! # let json = serde_json::to_string(&upload)?;
+//! # assert_eq!(upload, serde_json::from_str(&json)?);
+//! # let upload = Upload { blocks: vec![[1; 64]; 1001] };
+//! # let json = serde_json::to_string(&upload)?;
+//! # assert!(serde_json::from_str::<Upload>(&json).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The array past the cap is skipped over rather than deserialized, and nothing after it is read.
+//! An array that fails to deserialize is reported along with its index, e.g.
+//! `at index 2: invalid length 2, expected an array of size 64`.
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+/// Serialize and deserialize `Vec<[T; N]>` holding at most `MAX` arrays
+///
+/// Use as `#[serde(with = "serde_arrays::capped::Capped::<MAX>")]`.
+pub struct Capped<const MAX: usize>;
+
+impl<const MAX: usize> Capped<MAX> {
+    /// Serialize a `Vec` of arrays
+    ///
+    /// The cap is not checked here, only when deserializing.
+    pub fn serialize<S, T, const N: usize>(data: &[[T; N]], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut s = ser.serialize_seq(Some(data.len()))?;
+        for item in data {
+            s.serialize_element(&ArrayWrap::new(item))?;
+        }
+        s.end()
+    }
+
+    /// Deserialize a `Vec` of arrays, failing once more than `MAX` are found
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<Vec<[T; N]>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_seq(CappedVisitor::<T, N, MAX> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A Serde Deserializer `Visitor` for Vec<[T; N]> with at most `MAX` arrays
+struct CappedVisitor<T, const N: usize, const MAX: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize, const MAX: usize> Visitor<'de> for CappedVisitor<T, N, MAX>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Vec<[T; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a sequence of at most {} arrays of size {}",
+            MAX, N
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let hint = seq.size_hint().unwrap_or(0);
+        if hint > MAX {
+            return Err(de::Error::invalid_length(hint, &self));
+        }
+
+        // Don't trust the size hint so far as to allocate an unbounded amount of memory up front
+        let mut data = Vec::with_capacity(crate::cautious_capacity::<[T; N]>(Some(hint)));
+        while data.len() < MAX {
+            let arr = seq
+                .next_element_seed(ArraySeed::<T, N>::new())
+                .map_err(|err| {
+                    de::Error::custom(format_args!("at index {}: {}", data.len(), err))
+                })?;
+            match arr {
+                Some(arr) => data.push(arr),
+                None => return Ok(data),
+            }
+        }
+
+        match seq.next_element::<IgnoredAny>()? {
+            Some(_) => Err(de::Error::custom(format_args!(
+                "expected {}, found at least {} arrays",
+                &self as &dyn de::Expected,
+                MAX + 1
+            ))),
+            None => Ok(data),
+        }
+    }
+}
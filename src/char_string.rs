@@ -0,0 +1,94 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize `[char; N]` arrays as a single string
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Code {
+//!     #[serde(with = "serde_arrays::char_string")]
+//!     code: [char; 3],
+//! }
+//!
+//! let code = Code { code: ['a', 'b', 'c'] };
+//! let json = serde_json::to_string(&code)?;
+//! assert_eq!(json, r#"{"code":"abc"}"#);
+//! # assert_eq!(code, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The string must contain exactly `N` `char`s, which may be fewer than its length in bytes.
+
+use core::fmt;
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize a `char` array as a string
+pub fn serialize<S, const N: usize>(data: &[char; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.collect_str(&Chars(data))
+}
+
+/// Deserialize a `char` array from a string of exactly `N` `char`s
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[char; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(CharStringVisitor::<N>)
+}
+
+/// Displays a slice of `char`s as the string they make up, without allocating
+struct Chars<'a>(&'a [char]);
+
+impl fmt::Display for Chars<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use fmt::Write;
+
+        for &c in self.0 {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [char; N] arrays written as strings
+struct CharStringVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for CharStringVisitor<N> {
+    type Value = [char; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string of {} chars", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let mut arr = ['\0'; N];
+        let mut chars = v.chars();
+        for (idx, slot) in arr.iter_mut().enumerate() {
+            match chars.next() {
+                Some(c) => *slot = c,
+                None => return Err(de::Error::invalid_length(idx, &self)),
+            }
+        }
+
+        let excess = chars.count();
+        if excess > 0 {
+            return Err(de::Error::invalid_length(N + excess, &self));
+        }
+
+        Ok(arr)
+    }
+}
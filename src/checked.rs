@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays whose elements must all be distinct
+//!
+//! The array is deserialized in full, just as with [`deserialize`](crate::deserialize), and then
+//! checked for duplicates before being returned. The first element that repeats an earlier one
+//! fails deserialization with an error naming both indices. Serialization always writes the array
+//! as-is.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug)]
+//! struct Shuffle {
+//!     #[serde(with = "serde_arrays::checked")]
+//!     order: [u8; 4],
+//! }
+//!
+//! let shuffle: Shuffle = serde_json::from_str(r#"{"order":[2,0,3,1]}"#)?;
+//! assert_eq!(shuffle.order, [2, 0, 3, 1]);
+//!
+//! let err = serde_json::from_str::<Shuffle>(r#"{"order":[2,0,2,1]}"#).unwrap_err();
+//! assert!(err.to_string().starts_with("duplicate element at index 2, first seen at index 0"));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::hash::Hash;
+use serde::{
+    de::{self, Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+use std::collections::{hash_map::Entry, HashMap};
+
+/// Serialize the full array
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::serialize(data, ser)
+}
+
+/// Deserialize an array, failing if any element appears more than once
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Eq + Hash,
+{
+    let arr: [T; N] = crate::deserialize(deserializer)?;
+
+    // On a duplicate, returning drops `arr` along with every element in it
+    let mut seen = HashMap::with_capacity(N);
+    for (idx, item) in arr.iter().enumerate() {
+        match seen.entry(item) {
+            Entry::Occupied(first) => {
+                return Err(de::Error::custom(format_args!(
+                    "duplicate element at index {}, first seen at index {}",
+                    idx,
+                    first.get()
+                )))
+            }
+            Entry::Vacant(slot) => {
+                slot.insert(idx);
+            }
+        }
+    }
+
+    Ok(arr)
+}
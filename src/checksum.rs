@@ -0,0 +1,70 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArrayError;
+
+/// Verify an array against an expected checksum
+///
+/// `hasher` computes the checksum of the array, e.g. a CRC32 over its elements, which is then
+/// compared against `expected`.
+///
+/// Serde's derive can't cross-validate one field against another, but this can be combined with
+/// Serde's `try_from` container attribute to deserialize in two phases: first into a "raw" struct,
+/// then converting it into the final type only if the checksum matches.
+///
+/// ```
+/// use serde::Deserialize;
+/// use serde_arrays::{verify_checksum, ArrayError};
+/// use std::convert::TryFrom;
+///
+/// fn sum(data: &[u8; 40]) -> u32 {
+///     data.iter().map(|&b| u32::from(b)).sum()
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct RawRecord {
+///     #[serde(with = "serde_arrays")]
+///     data: [u8; 40],
+///     checksum: u32,
+/// }
+///
+/// #[derive(Deserialize, Debug)]
+/// #[serde(try_from = "RawRecord")]
+/// struct Record {
+///     data: [u8; 40],
+/// }
+///
+/// impl TryFrom<RawRecord> for Record {
+///     type Error = ArrayError;
+///
+///     fn try_from(raw: RawRecord) -> Result<Self, Self::Error> {
+///         verify_checksum(&raw.data, raw.checksum, sum)?;
+///         Ok(Record { data: raw.data })
+///     }
+/// }
+///
+/// let json = format!(r#"{{"data":{:?},"checksum":80}}"#, [2u8; 40]);
+/// assert!(serde_json::from_str::<Record>(&json).is_ok());
+///
+/// let json = format!(r#"{{"data":{:?},"checksum":81}}"#, [2u8; 40]);
+/// assert!(serde_json::from_str::<Record>(&json).is_err());
+/// ```
+pub fn verify_checksum<T, F, const N: usize>(
+    data: &[T; N],
+    expected: u32,
+    hasher: F,
+) -> Result<(), ArrayError>
+where
+    F: FnOnce(&[T; N]) -> u32,
+{
+    let actual = hasher(data);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ArrayError::ChecksumMismatch { expected, actual })
+    }
+}
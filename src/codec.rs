@@ -0,0 +1,231 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays through a user-supplied element [`Codec`]
+//!
+//! This crate takes care of the fixed length of the array, while the codec decides how each
+//! individual element is represented. Because `#[serde(with)]` can't take arguments, the codec is
+//! passed as a type parameter to [`WithCodec`]:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use serde_arrays::codec::LowerHex;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+//! struct Colors {
+//!     #[serde(with = "serde_arrays::codec::WithCodec::<LowerHex>")]
+//!     palette: [u32; 40],
+//! }
+//! # let data = Colors { palette: [0xff00ff; 40] };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert!(json.starts_with(r#"{"palette":["ff00ff","#));
+//! # let de_data = serde_json::from_str(&json)?;
+//! # assert_eq!(data, de_data);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use alloc::string::{String, ToString};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// Element-level encoding used by [`WithCodec`]
+///
+/// Implement this trait to control how each element of an array is serialized and deserialized,
+/// e.g. as a string in some particular notation.
+pub trait Codec<T> {
+    /// Serialize a single element
+    fn encode_element<S>(value: &T, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    /// Deserialize a single element
+    fn decode_element<'de, D>(de: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+/// Serialize and deserialize `[T; N]` using the [`Codec`] `C` for each element
+///
+/// Use as `#[serde(with = "serde_arrays::codec::WithCodec::<MyCodec>")]`.
+pub struct WithCodec<C> {
+    _marker: PhantomData<C>,
+}
+
+impl<C> WithCodec<C> {
+    /// Serialize an array, encoding each element with `C`
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        C: Codec<T>,
+    {
+        let mut s = ser.serialize_tuple(N)?;
+        for item in data {
+            s.serialize_element(&Encode::<C, T> {
+                value: item,
+                _marker: PhantomData,
+            })?;
+        }
+        s.end()
+    }
+
+    /// Deserialize an array, decoding each element with `C`
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        C: Codec<T>,
+    {
+        deserializer.deserialize_tuple(
+            N,
+            CodecVisitor::<C, T, N> {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// Wrapper to serialize a single element through a codec
+struct Encode<'a, C, T> {
+    value: &'a T,
+    _marker: PhantomData<C>,
+}
+
+impl<'a, C: Codec<T>, T> Serialize for Encode<'a, C, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        C::encode_element(self.value, serializer)
+    }
+}
+
+/// Seed to deserialize a single element through a codec
+struct Decode<C, T> {
+    _marker: PhantomData<(C, T)>,
+}
+
+impl<'de, C: Codec<T>, T> DeserializeSeed<'de> for Decode<C, T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        C::decode_element(deserializer)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays of codec-encoded elements
+struct CodecVisitor<C, T, const N: usize> {
+    _marker: PhantomData<(C, T)>,
+}
+
+impl<'de, C: Codec<T>, T, const N: usize> Visitor<'de> for CodecVisitor<C, T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| Decode::<C, T> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Encode elements as strings using their `Display` and `FromStr` implementations
+pub struct DisplayFromStr;
+
+impl<T> Codec<T> for DisplayFromStr
+where
+    T: fmt::Display + FromStr,
+    T::Err: fmt::Display,
+{
+    fn encode_element<S>(value: &T, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ser.collect_str(value)
+    }
+
+    fn decode_element<'de, D>(de: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        de.deserialize_str(ParseVisitor::new(|s: &str| {
+            s.parse::<T>().map_err(|e| e.to_string())
+        }))
+    }
+}
+
+/// Encode unsigned integer elements as lowercase hexadecimal strings, without a `0x` prefix
+pub struct LowerHex;
+
+macro_rules! impl_lower_hex {
+    ($($ty:ty),*) => {
+        $(
+            impl Codec<$ty> for LowerHex {
+                fn encode_element<S>(value: &$ty, ser: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    ser.collect_str(&format_args!("{:x}", value))
+                }
+
+                fn decode_element<'de, D>(de: D) -> Result<$ty, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    de.deserialize_str(ParseVisitor::new(|s: &str| {
+                        <$ty>::from_str_radix(s, 16).map_err(|e| e.to_string())
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_lower_hex!(u8, u16, u32, u64, u128, usize);
+
+/// A Serde Deserializer `Visitor` that parses a string with the function `parse`
+pub(crate) struct ParseVisitor<T, F> {
+    parse: F,
+    _marker: PhantomData<T>,
+}
+
+impl<T, F> ParseVisitor<T, F> {
+    pub(crate) fn new(parse: F) -> Self {
+        ParseVisitor {
+            parse,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, F> Visitor<'de> for ParseVisitor<T, F>
+where
+    F: FnOnce(&str) -> Result<T, String>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        (self.parse)(v).map_err(|e| E::custom(format_args!("invalid value {:?}: {}", v, e)))
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArrayLengthError;
+
+/// Collect exactly `N` items from an iterator into a `[T; N]`
+///
+/// This is the same array-building logic every array deserialized by this crate goes through,
+/// available for use outside of Serde. Should the iterator run dry early, the items taken from it
+/// are dropped and the error gives how many there were; should it hold more than `N` items, only
+/// one past the end is taken from it, so the error's `found` is `N + 1` however many actually
+/// remain:
+///
+/// ```
+/// use serde_arrays::{collect_array, ArrayLengthError};
+///
+/// let squares: [u64; 40] = collect_array((0..).map(|i| i * i).take(40))?;
+/// assert_eq!(squares[39], 39 * 39);
+///
+/// let short = collect_array::<_, _, 40>((0..10).map(|i| i * i)).unwrap_err();
+/// assert_eq!(short, ArrayLengthError { expected: 40, found: 10 });
+/// assert!(short.is_too_short());
+/// # Ok::<(), ArrayLengthError>(())
+/// ```
+pub fn collect_array<I, T, const N: usize>(iter: I) -> Result<[T; N], ArrayLengthError>
+where
+    I: IntoIterator<Item = T>,
+{
+    let mut iter = iter.into_iter();
+    let arr = crate::try_build_array(|idx| {
+        iter.next().ok_or(ArrayLengthError {
+            expected: N,
+            found: idx,
+        })
+    })?;
+
+    match iter.next() {
+        Some(_) => Err(ArrayLengthError {
+            expected: N,
+            found: N + 1,
+        }),
+        None => Ok(arr),
+    }
+}
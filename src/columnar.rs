@@ -0,0 +1,281 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize `Vec<[T; N]>` in columnar (struct-of-arrays) layout
+//!
+//! `K` rows of `N` elements are written as `N` columns of `K` elements each, which generally
+//! compresses better and is the layout column stores expect:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Points {
+//!     #[serde(with = "serde_arrays::columnar")]
+//!     xyz: Vec<[i32; 3]>,
+//! }
+//!
+//! let points = Points { xyz: vec![[1, 2, 3], [4, 5, 6]] };
+//! let json = serde_json::to_string(&points)?;
+//! assert_eq!(json, r#"{"xyz":[[1,4],[2,5],[3,6]]}"#);
+//! # assert_eq!(points, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! When deserializing, every column must have the same length.
+//!
+//! Arrays of pairs can instead be read from and written to an object of two named columns with
+//! [`Pairs`], naming each column by implementing [`FieldName`] on a marker type:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use serde_arrays::unwrap_field::FieldName;
+//!
+//! struct A;
+//! impl FieldName for A {
+//!     const NAME: &'static str = "a";
+//! }
+//!
+//! struct B;
+//! impl FieldName for B {
+//!     const NAME: &'static str = "b";
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Table {
+//!     #[serde(with = "serde_arrays::columnar::Pairs::<A, B>")]
+//!     rows: [(i32, i32); 2],
+//! }
+//!
+//! let table: Table = serde_json::from_str(r#"{"rows":{"a":[1,2],"b":[3,4]}}"#)?;
+//! assert_eq!(table.rows, [(1, 3), (2, 4)]);
+//! # assert_eq!(serde_json::to_string(&table)?, r#"{"rows":{"a":[1,2],"b":[3,4]}}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Both columns must have exactly `N` elements; as with Serde's derived implementations, any other
+//! fields in the object are ignored.
+
+use crate::{field::FieldSeed, unwrap_field::FieldName};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeMap, SerializeSeq, SerializeTuple, Serializer},
+};
+
+/// Serialize rows of `[T; N]` as `N` columns
+pub fn serialize<S, T, const N: usize>(data: &[[T; N]], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_tuple(N)?;
+    for col in 0..N {
+        s.serialize_element(&Column { rows: data, col })?;
+    }
+    s.end()
+}
+
+/// Deserialize `N` equal-length columns into rows of `[T; N]`
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<Vec<[T; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N,
+        ColumnarVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// Serializes one column of a slice of rows as a sequence
+struct Column<'a, T, const N: usize> {
+    rows: &'a [[T; N]],
+    col: usize,
+}
+
+impl<'a, T: Serialize, const N: usize> Serialize for Column<'a, T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_seq(Some(self.rows.len()))?;
+        for row in self.rows {
+            s.serialize_element(&row[self.col])?;
+        }
+        s.end()
+    }
+}
+
+/// A Serde Deserializer `Visitor` for columnar Vec<[T; N]>
+struct ColumnarVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ColumnarVisitor<T, N> {
+    type Value = Vec<[T; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} columns of equal length", N)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut columns: [Vec<T>; N] = crate::fill_array(seq, &self, |_| PhantomData)?;
+
+        let rows = columns.first().map_or(0, Vec::len);
+        if let Some((col, column)) = columns.iter().enumerate().find(|(_, c)| c.len() != rows) {
+            return Err(de::Error::custom(format_args!(
+                "column {} has {} elements but column 0 has {}",
+                col,
+                column.len(),
+                rows
+            )));
+        }
+
+        let mut columns: Vec<_> = columns.iter_mut().map(|c| c.drain(..)).collect();
+        let mut data = Vec::with_capacity(rows);
+        for _ in 0..rows {
+            // Every column has exactly `rows` elements, so none of these can run dry
+            data.push(crate::try_build_array(|col| {
+                Ok::<_, A::Error>(columns[col].next().unwrap())
+            })?);
+        }
+        Ok(data)
+    }
+}
+
+/// Serialize and deserialize `[(A, B); N]` as an object of the two columns `X::NAME` and `Y::NAME`
+///
+/// Use as `#[serde(with = "serde_arrays::columnar::Pairs::<X, Y>")]`.
+pub struct Pairs<X, Y> {
+    _marker: PhantomData<(X, Y)>,
+}
+
+impl<X: FieldName, Y: FieldName> Pairs<X, Y> {
+    /// Serialize an array of pairs as an object of two columns
+    pub fn serialize<S, A, B, const N: usize>(data: &[(A, B); N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        A: Serialize,
+        B: Serialize,
+    {
+        let mut map = ser.serialize_map(Some(2))?;
+        map.serialize_entry(
+            X::NAME,
+            &PairColumn {
+                data,
+                second: false,
+            },
+        )?;
+        map.serialize_entry(Y::NAME, &PairColumn { data, second: true })?;
+        map.end()
+    }
+
+    /// Deserialize an array of pairs by zipping two columns of `N` elements each
+    pub fn deserialize<'de, D, A, B, const N: usize>(
+        deserializer: D,
+    ) -> Result<[(A, B); N], D::Error>
+    where
+        D: Deserializer<'de>,
+        A: Deserialize<'de>,
+        B: Deserialize<'de>,
+    {
+        deserializer.deserialize_map(PairsVisitor::<X, Y, A, B, N> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Serializes the first or second half of every pair in an array as a tuple
+struct PairColumn<'a, A, B, const N: usize> {
+    data: &'a [(A, B); N],
+    second: bool,
+}
+
+impl<'a, A: Serialize, B: Serialize, const N: usize> Serialize for PairColumn<'a, A, B, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(N)?;
+        for (a, b) in self.data {
+            if self.second {
+                s.serialize_element(b)?;
+            } else {
+                s.serialize_element(a)?;
+            }
+        }
+        s.end()
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [(A, B); N] arrays stored as two named columns
+struct PairsVisitor<X, Y, A, B, const N: usize> {
+    _marker: PhantomData<(X, Y, A, B)>,
+}
+
+impl<'de, X, Y, A, B, const N: usize> Visitor<'de> for PairsVisitor<X, Y, A, B, N>
+where
+    X: FieldName,
+    Y: FieldName,
+    A: Deserialize<'de>,
+    B: Deserialize<'de>,
+{
+    type Value = [(A, B); N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "an object with the columns `{}` and `{}` of {} elements each",
+            X::NAME,
+            Y::NAME,
+            N
+        )
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut first: Option<[A; N]> = None;
+        let mut second: Option<[B; N]> = None;
+        let names = [X::NAME, Y::NAME];
+        while let Some(key) = map.next_key_seed(FieldSeed(&names))? {
+            match key {
+                Some(0) if first.is_some() => return Err(de::Error::duplicate_field(X::NAME)),
+                Some(0) => first = Some(next_column(&mut map, X::NAME)?),
+                Some(_) if second.is_some() => return Err(de::Error::duplicate_field(Y::NAME)),
+                Some(_) => second = Some(next_column(&mut map, Y::NAME)?),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let first = Vec::from(first.ok_or_else(|| de::Error::missing_field(X::NAME))?);
+        let second = Vec::from(second.ok_or_else(|| de::Error::missing_field(Y::NAME))?);
+        let mut pairs = first.into_iter().zip(second);
+        // Both columns have exactly `N` elements, so the pairs cannot run dry
+        crate::try_build_array(|_| Ok(pairs.next().unwrap()))
+    }
+}
+
+/// Read the next map value as a column of `N` elements, naming the column in any error
+fn next_column<'de, M, T, const N: usize>(map: &mut M, name: &str) -> Result<[T; N], M::Error>
+where
+    M: MapAccess<'de>,
+    T: Deserialize<'de>,
+{
+    map.next_value_seed(crate::ArraySeed::<T, N>::new())
+        .map_err(|err| de::Error::custom(format_args!("column `{}`: {}", name, err)))
+}
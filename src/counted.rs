@@ -0,0 +1,138 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays wrapped in a struct alongside their element count
+//!
+//! Some protocols wrap arrays as `{"count": 3, "items": [...]}`. This module reads and writes
+//! exactly that shape, checking the `count` against `N` as well as the number of `items`:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Reply {
+//!     #[serde(with = "serde_arrays::counted")]
+//!     ids: [u32; 3],
+//! }
+//!
+//! let reply = Reply { ids: [7, 8, 9] };
+//! let json = serde_json::to_string(&reply)?;
+//! assert_eq!(json, r#"{"ids":{"count":3,"items":[7,8,9]}}"#);
+//! # assert_eq!(reply, serde_json::from_str(&json)?);
+//!
+//! let err = serde_json::from_str::<Reply>(r#"{"ids":{"count":4,"items":[7,8,9]}}"#).unwrap_err();
+//! assert!(err.to_string().starts_with("count 4 does not match an array of size 3"));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The fields may come in either order, and any other fields are ignored. For binary formats, the
+//! struct is written as the count followed by the elements.
+
+use crate::{field::FieldSeed, wrapper::ArrayWrap, ArraySeed};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+const FIELDS: &[&str] = &["count", "items"];
+
+/// Serialize an array as a `count`/`items` struct
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_struct("Counted", 2)?;
+    s.serialize_field("count", &(N as u64))?;
+    s.serialize_field("items", &ArrayWrap::new(data))?;
+    s.end()
+}
+
+/// Deserialize an array from a `count`/`items` struct
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_struct(
+        "Counted",
+        FIELDS,
+        CountedVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays wrapped with their count
+struct CountedVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for CountedVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a count and items of an array of size {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let count = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        check_count::<_, N>(count)?;
+        seq.next_element_seed(ArraySeed::<T, N>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut count = None;
+        let mut items = None;
+        while let Some(key) = map.next_key_seed(FieldSeed(FIELDS))? {
+            match key.map(|idx| FIELDS[idx]) {
+                Some("count") if count.is_some() => {
+                    return Err(de::Error::duplicate_field("count"))
+                }
+                Some("count") => {
+                    let c = map.next_value()?;
+                    check_count::<_, N>(c)?;
+                    count = Some(c);
+                }
+                Some("items") if items.is_some() => {
+                    return Err(de::Error::duplicate_field("items"))
+                }
+                Some("items") => items = Some(map.next_value_seed(ArraySeed::<T, N>::new())?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        count.ok_or_else(|| de::Error::missing_field("count"))?;
+        items.ok_or_else(|| de::Error::missing_field("items"))
+    }
+}
+
+/// Check the decoded count against the array's length `N`
+fn check_count<E: de::Error, const N: usize>(count: u64) -> Result<(), E> {
+    if count == N as u64 {
+        Ok(())
+    } else {
+        Err(de::Error::custom(format_args!(
+            "count {} does not match an array of size {}",
+            count, N
+        )))
+    }
+}
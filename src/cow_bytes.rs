@@ -0,0 +1,98 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize byte arrays, borrowing from the input where possible
+//!
+//! This works as [`bytes`](crate::bytes) does, but deserializes into a `Cow<[u8; N]>`: formats
+//! that can hand out a slice of their input give a [`Cow::Borrowed`] with nothing copied, while
+//! any other format gives a [`Cow::Owned`]:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::borrow::Cow;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Packet<'a> {
+//!     #[serde(borrow, with = "serde_arrays::cow_bytes")]
+//!     payload: Cow<'a, [u8; 1024]>,
+//! }
+//!
+//! let packet = Packet { payload: Cow::Owned([7; 1024]) };
+//! let bytes = bincode::serialize(&packet).unwrap();
+//!
+//! let borrowed: Packet = bincode::deserialize(&bytes).unwrap();
+//! assert!(matches!(borrowed.payload, Cow::Borrowed(_)));
+//! # assert_eq!(packet, borrowed);
+//! ```
+//!
+//! As with any borrowed field, remember the `borrow` attribute.
+
+use alloc::borrow::Cow;
+use core::{convert::TryFrom, fmt};
+use serde::{
+    de::{self, Deserializer, SeqAccess, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize a byte array as a byte string, or as an array for human-readable formats
+// Serde's `with` attribute passes a reference to the field, so this must take `&Cow`
+#[allow(clippy::ptr_arg)]
+pub fn serialize<S, const N: usize>(data: &Cow<[u8; N]>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::bytes::serialize(data, ser)
+}
+
+/// Deserialize a byte array, borrowing it from the input if the format allows
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<Cow<'de, [u8; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_tuple(N, CowBytesVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(CowBytesVisitor::<N>)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for byte arrays that borrows byte strings from the input
+struct CowBytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for CowBytesVisitor<N> {
+    type Value = Cow<'de, [u8; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte string or array of {} bytes", N)
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        <&[u8; N]>::try_from(v)
+            .map(Cow::Borrowed)
+            .map_err(|_| de::Error::invalid_length(v.len(), &self))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        <&[u8; N]>::try_from(v)
+            .map(|arr| Cow::Owned(*arr))
+            .map_err(|_| de::Error::invalid_length(v.len(), &self))
+    }
+
+    /// Human-readable formats such as JSON represent bytes as a sequence of integers
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| core::marker::PhantomData).map(Cow::Owned)
+    }
+}
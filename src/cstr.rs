@@ -0,0 +1,87 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize NUL-padded `[u8; N]` text fields, as found in C structs, as strings
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Device {
+//!     #[serde(with = "serde_arrays::cstr")]
+//!     name: [u8; 8],
+//! }
+//!
+//! let device = Device { name: *b"eth0\0\0\0\0" };
+//! let json = serde_json::to_string(&device)?;
+//! assert_eq!(json, r#"{"name":"eth0"}"#);
+//! # assert_eq!(device, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The string is made of the bytes up to the first NUL, or all `N` bytes if there is none, and
+//! must be valid UTF-8. On input, the string is padded with NULs to `N` bytes; it may take up all
+//! `N` bytes, leaving no room for a terminating NUL, but no more, and may not itself contain a NUL.
+
+use core::fmt;
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::{self, Serializer},
+};
+
+/// Serialize a NUL-padded byte array as a string
+pub fn serialize<S, const N: usize>(data: &[u8; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let len = data.iter().position(|&b| b == 0).unwrap_or(N);
+    match core::str::from_utf8(&data[..len]) {
+        Ok(s) => ser.serialize_str(s),
+        Err(err) => Err(ser::Error::custom(format_args!(
+            "invalid UTF-8 in C string: {}",
+            err
+        ))),
+    }
+}
+
+/// Deserialize a NUL-padded byte array from a string of at most `N` bytes
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(CStrVisitor::<N>)
+}
+
+/// A Serde Deserializer `Visitor` for [u8; N] arrays written as strings
+struct CStrVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for CStrVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string of at most {} bytes", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        if v.len() > N {
+            return Err(de::Error::invalid_length(v.len(), &self));
+        }
+        if let Some(idx) = v.bytes().position(|b| b == 0) {
+            return Err(de::Error::custom(format_args!(
+                "C string contains a NUL byte at index {}",
+                idx
+            )));
+        }
+
+        let mut arr = [0; N];
+        arr[..v.len()].copy_from_slice(v.as_bytes());
+        Ok(arr)
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays as a single comma-separated string
+//!
+//! This is for interoperating with producers that write arrays such as `[u8; N]` as
+//! `"1,2,3,..."` rather than as an array. Elements are serialized using their `Display`
+//! implementation joined by commas, and deserialized by splitting on commas and parsing each
+//! token with `FromStr`; whitespace around a token is ignored.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Legacy {
+//!     #[serde(with = "serde_arrays::csv_string")]
+//!     mac: [u8; 6],
+//! }
+//!
+//! let l: Legacy = serde_json::from_str(r#"{"mac":"0,17, 34,51,68,85"}"#)?;
+//! assert_eq!(l.mac, [0, 17, 34, 51, 68, 85]);
+//! assert_eq!(serde_json::to_string(&l)?, r#"{"mac":"0,17,34,51,68,85"}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::delimited::{FromStrSeed, Joined, Tokens};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize an array as a comma-separated string
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    ser.collect_str(&Joined {
+        items: data,
+        sep: ",",
+    })
+}
+
+/// Deserialize an array from a string of exactly `N` comma-separated tokens
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    deserializer.deserialize_str(CsvVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays encoded as comma-separated strings
+struct CsvVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for CsvVisitor<T, N>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string of {} comma-separated values", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        // An empty string is an empty array, not a single empty token
+        let tokens = Some(v)
+            .filter(|v| !v.trim().is_empty())
+            .into_iter()
+            .flat_map(|v| v.split(','))
+            .map(str::trim);
+        crate::fill_array(Tokens::new(tokens), &self, |_| FromStrSeed::new())
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays leniently, filling in any missing elements with `T::default()`
+//!
+//! Input may hold anywhere from zero to `N` elements; whatever is missing from the end is filled
+//! in with the default value. More than `N` elements is still an error. Serialization always
+//! writes the full array, as a sequence so that binary formats such as bincode record how many
+//! elements follow.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Settings {
+//!     #[serde(with = "serde_arrays::default_filled")]
+//!     levels: [u32; 8],
+//! }
+//!
+//! let settings: Settings = serde_json::from_str(r#"{"levels":[3,1,4]}"#)?;
+//! assert_eq!(settings.levels, [3, 1, 4, 0, 0, 0, 0, 0]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! This is [`OptionalTail`] with every element optional; use that instead to require some of the
+//! leading elements.
+
+use crate::optional_tail::OptionalTail;
+use serde::{de::Deserialize, de::Deserializer, ser::Serialize, ser::Serializer};
+
+/// Serialize the full array as a sequence
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::seq::serialize(data, ser)
+}
+
+/// Deserialize an array of up to `N` elements, defaulting any that are missing
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    OptionalTail::<{ usize::MAX }>::deserialize(deserializer)
+}
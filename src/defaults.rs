@@ -0,0 +1,63 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::convert::Infallible;
+
+/// Create an array with every element set to `T::default()`
+///
+/// The standard library only implements `Default` for arrays of up to 32 elements, so Serde's
+/// plain `#[serde(default)]` cannot be used on larger array fields. This function can be used
+/// with Serde's `default = "path"` attribute instead:
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "serde_arrays", default = "serde_arrays::default_array")]
+///     weights: [u32; 64],
+/// }
+///
+/// let config: Config = serde_json::from_str("{}")?;
+/// assert_eq!(config.weights, [0; 64]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn default_array<T, const N: usize>() -> [T; N]
+where
+    T: Default,
+{
+    match crate::try_build_array(|_| Ok::<_, Infallible>(T::default())) {
+        Ok(arr) => arr,
+        Err(never) => match never {},
+    }
+}
+
+/// Check if every element of an array is equal to `T::default()`
+///
+/// This is intended to be used with Serde's `skip_serializing_if` attribute to omit arrays that
+/// contain nothing but default values:
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Config {
+///     #[serde(with = "serde_arrays", skip_serializing_if = "serde_arrays::is_all_default")]
+///     weights: [u32; 40],
+/// }
+///
+/// let json = serde_json::to_string(&Config { weights: [0; 40] })?;
+/// assert_eq!(json, "{}");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn is_all_default<T, const N: usize>(data: &[T; N]) -> bool
+where
+    T: Default + PartialEq,
+{
+    let default = T::default();
+    data.iter().all(|item| *item == default)
+}
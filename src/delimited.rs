@@ -0,0 +1,97 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Shared helpers for arrays represented as a single delimited string
+
+use crate::codec::ParseVisitor;
+use alloc::string::ToString;
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, SeqAccess};
+
+/// Display adapter joining the elements of an array with `sep`
+pub(crate) struct Joined<'a, T> {
+    pub(crate) items: &'a [T],
+    pub(crate) sep: &'a str,
+}
+
+impl<'a, T: fmt::Display> fmt::Display for Joined<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.sep)?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `SeqAccess` yielding each token of a string as an element
+pub(crate) struct Tokens<I, E> {
+    tokens: I,
+    _marker: PhantomData<E>,
+}
+
+impl<'a, I, E> Tokens<I, E>
+where
+    I: Iterator<Item = &'a str>,
+{
+    pub(crate) fn new(tokens: I) -> Self {
+        Tokens {
+            tokens,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, 'a, I, E> SeqAccess<'de> for Tokens<I, E>
+where
+    I: Iterator<Item = &'a str>,
+    E: de::Error,
+{
+    type Error = E;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, E>
+    where
+        S: DeserializeSeed<'de>,
+    {
+        match self.tokens.next() {
+            Some(token) => seed.deserialize(token.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Seed to deserialize an element by parsing a string with `FromStr`
+pub(crate) struct FromStrSeed<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<T> FromStrSeed<T> {
+    pub(crate) fn new() -> Self {
+        FromStrSeed {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> DeserializeSeed<'de> for FromStrSeed<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ParseVisitor::new(|s: &str| {
+            s.parse::<T>().map_err(|e| e.to_string())
+        }))
+    }
+}
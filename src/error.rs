@@ -0,0 +1,73 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::fmt;
+
+/// Errors from this crate's helpers that operate outside of Serde's own error handling
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ArrayError {
+    /// The checksum computed over an array did not match the expected value
+    ChecksumMismatch {
+        /// The checksum the array was expected to have
+        expected: u32,
+        /// The checksum actually computed over the array
+        actual: u32,
+    },
+}
+
+impl fmt::Display for ArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArrayError::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {:#010x}, found {:#010x}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArrayError {}
+
+/// An array had the wrong number of elements
+///
+/// Its `Display` output, `invalid length {found}, expected an array of size {expected}`, is the
+/// same message Serde produces for the length errors from [`deserialize`](crate::deserialize).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArrayLengthError {
+    /// The number of elements the array must have
+    pub expected: usize,
+    /// The number of elements actually found
+    pub found: usize,
+}
+
+impl ArrayLengthError {
+    /// Whether there were fewer elements than expected
+    pub fn is_too_short(&self) -> bool {
+        self.found < self.expected
+    }
+
+    /// Whether there were more elements than expected
+    pub fn is_too_long(&self) -> bool {
+        self.found > self.expected
+    }
+}
+
+impl fmt::Display for ArrayLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid length {}, expected an array of size {}",
+            self.found, self.expected
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArrayLengthError {}
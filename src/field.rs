@@ -0,0 +1,57 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::fmt;
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+
+/// Seed and `Visitor` to find which of a fixed set of names a map key is, without allocating
+///
+/// Yields the position of the matching name in `fields`, or `None` for any other key so that the
+/// caller can skip its value. Every module reading arrays out of a map identifies its keys through
+/// this seed, so the same key is matched the same way everywhere.
+pub(crate) struct FieldSeed<'a>(pub(crate) &'a [&'a str]);
+
+impl<'de, 'a> DeserializeSeed<'de> for FieldSeed<'a> {
+    type Value = Option<usize>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Keys are read as plain strings rather than identifiers, since formats such as bincode
+        // support maps with string keys but not `deserialize_identifier`
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for FieldSeed<'a> {
+    type Value = Option<usize>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for (i, field) in self.0.iter().enumerate() {
+            if i > 0 {
+                formatter.write_str(" or ")?;
+            }
+            write!(formatter, "`{}`", field)?;
+        }
+        Ok(())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.0.iter().position(|field| *field == v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(self.0.iter().position(|field| field.as_bytes() == v))
+    }
+}
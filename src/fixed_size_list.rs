@@ -0,0 +1,57 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays with Apache Arrow `FixedSizeList` semantics
+//!
+//! Serde bridges to columnar formats such as Arrow map Serde *sequences* to list columns, but
+//! generally don't know what to do with tuples, which is how this crate normally represents
+//! arrays. This module instead serializes `[T; N]` as a sequence that always declares its length
+//! as exactly `N` (`serialize_seq(Some(N))`), matching a `FixedSizeList` of size `N`, and
+//! deserializes a sequence back into `[T; N]`, rejecting any list whose length isn't `N`.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Embedding {
+//!     #[serde(with = "serde_arrays::fixed_size_list")]
+//!     vector: [f32; 64],
+//! }
+//! # let data = Embedding { vector: [0.5; 64] };
+//! # let json = serde_json::to_string(&data)?;
+//! # let de_data = serde_json::from_str(&json)?;
+//! # assert_eq!(data, de_data);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::ArrayVisitor;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+/// Serialize an array as a sequence of declared length `N`
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_seq(Some(N))?;
+    for item in data {
+        s.serialize_element(item)?;
+    }
+    s.end()
+}
+
+/// Deserialize an array from a sequence of exactly `N` elements
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(ArrayVisitor::<T, N>::new())
+}
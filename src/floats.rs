@@ -0,0 +1,208 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize float arrays with NaN and infinities intact
+//!
+//! Many human-readable formats have no way to write non-finite floats: JSON writes them as `null`,
+//! which then fails to deserialize as a float at all. For human-readable formats, this module
+//! writes NaN as the string `"nan"` and the infinities as `"inf"` and `"-inf"`, and reads them
+//! back again. Finite values are written as plain numbers, and binary formats, which represent
+//! every float natively, receive the values unchanged:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Samples {
+//!     #[serde(with = "serde_arrays::floats")]
+//!     values: [f64; 4],
+//! }
+//!
+//! let samples = Samples { values: [1.5, f64::NAN, f64::INFINITY, f64::NEG_INFINITY] };
+//! let json = serde_json::to_string(&samples)?;
+//! assert_eq!(json, r#"{"values":[1.5,"nan","inf","-inf"]}"#);
+//!
+//! let samples: Samples = serde_json::from_str(&json)?;
+//! assert!(samples.values[1].is_nan());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Plain [`serde_arrays`](crate) applies no such encoding, leaving non-finite values to the
+//! format: JSON, for one, writes them as `null` and then fails to read them back.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// A floating-point type whose non-finite values can be written as strings
+pub trait Float: Copy + Serialize {
+    /// Widen to an `f64`, which holds every value of `Self` exactly
+    fn to_f64(self) -> f64;
+
+    /// Convert from an `f64`, rounding to the nearest value of `Self`
+    fn from_f64(value: f64) -> Self;
+}
+
+impl Float for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+impl Float for f32 {
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+/// Serialize a float array, writing non-finite values as strings in human-readable formats
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Float,
+{
+    if !ser.is_human_readable() {
+        return crate::serialize(data, ser);
+    }
+
+    let mut s = ser.serialize_tuple(N)?;
+    for item in data {
+        s.serialize_element(&Element(*item))?;
+    }
+    s.end()
+}
+
+/// Deserialize a float array, reading non-finite values from strings in human-readable formats
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Float + Deserialize<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return crate::deserialize(deserializer);
+    }
+
+    deserializer.deserialize_tuple(
+        N,
+        FloatsVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A single float, written as a string if it isn't finite
+struct Element<T>(T);
+
+impl<T: Float> Serialize for Element<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = self.0.to_f64();
+        if value.is_nan() {
+            serializer.serialize_str("nan")
+        } else if value == f64::INFINITY {
+            serializer.serialize_str("inf")
+        } else if value == f64::NEG_INFINITY {
+            serializer.serialize_str("-inf")
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays of floats that may be written as strings
+struct FloatsVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for FloatsVisitor<T, N>
+where
+    T: Float,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| ElementSeed::<T> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Seed to deserialize a single float from a number or one of the non-finite strings
+struct ElementSeed<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Float> DeserializeSeed<'de> for ElementSeed<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, T: Float> Visitor<'de> for ElementSeed<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, r#"a number, "nan", "inf", or "-inf""#)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(T::from_f64(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(T::from_f64(v as f64))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(T::from_f64(v as f64))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        match v {
+            "nan" => Ok(T::from_f64(f64::NAN)),
+            "inf" => Ok(T::from_f64(f64::INFINITY)),
+            "-inf" => Ok(T::from_f64(f64::NEG_INFINITY)),
+            _ => Err(de::Error::invalid_value(de::Unexpected::Str(v), &self)),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays framed by their own length as the first element
+//!
+//! Some wire protocols write a fixed-size array as `[len, e0, e1, ...]`, repeating its length
+//! inside the array even though it never changes. This module writes `[T; N]` as a tuple of
+//! `N + 1` elements, the first of which is `N` itself as a `u64`, and reads it back the same way:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Packet {
+//!     #[serde(with = "serde_arrays::framed")]
+//!     words: [u32; 4],
+//! }
+//!
+//! let packet = Packet { words: [10, 20, 30, 40] };
+//! let json = serde_json::to_string(&packet)?;
+//! assert_eq!(json, r#"{"words":[4,10,20,30,40]}"#);
+//! assert_eq!(packet, serde_json::from_str(&json)?);
+//!
+//! assert!(serde_json::from_str::<Packet>(r#"{"words":[3,10,20,30]}"#).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! A declared length other than `N` is an error before any element is read, as is a declared
+//! length that doesn't match the number of elements which follow it. To have the format itself
+//! write the length instead, as with bincode's sequence length prefix, see
+//! [`prefixed`](crate::prefixed).
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// Serialize an array as its length followed by its elements
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_tuple(N + 1)?;
+    s.serialize_element(&(N as u64))?;
+    for item in data {
+        s.serialize_element(item)?;
+    }
+    s.end()
+}
+
+/// Deserialize an array from its length followed by exactly that many elements
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N + 1,
+        FramedVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays preceded by their length
+struct FramedVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for FramedVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)?;
+        write!(formatter, " preceded by its length")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let declared = match seq.next_element::<u64>()? {
+            Some(declared) => declared,
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+        if declared != N as u64 {
+            return Err(de::Error::custom(format_args!(
+                "declared length {} does not match an array of size {}",
+                declared, N
+            )));
+        }
+
+        crate::fill_array(seq, &self, |_| PhantomData)
+    }
+}
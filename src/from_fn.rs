@@ -0,0 +1,44 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/// Build a `[T; N]` from a fallible function of each element's index
+///
+/// This is a fallible counterpart to the standard library's `core::array::from_fn` (which needs
+/// Rust 1.63), and is the very code every array deserialized by this crate is built with. `f` is
+/// called with each index in turn, from `0` to `N - 1`; the first error it returns is returned in
+/// turn, after the elements already built are dropped. The same holds should `f` panic.
+///
+/// It's handy within hand-written `Deserialize` implementations, e.g. to read each element from
+/// a `SeqAccess`:
+///
+/// ```
+/// use serde::de::{self, SeqAccess};
+///
+/// fn read_array<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<[String; 40], A::Error> {
+///     serde_arrays::try_from_fn(|idx| {
+///         seq.next_element()?
+///             .ok_or_else(|| de::Error::invalid_length(idx, &"an array of size 40"))
+///     })
+/// }
+/// # let json = serde_json::to_string(&vec!["x"; 40])?;
+/// # let mut de = serde_json::Deserializer::from_str(&json);
+/// # struct V;
+/// # impl<'de> de::Visitor<'de> for V {
+/// #     type Value = [String; 40];
+/// #     fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result { write!(f, "a seq") }
+/// #     fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> { read_array(seq) }
+/// # }
+/// # let arr = serde::Deserializer::deserialize_seq(&mut de, V)?;
+/// # assert_eq!(arr[39], "x");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn try_from_fn<T, E, F, const N: usize>(f: F) -> Result<[T; N], E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    crate::try_build_array(f)
+}
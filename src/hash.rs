@@ -0,0 +1,95 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::hash::{Hash, Hasher};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A deterministic, non-randomized [`Hasher`] used by [`stable_hash`]
+///
+/// This is 64-bit FNV-1a, with every integer fed to it in little-endian byte order and `usize`
+/// and `isize` widened to 64 bits, so that the resulting hash is the same across runs, machines,
+/// and platforms. It is *not* suitable for use with untrusted keys in a `HashMap`.
+#[derive(Debug, Clone)]
+pub struct StableHasher {
+    state: u64,
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        StableHasher {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16)
+    }
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32)
+    }
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64)
+    }
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128)
+    }
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as u64)
+    }
+}
+
+/// Compute a stable hash of an array, e.g. for change detection or content addressing
+///
+/// Unlike the hashers used by `HashMap`, the result is reproducible across runs and platforms
+/// provided the elements' own `Hash` implementations are (as they are for integers, `bool`,
+/// `char`, and strings). See [`StableHasher`] for the algorithm; it will not change without a
+/// major version bump of this crate.
+///
+/// ```
+/// let a = serde_arrays::stable_hash(&[1u32; 40]);
+/// let b = serde_arrays::stable_hash(&[1u32; 40]);
+/// assert_eq!(a, b);
+/// ```
+pub fn stable_hash<T: Hash, const N: usize>(data: &[T; N]) -> u64 {
+    let mut hasher = StableHasher::default();
+    // Hash each element rather than the array itself, which would also feed in its length as a
+    // `usize`; `N` is fixed by the type anyway
+    for item in data {
+        item.hash(&mut hasher);
+    }
+    hasher.finish()
+}
@@ -0,0 +1,117 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize byte arrays as hex strings
+//!
+//! Hashes and keys are usually shown as hex, so this module writes `[u8; N]` as a single
+//! lowercase hex string for human-readable formats such as JSON. Other formats get a byte string,
+//! exactly as with [`bytes`](crate::bytes):
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Commit {
+//!     #[serde(with = "serde_arrays::hex")]
+//!     id: [u8; 4],
+//! }
+//!
+//! let commit = Commit { id: [0xDE, 0xAD, 0xBE, 0xEF] };
+//! let json = serde_json::to_string(&commit)?;
+//! assert_eq!(json, r#"{"id":"deadbeef"}"#);
+//! assert_eq!(commit, serde_json::from_str(r#"{"id":"DEADBEEF"}"#)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Hex digits of either case are accepted when deserializing. An odd number of digits, a
+//! character that isn't a hex digit, or the wrong number of bytes is an error; the string itself
+//! is left out of the error, since it may well be a secret key.
+
+use crate::bytes::BytesVisitor;
+use alloc::{format, string::String};
+use core::fmt;
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize a byte array as a lowercase hex string, or as a byte string for binary formats
+pub fn serialize<S, const N: usize>(data: &[u8; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if ser.is_human_readable() {
+        ser.collect_str(&HexDisplay(data))
+    } else {
+        ser.serialize_bytes(data)
+    }
+}
+
+/// Deserialize a byte array from a hex string, or from a byte string for binary formats
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(HexVisitor::<N>)
+    } else {
+        deserializer.deserialize_bytes(BytesVisitor::<N>)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [u8; N] arrays encoded as hex strings
+struct HexVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for HexVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a hex string of {} bytes", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        decode(v).map_err(|msg| de::Error::custom(format_args!("invalid hex string: {}", msg)))
+    }
+}
+
+/// Display adapter writing bytes as lowercase hex
+pub(crate) struct HexDisplay<'a>(pub(crate) &'a [u8]);
+
+impl<'a> fmt::Display for HexDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse a hex string (of either case) into exactly `N` bytes
+pub(crate) fn decode<const N: usize>(s: &str) -> Result<[u8; N], String> {
+    let len = s.chars().count();
+    if len % 2 == 1 {
+        return Err(format!("odd number of hex digits ({})", len));
+    }
+    if len / 2 != N {
+        return Err(format!("expected {} bytes, found {}", N, len / 2));
+    }
+
+    let mut digits = s.chars().map(|c| {
+        c.to_digit(16)
+            .map(|d| d as u8)
+            .ok_or_else(|| format!("invalid hex digit {:?}", c))
+    });
+    crate::try_build_array(|_| {
+        // The length was checked above, so there are always two more digits
+        let hi = digits.next().unwrap()?;
+        let lo = digits.next().unwrap()?;
+        Ok(hi << 4 | lo)
+    })
+}
@@ -0,0 +1,149 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize `BTreeMap<[u8; K], [V; N]>`, with byte-array keys as hex strings
+//!
+//! Human-readable formats such as JSON only allow strings as map keys, so for those each key is
+//! written as a lowercase hex string (and parsed back from hex of either case). Binary formats
+//! receive the key arrays as-is. Values are handled the same as with plain
+//! [`serde_arrays`](crate), and entries are always written in key order.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::collections::BTreeMap;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Routes {
+//!     #[serde(with = "serde_arrays::hex_key_map")]
+//!     by_addr: BTreeMap<[u8; 4], [u16; 2]>,
+//! }
+//!
+//! let mut by_addr = BTreeMap::new();
+//! by_addr.insert([192, 168, 0, 1], [80, 443]);
+//! let routes = Routes { by_addr };
+//!
+//! let json = serde_json::to_string(&routes)?;
+//! assert_eq!(json, r#"{"by_addr":{"c0a80001":[80,443]}}"#);
+//! # assert_eq!(routes, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{hex::HexDisplay, wrapper::ArrayWrap, ArraySeed};
+use alloc::collections::BTreeMap;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+
+/// Serialize a map with byte-array keys and array values
+pub fn serialize<S, V, const K: usize, const N: usize>(
+    data: &BTreeMap<[u8; K], [V; N]>,
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: Serialize,
+{
+    let human_readable = ser.is_human_readable();
+    let mut map = ser.serialize_map(Some(data.len()))?;
+    for (key, value) in data {
+        if human_readable {
+            map.serialize_key(&format_args!("{}", HexDisplay(key)))?;
+        } else {
+            map.serialize_key(&ArrayWrap::new(key))?;
+        }
+        map.serialize_value(&ArrayWrap::new(value))?;
+    }
+    map.end()
+}
+
+/// Deserialize a map with byte-array keys and array values
+pub fn deserialize<'de, D, V, const K: usize, const N: usize>(
+    deserializer: D,
+) -> Result<BTreeMap<[u8; K], [V; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: Deserialize<'de>,
+{
+    let human_readable = deserializer.is_human_readable();
+    deserializer.deserialize_map(HexKeyMapVisitor::<V, K, N> {
+        human_readable,
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for maps of byte-array keys to [V; N] arrays
+struct HexKeyMapVisitor<V, const K: usize, const N: usize> {
+    human_readable: bool,
+    _marker: PhantomData<V>,
+}
+
+impl<'de, V, const K: usize, const N: usize> Visitor<'de> for HexKeyMapVisitor<V, K, N>
+where
+    V: Deserialize<'de>,
+{
+    type Value = BTreeMap<[u8; K], [V; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a map of {}-byte keys to arrays of size {}",
+            K, N
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut data = BTreeMap::new();
+        while let Some(key) = map.next_key_seed(KeySeed::<K> {
+            human_readable: self.human_readable,
+        })? {
+            let value = map.next_value_seed(ArraySeed::<V, N>::new())?;
+            data.insert(key, value);
+        }
+        Ok(data)
+    }
+}
+
+/// Seed and `Visitor` to deserialize a `[u8; K]` map key
+struct KeySeed<const K: usize> {
+    human_readable: bool,
+}
+
+impl<'de, const K: usize> DeserializeSeed<'de> for KeySeed<K> {
+    type Value = [u8; K];
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.human_readable {
+            deserializer.deserialize_str(self)
+        } else {
+            crate::deserialize(deserializer)
+        }
+    }
+}
+
+impl<'de, const K: usize> Visitor<'de> for KeySeed<K> {
+    type Value = [u8; K];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a hex string of {} bytes", K)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        crate::hex::decode(v)
+            .map_err(|msg| de::Error::custom(format_args!("invalid hex key: {}", msg)))
+    }
+}
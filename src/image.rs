@@ -0,0 +1,119 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize images stored as `[[T; W]; H]`, reporting the row of any error
+//!
+//! The image is written as an array of `H` rows, each written as [`serde_arrays`](crate) writes a
+//! `[T; W]`, exactly as with [`nested::Rows`](crate::nested::Rows). Should a row fail to
+//! deserialize, though, the error says which one:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
+//! struct Rgba(u8, u8, u8, u8);
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Sprite {
+//!     #[serde(with = "serde_arrays::image")]
+//!     pixels: [[Rgba; 40]; 3],
+//! }
+//!
+//! let rows = vec![vec![[0; 4]; 40], vec![[0; 4]; 40], vec![[0; 4]; 39]];
+//! let json = format!(r#"{{"pixels":{}}}"#, serde_json::to_string(&rows)?);
+//! let err = serde_json::from_str::<Sprite>(&json).unwrap_err();
+//! assert!(err.to_string().starts_with("row 2: invalid length 39, expected an array of size 40"));
+//! # let sprite = Sprite { pixels: [[Rgba(1, 2, 3, 4); 40]; 3] };
+//! # let json = serde_json::to_string(&sprite)?;
+//! # assert_eq!(sprite, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Rows are counted from 0, the top of the image.
+
+use crate::ArraySeed;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize an image as an array of rows
+pub fn serialize<S, T, const W: usize, const H: usize>(
+    data: &[[T; W]; H],
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::nested::Rows::serialize(data, ser)
+}
+
+/// Deserialize an image from an array of rows, naming the row of any error
+pub fn deserialize<'de, D, T, const W: usize, const H: usize>(
+    deserializer: D,
+) -> Result<[[T; W]; H], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        H,
+        ImageVisitor::<T, W, H> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for [[T; W]; H] images
+struct ImageVisitor<T, const W: usize, const H: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const W: usize, const H: usize> Visitor<'de> for ImageVisitor<T, W, H>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [[T; W]; H];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an image of {} rows of {} pixels", H, W)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |row| RowSeed::<T, W> {
+            row,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Seed to deserialize a single row, naming it in any error
+struct RowSeed<T, const W: usize> {
+    row: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const W: usize> DeserializeSeed<'de> for RowSeed<T, W>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; W];
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        ArraySeed::<T, W>::new()
+            .deserialize(deserializer)
+            .map_err(|err| de::Error::custom(format_args!("row {}: {}", self.row, err)))
+    }
+}
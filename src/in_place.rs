@@ -0,0 +1,77 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::fmt;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+
+/// Deserialize an array of exactly `N` elements into an existing array
+///
+/// Each element is assigned to its slot in `place` as soon as it is read, so no new array is built
+/// and moved. This is useful for reusing a buffer across many messages:
+///
+/// ```
+/// let mut buf = [0u32; 40];
+/// for msg in &[vec![1; 40], vec![2; 40]] {
+///     let json = serde_json::to_string(msg)?;
+///     let mut de = serde_json::Deserializer::from_str(&json);
+///     serde_arrays::deserialize_into(&mut buf, &mut de)?;
+///     assert_eq!(buf[..], msg[..]);
+/// }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// Should an error occur, the array is left partly overwritten: if the `k`th element (counting
+/// from 0) is missing or fails to deserialize, elements `0..k` hold the new values and elements
+/// `k..N` are untouched. If the input holds more than `N` elements, all `N` have been overwritten.
+/// Every element always remains valid, and is dropped as usual when replaced.
+///
+/// This is not usable with `#[serde(with)]`, but rather intended for manual `Deserialize`
+/// implementations or direct use with a `Deserializer`.
+pub fn deserialize_into<'de, D, T, const N: usize>(
+    place: &mut [T; N],
+    deserializer: D,
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(N, InPlaceVisitor { place })
+}
+
+/// A Serde Deserializer `Visitor` that overwrites the elements of an existing [T; N] array
+struct InPlaceVisitor<'a, T, const N: usize> {
+    place: &'a mut [T; N],
+}
+
+impl<'de, 'a, T, const N: usize> Visitor<'de> for InPlaceVisitor<'a, T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        for idx in 0..N {
+            match seq.next_element()? {
+                Some(val) => self.place[idx] = val,
+                None => return Err(de::Error::invalid_length(idx, &self)),
+            }
+        }
+
+        // Make sure there aren't any elements left over
+        match seq.next_element::<de::IgnoredAny>()? {
+            Some(_) => Err(crate::too_long(seq, N, &self)),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays as maps keyed by element index
+//!
+//! `[a, b, c]` is written as `{"0": a, "1": b, "2": c}`, which makes for readable diffs of files
+//! that humans edit. On input the entries may be in any order, but every index must be present
+//! exactly once.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Weights {
+//!     #[serde(with = "serde_arrays::indexed_map")]
+//!     weights: [u32; 3],
+//! }
+//!
+//! let w: Weights = serde_json::from_str(r#"{"weights":{"2":30,"0":10,"1":20}}"#)?;
+//! assert_eq!(w.weights, [10, 20, 30]);
+//! # assert_eq!(serde_json::to_string(&w)?, r#"{"weights":{"0":10,"1":20,"2":30}}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! For formats whose indices start at some other number, such as 1-based scientific data, use
+//! [`IndexedMap`] with the appropriate base instead:
+//!
+//! ```
+//! # use serde::{Serialize, Deserialize};
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Weights {
+//!     #[serde(with = "serde_arrays::indexed_map::IndexedMap::<1>")]
+//!     weights: [u32; 3],
+//! }
+//!
+//! let w: Weights = serde_json::from_str(r#"{"weights":{"1":10,"2":20,"3":30}}"#)?;
+//! assert_eq!(w.weights, [10, 20, 30]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::{convert::TryFrom, fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+
+/// Serialize an array as a map keyed by 0-based index
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    IndexedMap::<0>::serialize(data, ser)
+}
+
+/// Deserialize an array from a map keyed by 0-based index
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    IndexedMap::<0>::deserialize(deserializer)
+}
+
+/// Serialize and deserialize `[T; N]` as a map with keys `BASE..BASE + N`
+///
+/// Use as `#[serde(with = "serde_arrays::indexed_map::IndexedMap::<BASE>")]`.
+pub struct IndexedMap<const BASE: usize>;
+
+impl<const BASE: usize> IndexedMap<BASE> {
+    /// Serialize an array as a map keyed by index, starting from `BASE`
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut map = ser.serialize_map(Some(N))?;
+        for (idx, item) in data.iter().enumerate() {
+            map.serialize_entry(&(BASE + idx), item)?;
+        }
+        map.end()
+    }
+
+    /// Deserialize an array from a map keyed by index, starting from `BASE`
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_map(IndexedVisitor::<T, N, BASE> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays represented as index-keyed maps
+struct IndexedVisitor<T, const N: usize, const BASE: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize, const BASE: usize> Visitor<'de> for IndexedVisitor<T, N, BASE>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a map with the indices {} to {} as keys",
+            BASE,
+            (BASE + N).saturating_sub(1)
+        )
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        fill_from_map::<_, _, N, BASE>(map)
+    }
+}
+
+/// Fill a `[T; N]` array from a map keyed by index, starting from `BASE`
+///
+/// The entries may be in any order, but every index must be present exactly once.
+pub(crate) fn fill_from_map<'de, A, T, const N: usize, const BASE: usize>(
+    mut map: A,
+) -> Result<[T; N], A::Error>
+where
+    A: MapAccess<'de>,
+    T: Deserialize<'de>,
+{
+    let mut slots: [Option<T>; N] = crate::try_build_array(|_| Ok::<_, A::Error>(None))?;
+
+    while let Some(key) = map.next_key_seed(IndexSeed)? {
+        let slot = key
+            .checked_sub(BASE)
+            .and_then(|idx| slots.get_mut(idx))
+            .ok_or_else(|| {
+                de::Error::custom(format_args!(
+                    "index {} is out of range for an array of size {} with indices starting at {}",
+                    key, N, BASE
+                ))
+            })?;
+        if slot.is_some() {
+            return Err(de::Error::custom(format_args!("duplicate index {}", key)));
+        }
+        *slot = Some(map.next_value()?);
+    }
+
+    crate::try_build_array(|idx| {
+        slots[idx]
+            .take()
+            .ok_or_else(|| de::Error::custom(format_args!("missing index {}", BASE + idx)))
+    })
+}
+
+/// Seed and `Visitor` to deserialize an index, whether it is written as an integer or a string
+pub(crate) struct IndexSeed;
+
+impl<'de> DeserializeSeed<'de> for IndexSeed {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_u64(self)
+    }
+}
+
+impl<'de> Visitor<'de> for IndexSeed {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array index")
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        usize::try_from(v).map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        usize::try_from(v).map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize [`IndexMap`]s whose values are arrays
+//!
+//! Requires the `indexmap` feature. Entries are written in the map's order, and deserialized in
+//! the order they appear in the input, so the order of keys survives a round trip:
+//!
+//! ```
+//! use indexmap::IndexMap;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Config {
+//!     #[serde(with = "serde_arrays::indexmap_values")]
+//!     tables: IndexMap<String, [u32; 16]>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(&format!(
+//!     r#"{{"tables":{{"zeta":{0},"alpha":{0}}}}}"#,
+//!     serde_json::to_string(&[0; 16])?,
+//! ))?;
+//! assert_eq!(config.tables.keys().collect::<Vec<_>>(), ["zeta", "alpha"]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Should a value fail to deserialize, the error names the key it belongs to.
+//!
+//! [`IndexMap`]: ::indexmap::IndexMap
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use ::indexmap::IndexMap;
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+
+/// Serialize a map with array values, in the map's order
+pub fn serialize<S, K, T, H, const N: usize>(
+    data: &IndexMap<K, [T; N], H>,
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize,
+    T: Serialize,
+{
+    let mut map = ser.serialize_map(Some(data.len()))?;
+    for (key, value) in data {
+        map.serialize_entry(key, &ArrayWrap::new(value))?;
+    }
+    map.end()
+}
+
+/// Deserialize a map with array values, in the order of the input
+pub fn deserialize<'de, D, K, T, H, const N: usize>(
+    deserializer: D,
+) -> Result<IndexMap<K, [T; N], H>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    T: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    deserializer.deserialize_map(IndexMapValuesVisitor::<K, T, H, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for index maps of [T; N] arrays
+struct IndexMapValuesVisitor<K, T, H, const N: usize> {
+    _marker: PhantomData<(K, T, H)>,
+}
+
+impl<'de, K, T, H, const N: usize> Visitor<'de> for IndexMapValuesVisitor<K, T, H, N>
+where
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    T: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    type Value = IndexMap<K, [T; N], H>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map with arrays of size {} as values", N)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Don't trust the size hint so far as to allocate an unbounded amount of memory up front
+        let mut data = IndexMap::with_capacity_and_hasher(
+            map.size_hint().unwrap_or(0).min(4096),
+            H::default(),
+        );
+        while let Some(key) = map.next_key::<K>()? {
+            let value = map
+                .next_value_seed(ArraySeed::<T, N>::new())
+                .map_err(|err| de::Error::custom(format_args!("for key {:?}: {}", key, err)))?;
+            data.insert(key, value);
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Safe construction of arrays one element at a time
+//!
+//! Every array this crate produces is built through [`ArrayBuilder`], which keeps all of the
+//! `unsafe` code involved in one place.
+
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+/// A `[T; N]` array under construction
+///
+/// Elements are pushed in order, from index `0` upwards, and once all `N` are in place the array
+/// is taken out with [`finish`](Self::finish). Should the builder be dropped before then, e.g.
+/// because building the array failed or panicked, the elements pushed so far are dropped with it.
+pub(crate) struct ArrayBuilder<T, const N: usize> {
+    /// The array, of which exactly the first `len` elements are initialized
+    arr: MaybeUninit<[T; N]>,
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayBuilder<T, N> {
+    /// Create a builder with no elements yet
+    pub(crate) fn new() -> Self {
+        ArrayBuilder {
+            arr: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    /// The number of elements pushed so far
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether all `N` elements have been pushed
+    pub(crate) fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Append an element, or hand it back if the array is already full
+    pub(crate) fn push(&mut self, val: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(val);
+        }
+
+        // Safety: `len < N`, so the pointer is within the array, and the element there has not
+        // been initialized yet
+        unsafe { (self.arr.as_mut_ptr() as *mut T).add(self.len).write(val) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Take the finished array
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `N` elements have been pushed; the elements that have been are dropped.
+    pub(crate) fn finish(self) -> [T; N] {
+        assert!(
+            self.is_full(),
+            "only {} of {} array elements have been pushed",
+            self.len,
+            N
+        );
+
+        // Every element is now owned by the finished array rather than the builder
+        let this = ManuallyDrop::new(self);
+        // Safety: all `N` elements have been initialized, and `this` is never used or dropped again
+        unsafe { ptr::read(&this.arr).assume_init() }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayBuilder<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayBuilder<T, N> {
+    fn drop(&mut self) {
+        // Safety: exactly the first `len` elements have been initialized, and none of them will be
+        // used again
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                self.arr.as_mut_ptr() as *mut T,
+                self.len,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // `ArrayBuilder` holds all of the crate's `unsafe` code. Run these under Miri to check for
+    // undefined behavior and leaks:
+    //
+    //     cargo +nightly miri test --lib init
+
+    extern crate std;
+
+    use super::ArrayBuilder;
+    use std::{
+        cell::Cell,
+        string::{String, ToString},
+    };
+
+    /// Counts how many times it has been dropped
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn builder_exact_finish() {
+        let mut builder = ArrayBuilder::<String, 4>::new();
+        assert_eq!(builder.len(), 0);
+        for i in 0..4 {
+            assert!(!builder.is_full());
+            builder.push(i.to_string()).unwrap();
+            assert_eq!(builder.len(), i + 1);
+        }
+        assert!(builder.is_full());
+
+        let arr = builder.finish();
+        assert_eq!(arr, ["0", "1", "2", "3"]);
+    }
+
+    #[test]
+    fn builder_empty_array() {
+        let builder = ArrayBuilder::<String, 0>::default();
+        assert!(builder.is_full());
+
+        let arr: [String; 0] = builder.finish();
+        assert!(arr.is_empty());
+    }
+
+    #[test]
+    fn builder_drops_partial_fill() {
+        let drops = Cell::new(0);
+        let mut builder = ArrayBuilder::<DropCounter, 8>::new();
+        for _ in 0..5 {
+            assert!(builder.push(DropCounter(&drops)).is_ok());
+        }
+        assert_eq!(drops.get(), 0);
+
+        drop(builder);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn builder_finish_transfers_ownership() {
+        let drops = Cell::new(0);
+        let mut builder = ArrayBuilder::<DropCounter, 3>::new();
+        for _ in 0..3 {
+            assert!(builder.push(DropCounter(&drops)).is_ok());
+        }
+
+        let arr = builder.finish();
+        assert_eq!(drops.get(), 0);
+        drop(arr);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn builder_overflow_push_hands_value_back() {
+        let mut builder = ArrayBuilder::<String, 2>::new();
+        builder.push("a".to_string()).unwrap();
+        builder.push("b".to_string()).unwrap();
+
+        assert_eq!(builder.push("c".to_string()), Err("c".to_string()));
+        assert_eq!(builder.len(), 2);
+        assert_eq!(builder.finish(), ["a", "b"]);
+    }
+
+    #[test]
+    fn builder_finish_partial_panics_and_drops() {
+        let drops = Cell::new(0);
+        let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut builder = ArrayBuilder::<DropCounter, 4>::new();
+            for _ in 0..2 {
+                assert!(builder.push(DropCounter(&drops)).is_ok());
+            }
+            builder.finish()
+        }));
+
+        let msg = *res.err().unwrap().downcast::<String>().unwrap();
+        assert_eq!(msg, "only 2 of 4 array elements have been pushed");
+        assert_eq!(drops.get(), 2);
+    }
+}
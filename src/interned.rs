@@ -0,0 +1,115 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize sequences of reference-counted arrays, sharing one allocation per distinct value
+//!
+//! Requires the `rc` and `std` features. Where the same array appears many times over, such as an
+//! ID repeated across thousands of records, this module deserializes a `Vec<Rc<[T; N]>>` (or
+//! `Vec<Arc<[T; N]>>`) so that equal arrays all point to a single allocation:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::rc::Rc;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Events {
+//!     #[serde(with = "serde_arrays::interned")]
+//!     sources: Vec<Rc<[u8; 16]>>,
+//! }
+//!
+//! let json = format!(r#"{{"sources":[{0:?},{1:?},{0:?}]}}"#, [1u8; 16], [2u8; 16]);
+//! let events: Events = serde_json::from_str(&json)?;
+//! assert!(Rc::ptr_eq(&events.sources[0], &events.sources[2]));
+//! # assert!(!Rc::ptr_eq(&events.sources[0], &events.sources[1]));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Serializing writes every array out in full, exactly as [`seq`](crate::seq) of plain arrays
+//! would; it is only deserializing that shares them. Arrays are interned within a single sequence,
+//! never across separate ones.
+
+use crate::{rc::Shared, wrapper::ArrayWrap, ArraySeed};
+use alloc::vec::Vec;
+use core::{borrow::Borrow, fmt, hash::Hash, marker::PhantomData, ops::Deref};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+use std::collections::HashSet;
+
+/// Serialize a sequence of reference-counted arrays, writing each array in full
+pub fn serialize<S, P, T, const N: usize>(data: &[P], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    P: Deref<Target = [T; N]>,
+    T: Serialize,
+{
+    let mut s = ser.serialize_seq(Some(data.len()))?;
+    for item in data {
+        s.serialize_element(&ArrayWrap::new(item))?;
+    }
+    s.end()
+}
+
+/// Deserialize a sequence of reference-counted arrays, sharing the allocation of equal arrays
+pub fn deserialize<'de, D, P, T, const N: usize>(deserializer: D) -> Result<Vec<P>, D::Error>
+where
+    D: Deserializer<'de>,
+    P: Shared<Target = [T; N]> + Borrow<[T; N]> + Clone + Eq + Hash,
+    T: Deserialize<'de> + Eq + Hash,
+{
+    deserializer.deserialize_seq(InternedVisitor::<P, T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for sequences of shared [T; N] arrays
+struct InternedVisitor<P, T, const N: usize> {
+    _marker: PhantomData<(P, T)>,
+}
+
+impl<'de, P, T, const N: usize> Visitor<'de> for InternedVisitor<P, T, N>
+where
+    P: Shared<Target = [T; N]> + Borrow<[T; N]> + Clone + Eq + Hash,
+    T: Deserialize<'de> + Eq + Hash,
+{
+    type Value = Vec<P>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of arrays of size {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // The set holds one pointer to each distinct array, and is found by the array itself
+        let mut interned = HashSet::<P>::new();
+        let mut data = Vec::with_capacity(crate::cautious_capacity::<P>(seq.size_hint()));
+        loop {
+            let arr = seq
+                .next_element_seed(ArraySeed::<T, N>::new())
+                .map_err(|err| {
+                    de::Error::custom(format_args!("at index {}: {}", data.len(), err))
+                })?;
+            let arr = match arr {
+                Some(arr) => arr,
+                None => return Ok(data),
+            };
+
+            let shared = match interned.get(&arr) {
+                Some(shared) => shared.clone(),
+                None => {
+                    let shared = P::new(arr);
+                    interned.insert(shared.clone());
+                    shared
+                }
+            };
+            data.push(shared);
+        }
+    }
+}
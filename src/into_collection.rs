@@ -0,0 +1,116 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize exactly `N` elements into any collection, not just an array
+//!
+//! [`IntoCollection`] reads an array of size `N` just as [`serde_arrays`](crate) does, including
+//! the length check, but collects the elements into any `C: FromIterator<T>` such as a `Vec`,
+//! `SmallVec`, or `ArrayVec`:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Row {
+//!     #[serde(with = "serde_arrays::into_collection::IntoCollection::<40>")]
+//!     cells: Vec<u32>,
+//! }
+//! # let row = Row { cells: vec![1; 40] };
+//! # let json = serde_json::to_string(&row)?;
+//! # assert_eq!(row, serde_json::from_str(&json)?);
+//! # assert!(serde_json::from_str::<Row>("[[1,2,3]]").is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Elements are streamed straight into the collection, so a collection with a fixed capacity
+//! smaller than `N` (e.g. an `ArrayVec`) will panic as it would with any other `collect`.
+
+use core::{fmt, iter::FromIterator, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// Serialize and deserialize a collection holding exactly `N` elements as an array of size `N`
+///
+/// Use as `#[serde(with = "serde_arrays::into_collection::IntoCollection::<N>")]`.
+pub struct IntoCollection<const N: usize>;
+
+impl<const N: usize> IntoCollection<N> {
+    /// Serialize a collection as an array, failing if it doesn't hold exactly `N` elements
+    pub fn serialize<S, C, T>(data: &C, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        C: AsRef<[T]>,
+        T: Serialize,
+    {
+        let data = data.as_ref();
+        if data.len() != N {
+            return Err(serde::ser::Error::custom(format_args!(
+                "expected a collection of {} elements, found {}",
+                N,
+                data.len()
+            )));
+        }
+
+        let mut s = ser.serialize_tuple(N)?;
+        for item in data {
+            s.serialize_element(item)?;
+        }
+        s.end()
+    }
+
+    /// Deserialize an array of size `N`, collecting its elements into `C`
+    pub fn deserialize<'de, D, C, T>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+        C: FromIterator<T>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(
+            N,
+            CollectionVisitor::<C, T, N> {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// A Serde Deserializer `Visitor` collecting exactly `N` elements into `C`
+struct CollectionVisitor<C, T, const N: usize> {
+    _marker: PhantomData<(C, T)>,
+}
+
+impl<'de, C, T, const N: usize> Visitor<'de> for CollectionVisitor<C, T, N>
+where
+    C: FromIterator<T>,
+    T: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let collection = (0..N)
+            .map(|idx| {
+                seq.next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(idx, &self))
+            })
+            .collect::<Result<C, A::Error>>()?;
+
+        // Make sure there aren't any elements left over
+        match seq.next_element::<de::IgnoredAny>()? {
+            Some(_) => Err(crate::too_long(seq, N, &self)),
+            None => Ok(collection),
+        }
+    }
+}
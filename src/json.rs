@@ -0,0 +1,72 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArraySeed, Serializable};
+use alloc::string::String;
+use core::marker::PhantomData;
+use serde::{
+    de::{Deserialize, DeserializeSeed},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize an array to a JSON string on its own, without a containing struct
+///
+/// Requires the `serde_json` feature. The array is written exactly as `#[serde(with =
+/// "serde_arrays")]` would write it as a field, which is handy when the array is the whole payload:
+///
+/// ```
+/// let data = [7u32; 36];
+/// let json = serde_arrays::to_json_string(&data)?;
+/// assert!(json.starts_with("[7,7,7,"));
+///
+/// let back: [u32; 36] = serde_arrays::from_json_str(&json)?;
+/// assert_eq!(data, back);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn to_json_string<A, T, const N: usize>(data: &A) -> serde_json::Result<String>
+where
+    A: Serializable<T, N>,
+    T: Serialize,
+{
+    serde_json::to_string(&Wrap {
+        inner: data,
+        _marker: PhantomData::<T>,
+    })
+}
+
+/// Deserialize an array from a JSON string holding nothing else
+///
+/// Requires the `serde_json` feature. This is the counterpart of [`to_json_string`]; trailing
+/// characters other than whitespace are an error, just as with [`serde_json::from_str`].
+pub fn from_json_str<'a, T, const N: usize>(s: &'a str) -> serde_json::Result<[T; N]>
+where
+    T: Deserialize<'a>,
+{
+    let mut deserializer = serde_json::Deserializer::from_str(s);
+    let data = ArraySeed::<T, N>::new().deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(data)
+}
+
+/// Wraps any [`Serializable`] type so it can be passed to Serde as a `Serialize` value
+struct Wrap<'a, A, T, const N: usize> {
+    inner: &'a A,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, A, T, const N: usize> Serialize for Wrap<'a, A, T, N>
+where
+    A: Serializable<T, N>,
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::serialize(self.inner, serializer)
+    }
+}
@@ -70,6 +70,8 @@
 //! Even nested arrays are supported:
 //!
 //! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
 //! # use serde::{Serialize, Deserialize};
 //! # use serde_json;
 //! #[derive(Serialize, Debug, PartialEq, Eq)]
@@ -83,12 +85,38 @@
 //! # let json = serde_json::to_string(&data)?;
 //! # //let de_data = serde_json::from_str(&json)?;
 //! # //assert_eq!(data, de_data);
+//! # }
 //! # Ok::<(), serde_json::Error>(())
 //! ```
 //!
+//! # Features
+//!
+//! This crate supports `no_std`. The `std` feature is enabled by default; the core
+//! [`serialize`]/[`deserialize`] functions and the modules that need no allocation work without
+//! it. Modules that need to allocate (e.g. [`columnar`] and support for `Vec<[T; N]>`) require the
+//! `alloc` feature, and a few (e.g. [`string_dict`]) require `std` itself:
+//!
+//! ```toml
+//! [dependencies]
+//! serde_arrays = { version = "0.1", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! The `type-names` feature names the element type in error messages, e.g. "invalid length 3,
+//! expected an array of size 4 of `u32`", which helps tell apart arrays of the same size. It is
+//! off by default, since the names come from [`core::any::type_name`], whose output is meant for
+//! diagnostics only and may change between compiler versions.
+//!
+//! Support for `Rc<[T; N]>` and `Arc<[T; N]>` is behind the `rc` feature, as it is in Serde.
+//!
+//! Support for types from other crates is behind optional features named after those crates, such
+//! as `arrayvec`, `base64`, `bitvec`, `indexmap`, `ndarray`, and `smallvec`. The `serde_json`
+//! feature adds [`to_json_string`] and [`from_json_str`] for arrays that make up a whole JSON
+//! document, and the `schemars` feature describes arrays of any size in JSON Schema.
+//!
 //! # MSRV
 //!
-//! This library relies on the const generics feature introduced in Rust 1.51.0.
+//! This library relies on the const generics feature introduced in Rust 1.51.0. The MSRV covers the
+//! library itself; its tests and benchmarks rely on dev-dependencies that need a newer compiler.
 //!
 //! # Relevant links
 //!
@@ -100,16 +128,167 @@
 //!
 //! [Serde]: https://serde.rs/
 
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::{fmt, marker::PhantomData};
 use serde::{
-    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    de::{self, Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
     ser::{Serialize, Serializer},
 };
-use std::{fmt, marker::PhantomData, mem::MaybeUninit};
 
+pub mod adaptive;
+pub mod adjusting;
+mod arr;
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+mod as_ref;
+#[cfg(all(feature = "base64", feature = "alloc"))]
+pub mod base64;
+#[cfg(all(feature = "bitvec", feature = "alloc"))]
+pub mod bit_array;
+pub mod bitset;
+#[cfg(feature = "alloc")]
+pub mod boxed;
+pub mod broadcast;
+#[cfg(feature = "alloc")]
+pub mod btree_map_values;
+#[cfg(feature = "alloc")]
+mod byte_buf;
+pub mod bytes;
+#[cfg(feature = "alloc")]
+pub mod capped;
+pub mod char_string;
+#[cfg(feature = "std")]
+pub mod checked;
+mod checksum;
+#[cfg(feature = "alloc")]
+pub mod codec;
+mod collect;
+#[cfg(feature = "alloc")]
+pub mod columnar;
+pub mod counted;
+#[cfg(feature = "alloc")]
+pub mod cow_bytes;
+pub mod cstr;
+#[cfg(feature = "alloc")]
+pub mod csv_string;
+pub mod default_filled;
+mod defaults;
+#[cfg(feature = "alloc")]
+mod delimited;
+mod error;
+mod field;
+pub mod fixed_size_list;
+pub mod floats;
+pub mod framed;
+mod from_fn;
+mod hash;
+#[cfg(feature = "alloc")]
+pub mod hex;
+#[cfg(feature = "alloc")]
+pub mod hex_key_map;
+pub mod image;
+mod in_place;
+pub mod indexed_map;
+#[cfg(feature = "indexmap")]
+pub mod indexmap_values;
+mod init;
+#[cfg(all(feature = "rc", feature = "std"))]
+pub mod interned;
+pub mod into_collection;
+#[cfg(all(feature = "serde_json", feature = "alloc"))]
+mod json;
+#[cfg(feature = "std")]
+pub mod lock;
+mod macros;
+#[cfg(feature = "std")]
+pub mod map_values;
+pub mod matrix;
+pub mod mean_centered;
+pub mod named;
+pub mod named_fields;
+#[cfg(all(feature = "ndarray", feature = "alloc"))]
+pub mod ndarray;
+pub mod nested;
+pub mod nested_seq;
+pub mod null_as_default;
+pub mod option;
+pub mod optional_tail;
+pub mod padded;
+pub mod pair;
+pub mod prefixed;
+pub mod raw;
+#[cfg(feature = "rc")]
+pub mod rc;
+pub mod rle;
+#[cfg(feature = "std")]
+pub mod rounded;
+#[cfg(all(feature = "schemars", feature = "alloc"))]
+pub mod schemars;
+pub mod seq;
+pub mod serde_bytes;
 #[doc(hidden)]
 pub mod serializable;
+pub mod set;
+pub mod singleton;
+mod slice;
+#[cfg(all(feature = "smallvec", feature = "alloc"))]
+pub mod smallvec;
+#[cfg(feature = "std")]
+pub mod smoothed;
+pub mod sparse;
+mod split;
+mod stream;
+#[cfg(feature = "std")]
+pub mod string_dict;
+pub mod tagged;
+pub mod tiled;
+pub mod time;
+pub mod transparent;
+pub mod truncating;
+mod try_deserialize;
+#[cfg(feature = "std")]
+mod unique;
+pub mod unwrap_field;
+mod validated;
+#[cfg(feature = "alloc")]
+mod vec_conversion;
+pub mod versioned;
+#[cfg(feature = "alloc")]
+pub mod wasm;
+#[cfg(feature = "alloc")]
+pub mod whitespace;
 mod wrapper;
+pub use arr::Arr;
+pub use as_ref::serialize_ref;
+#[cfg(feature = "alloc")]
+pub use boxed::deserialize_boxed_slice;
+pub use checksum::verify_checksum;
+pub use collect::collect_array;
+pub use defaults::{default_array, is_all_default};
+pub use error::{ArrayError, ArrayLengthError};
+pub use from_fn::try_from_fn;
+pub use hash::{stable_hash, StableHasher};
+pub use in_place::deserialize_into;
+#[cfg(all(feature = "serde_json", feature = "alloc"))]
+pub use json::{from_json_str, to_json_string};
+pub use matrix::Matrix;
+pub use named::deserialize_named;
 pub use serializable::Serializable;
+pub use slice::serialize_slice;
+pub use split::{concat_array, split_array};
+pub use stream::deserialize_stream;
+pub use try_deserialize::try_deserialize;
+#[cfg(feature = "std")]
+pub use unique::deserialize_unique;
+pub use validated::deserialize_validated;
+#[cfg(feature = "alloc")]
+pub use vec_conversion::try_from_vec;
 
 /// Serialize const generic or arbitrarily-large arrays
 ///
@@ -118,6 +297,11 @@ pub use serializable::Serializable;
 ///
 /// For greater flexibility see [`serde_with`][serde_with].
 ///
+/// Arrays are serialized as tuples whether or not the format is human-readable. A tuple is already
+/// Serde's most compact representation of an array: its length is known from the type, so binary
+/// formats such as bincode write neither a length prefix nor any per-element framing, and a
+/// `[u16; 4]` takes exactly 8 bytes. (For `[u8; N]`, see [`bytes`] for a byte string instead.)
+///
 /// [serde_with]: https://crates.io/crates/serde_with/
 pub fn serialize<A, S, T, const N: usize>(data: &A, ser: S) -> Result<S::Ok, S::Error>
 where
@@ -129,11 +313,52 @@ where
 }
 
 /// A Serde Deserializer `Visitor` for [T; N] arrays
-struct ArrayVisitor<T, const N: usize> {
+///
+/// This is the visitor behind [`deserialize`], exposed for use in hand-written `Deserialize`
+/// implementations:
+///
+/// ```
+/// use serde::{Deserialize, Deserializer};
+/// use serde_arrays::ArrayVisitor;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Palette([u32; 48]);
+///
+/// impl<'de> Deserialize<'de> for Palette {
+///     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+///     where
+///         D: Deserializer<'de>,
+///     {
+///         deserializer
+///             .deserialize_tuple(48, ArrayVisitor::new())
+///             .map(Palette)
+///     }
+/// }
+/// # let json = serde_json::to_string(&vec![7u32; 48])?;
+/// # let palette: Palette = serde_json::from_str(&json)?;
+/// # assert_eq!(palette, Palette([7; 48]));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct ArrayVisitor<T, const N: usize> {
     // Literally nothing (a "phantom"), but stops Rust complaining about the "unused" T parameter
     _marker: PhantomData<T>,
 }
 
+impl<T, const N: usize> ArrayVisitor<T, N> {
+    /// Create a new visitor
+    pub fn new() -> Self {
+        ArrayVisitor {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVisitor<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
 where
     T: Deserialize<'de>,
@@ -142,61 +367,214 @@ where
 
     /// Format a message stating we expect an array of size `N`
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "an array of size {}", N)
+        expecting_array::<T, N>(formatter)
     }
 
     /// Process a sequence into an array
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
-        // Safety: `assume_init` is sound because the type we are claiming to have
-        // initialized here is a bunch of `MaybeUninit`s, which do not require
-        // initialization.
-        let mut arr: [MaybeUninit<T>; N] = unsafe { MaybeUninit::uninit().assume_init() };
-
-        // Iterate over the array and fill the elemenets with the ones obtained from
-        // `seq`.
-        let mut place_iter = arr.iter_mut();
-        let mut cnt_filled = 0;
-        let err = loop {
-            match (seq.next_element(), place_iter.next()) {
-                (Ok(Some(val)), Some(place)) => *place = MaybeUninit::new(val),
-                // no error, we're done
-                (Ok(None), None) => break None,
-                // error from serde, propagate it
-                (Err(e), _) => break Some(e),
-                // lengths do not match, report invalid_length
-                (Ok(None), Some(_)) | (Ok(Some(_)), None) => {
-                    break Some(de::Error::invalid_length(cnt_filled, &self))
-                }
-            }
-            cnt_filled += 1;
-        };
-        if let Some(err) = err {
-            if std::mem::needs_drop::<T>() {
-                for elem in std::array::IntoIter::new(arr).take(cnt_filled) {
-                    // Safety: `assume_init()` is sound because we did initialize CNT_FILLED
-                    // elements. We call it to drop the deserialized values.
-                    unsafe {
-                        elem.assume_init();
-                    }
-                }
-            }
-            return Err(err);
+        fill_array(seq, &self, |_| PhantomData)
+    }
+
+    /// Process a map keyed by index into an array
+    ///
+    /// This is only reached through `deserialize_any` in self-describing formats; a format that
+    /// honours the requested tuple, such as JSON, reports a map as an invalid type instead. To
+    /// accept index-keyed maps from JSON, use [`indexed_map`].
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        indexed_map::fill_from_map::<_, _, N, 0>(map)
+    }
+}
+
+/// A `DeserializeSeed` for [T; N] arrays, for deserializing arrays nested inside other types
+pub(crate) struct ArraySeed<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<T, const N: usize> ArraySeed<T, N> {
+    pub(crate) fn new() -> Self {
+        ArraySeed {
+            _marker: PhantomData,
         }
+    }
+}
 
-        // Safety: everything is initialized and we are ready to transmute to the
-        // initialized array type.
+impl<'de, T, const N: usize> DeserializeSeed<'de> for ArraySeed<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
 
-        // See https://github.com/rust-lang/rust/issues/62875#issuecomment-513834029
-        //let ret = unsafe { std::mem::transmute::<_, [T; N]>(arr) };
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer)
+    }
+}
 
-        let ret = unsafe { std::mem::transmute_copy(&arr) };
-        std::mem::forget(arr);
+/// Describe an array of `N` elements of type `T`, as a `Visitor`'s `expecting` message
+///
+/// This reads "an array of size 4", or with the `type-names` feature "an array of size 4 of
+/// `u32`".
+pub(crate) fn expecting_array<T, const N: usize>(formatter: &mut fmt::Formatter) -> fmt::Result {
+    expecting_len::<T>(formatter, N)
+}
 
-        Ok(ret)
+/// Describe an array of `len` elements of type `T`, where `len` is only known at runtime
+pub(crate) fn expecting_len<T>(formatter: &mut fmt::Formatter, len: usize) -> fmt::Result {
+    write!(formatter, "an array of size {}", len)?;
+    if cfg!(feature = "type-names") {
+        write!(formatter, " of `{}`", core::any::type_name::<T>())?;
     }
+    Ok(())
+}
+
+/// Fill a `[T; N]` array from a sequence
+///
+/// Each element is deserialized using the seed returned by `seed` for its index, which lets the
+/// various modules in this crate customize how elements are read while sharing the array-building
+/// logic. If the sequence does not contain exactly `N` elements, an `invalid_length` error is
+/// returned using `expected` to describe the array; should the format report the length of the
+/// sequence up front, this happens before any element is read.
+pub(crate) fn fill_array<'de, A, S, F, const N: usize>(
+    mut seq: A,
+    expected: &dyn de::Expected,
+    mut seed: F,
+) -> Result<[S::Value; N], A::Error>
+where
+    A: SeqAccess<'de>,
+    S: DeserializeSeed<'de>,
+    F: FnMut(usize) -> S,
+{
+    match seq.size_hint() {
+        Some(len) if len != N => return Err(de::Error::invalid_length(len, expected)),
+        _ => {}
+    }
+
+    let arr = try_build_array(|idx| match seq.next_element_seed(seed(idx))? {
+        Some(val) => Ok(val),
+        // too few elements, report invalid_length
+        None => Err(de::Error::invalid_length(idx, expected)),
+    })?;
+
+    // Make sure there aren't any elements left over, without building a value from them
+    match seq.next_element::<de::IgnoredAny>()? {
+        Some(_) => Err(too_long(seq, N, expected)),
+        None => Ok(arr),
+    }
+}
+
+/// Fill a boxed `[T; N]` array from a sequence, building it directly on the heap
+///
+/// The sequence is read exactly as by [`fill_array`], with the same errors, but each element is
+/// pushed into a heap allocation as it is read, so only a single element is ever on the stack
+/// however large `N` is.
+#[cfg(feature = "alloc")]
+pub(crate) fn fill_boxed_array<'de, A, S, F, const N: usize>(
+    mut seq: A,
+    expected: &dyn de::Expected,
+    mut seed: F,
+) -> Result<alloc::boxed::Box<[S::Value; N]>, A::Error>
+where
+    A: SeqAccess<'de>,
+    S: DeserializeSeed<'de>,
+    F: FnMut(usize) -> S,
+{
+    use core::convert::TryInto;
+
+    match seq.size_hint() {
+        Some(len) if len != N => return Err(de::Error::invalid_length(len, expected)),
+        _ => {}
+    }
+
+    // Allocating exactly `N` up front means the conversion to a boxed slice below never has to
+    // reallocate; if anything fails, the `Vec` drops whatever has been built so far
+    let mut data = alloc::vec::Vec::with_capacity(N);
+    for idx in 0..N {
+        match seq.next_element_seed(seed(idx))? {
+            Some(val) => data.push(val),
+            None => return Err(de::Error::invalid_length(idx, expected)),
+        }
+    }
+
+    // Make sure there aren't any elements left over, without building a value from them
+    if seq.next_element::<de::IgnoredAny>()?.is_some() {
+        return Err(too_long(seq, N, expected));
+    }
+
+    match data.into_boxed_slice().try_into() {
+        Ok(arr) => Ok(arr),
+        Err(_) => unreachable!("exactly N elements were collected"),
+    }
+}
+
+/// How many elements past the end of an over-long sequence are counted before giving up
+const EXCESS_LIMIT: usize = 1024;
+
+/// Build the error for a sequence with more than `len` elements, one of which has already been read
+///
+/// The rest of the sequence is drained so the error can report how many elements were actually
+/// found, up to [`EXCESS_LIMIT`] past the end; beyond that, or should draining fail, the error
+/// reports only the number seen so far as a lower bound. Excess elements are skipped as
+/// [`de::IgnoredAny`], so no `T` is built (and then has to be dropped) just to be counted; callers
+/// should read the first excess element the same way.
+pub(crate) fn too_long<'de, A>(mut seq: A, len: usize, expected: &dyn de::Expected) -> A::Error
+where
+    A: SeqAccess<'de>,
+{
+    let mut found = len + 1;
+    while found - len < EXCESS_LIMIT {
+        match seq.next_element::<de::IgnoredAny>() {
+            Ok(Some(_)) => found += 1,
+            Ok(None) => return de::Error::invalid_length(found, expected),
+            Err(_) => break,
+        }
+    }
+    de::Error::custom(format_args!(
+        "expected {}, found at least {} elements",
+        expected, found
+    ))
+}
+
+/// Most memory reserved up front for a sequence whose length is reported by the format
+#[cfg(feature = "alloc")]
+const MAX_PREALLOC_BYTES: usize = 1024 * 1024;
+
+/// Capacity to reserve for a sequence of `T` given its size hint
+///
+/// A hint is only a hint, and a hostile one could otherwise trigger a huge allocation, so at most
+/// [`MAX_PREALLOC_BYTES`] are reserved however large the hint or each element; past that the
+/// collection grows as usual while its elements are pushed.
+#[cfg(feature = "alloc")]
+pub(crate) fn cautious_capacity<T>(hint: Option<usize>) -> usize {
+    hint.unwrap_or(0)
+        .min(MAX_PREALLOC_BYTES / core::mem::size_of::<T>().max(1))
+}
+
+/// Build a `[T; N]` array from a fallible function of each element's index
+///
+/// This is the array-building loop shared by every path in this crate that produces an array, on
+/// top of [`init::ArrayBuilder`]. If `f` returns an error, or panics, every element built so far is
+/// dropped before the error is returned or the panic continues.
+pub(crate) fn try_build_array<T, E, F, const N: usize>(mut f: F) -> Result<[T; N], E>
+where
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut builder = init::ArrayBuilder::new();
+    while !builder.is_full() {
+        // On an error, returning drops `builder` and with it the elements built so far
+        let val = f(builder.len())?;
+        if builder.push(val).is_err() {
+            unreachable!("the builder is not yet full");
+        }
+    }
+    Ok(builder.finish())
 }
 
 /// Deserialize const generic or arbitrarily-large arrays
@@ -206,18 +584,40 @@ where
 ///
 /// This implementation is adapted from the [Serde documentation][deserialize_map].
 ///
+/// Input with the wrong number of elements fails with Serde's `invalid_length` error, whose
+/// message reads `invalid length {found}, expected an array of size {N}`; to inspect the lengths
+/// programmatically, see [`try_deserialize`].
+///
+/// Self-describing formats such as JSON, MessagePack, CBOR, and YAML hand a tuple and a sequence
+/// to the same `visit_seq`, so arrays written as plain sequences by other tools, e.g. from a
+/// `Vec`, are read just the same. The same goes for formats that ignore the tuple hint altogether
+/// and drive every value through `deserialize_any`, as relaxed parsers for e.g. JSON5 often do.
+/// Formats that aren't self-describing, such as bincode, give sequences a length prefix that tuples
+/// don't have; read those with [`seq`] instead.
+///
+/// Serde's own in-memory deserializers work too, so values already at hand can be checked and
+/// built into an array with the same errors, without going through a format first:
+///
+/// ```
+/// use serde::de::value::{Error, SeqDeserializer};
+///
+/// let values: Vec<u32> = (0..36).collect();
+/// let de = SeqDeserializer::<_, Error>::new(values.into_iter());
+/// let arr: [u32; 36] = serde_arrays::deserialize(de)?;
+/// # assert_eq!(arr[35], 35);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// Arrays must always be written as arrays, even those of a single element; to also accept a
+/// `[T; 1]` written as its bare element, use [`singleton`] instead.
+///
 /// [deserialize_map]: https://serde.rs/deserialize-map.html
 pub fn deserialize<'de, D, T, const N: usize>(deserialize: D) -> Result<[T; N], D::Error>
 where
     D: Deserializer<'de>,
     T: Deserialize<'de>,
 {
-    deserialize.deserialize_tuple(
-        N,
-        ArrayVisitor {
-            _marker: PhantomData,
-        },
-    )
+    deserialize.deserialize_tuple(N, ArrayVisitor::new())
 }
 
 /// Hacky way to include README in doc-tests, but works until #[doc(include...)] is stabilized
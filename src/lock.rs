@@ -0,0 +1,107 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays behind a lock, `Mutex<[T; N]>` and `RwLock<[T; N]>`
+//!
+//! Requires the `std` feature.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::sync::{Mutex, RwLock};
+//!
+//! #[derive(Serialize, Deserialize, Debug)]
+//! struct Config {
+//!     #[serde(with = "serde_arrays::lock")]
+//!     gains: Mutex<[u32; 16]>,
+//!     #[serde(with = "serde_arrays::lock")]
+//!     offsets: RwLock<[i32; 16]>,
+//! }
+//! # let data = Config { gains: Mutex::new([1; 16]), offsets: RwLock::new([-2; 16]) };
+//! # let json = serde_json::to_string(&data)?;
+//! # let de: Config = serde_json::from_str(&json)?;
+//! # assert_eq!(*de.gains.lock().unwrap(), [1; 16]);
+//! # assert_eq!(*de.offsets.read().unwrap(), [-2; 16]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The lock is held for as long as it takes to serialize the array. A poisoned lock is reported as
+//! a serialization error rather than a panic, and deserializing always gives a fresh lock.
+
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{self, Serialize, Serializer},
+};
+use std::sync::{Mutex, RwLock};
+
+/// Locks supported by [`lock::serialize`](serialize) and [`lock::deserialize`](deserialize)
+pub trait Lock: Sized {
+    /// The value behind the lock
+    type Target;
+
+    /// Move `value` into a new, unlocked lock
+    fn new(value: Self::Target) -> Self;
+
+    /// Call `f` with the value behind the lock, or return `None` if the lock is poisoned
+    fn with_locked<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&Self::Target) -> R;
+}
+
+impl<T> Lock for Mutex<T> {
+    type Target = T;
+
+    fn new(value: T) -> Self {
+        Mutex::new(value)
+    }
+
+    fn with_locked<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.lock().ok().map(|guard| f(&*guard))
+    }
+}
+
+impl<T> Lock for RwLock<T> {
+    type Target = T;
+
+    fn new(value: T) -> Self {
+        RwLock::new(value)
+    }
+
+    fn with_locked<F, R>(&self, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        self.read().ok().map(|guard| f(&*guard))
+    }
+}
+
+/// Serialize the array behind a lock exactly as the array itself
+pub fn serialize<S, L, T, const N: usize>(data: &L, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    L: Lock<Target = [T; N]>,
+    T: Serialize,
+{
+    match data.with_locked(|arr| crate::serialize(arr, ser)) {
+        Some(result) => result,
+        None => Err(ser::Error::custom(
+            "cannot serialize an array behind a poisoned lock",
+        )),
+    }
+}
+
+/// Deserialize an array into a new lock
+pub fn deserialize<'de, D, L, T, const N: usize>(deserializer: D) -> Result<L, D::Error>
+where
+    D: Deserializer<'de>,
+    L: Lock<Target = [T; N]>,
+    T: Deserialize<'de>,
+{
+    crate::deserialize(deserializer).map(L::new)
+}
@@ -0,0 +1,119 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+/// Define a struct whose array fields use [`serde_arrays`](crate) and publish their lengths as
+/// associated constants
+///
+/// Write an array field's length as `[T; NAME = len]` and the macro adds
+/// `#[serde(with = "serde_arrays")]` to the field and a `NAME` constant to the struct, with the
+/// same visibility as the field. Arrays given a plain `[T; len]` get the attribute but no
+/// constant, and any other field is passed through untouched:
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+///
+/// serde_arrays::array! {
+///     #[derive(Serialize, Deserialize, Debug, PartialEq)]
+///     pub struct Frame {
+///         pub id: u32,
+///         pub samples: [u16; SAMPLES = 48],
+///         pub weights: [f32; WEIGHTS = 48],
+///     }
+/// }
+///
+/// assert_eq!(Frame::SAMPLES, 48);
+///
+/// // Fails to compile should the two lengths ever differ
+/// const _: [(); Frame::SAMPLES] = [(); Frame::WEIGHTS];
+/// # let frame = Frame { id: 1, samples: [2; 48], weights: [3.0; 48] };
+/// # let json = serde_json::to_string(&frame)?;
+/// # assert_eq!(frame, serde_json::from_str(&json)?);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// The constants make a mismatch between arrays that must be the same length a compile-time error
+/// rather than a failure to deserialize at runtime:
+///
+/// ```compile_fail,E0308
+/// use serde::Serialize;
+///
+/// serde_arrays::array! {
+///     #[derive(Serialize)]
+///     pub struct Frame {
+///         pub samples: [u16; SAMPLES = 48],
+///         pub weights: [f32; WEIGHTS = 32],
+///     }
+/// }
+///
+/// const _: [(); Frame::SAMPLES] = [(); Frame::WEIGHTS];
+/// ```
+///
+/// Only structs with named fields and no generic parameters are supported, and the generated
+/// attribute names the crate `serde_arrays`, so it must not be renamed in `Cargo.toml`.
+///
+/// Every field whose type is written out as an array gets the attribute, even one that already has
+/// a `#[serde(with = ...)]` of its own, which then fails to compile with conflicting attributes.
+/// To keep such a field's own attribute, name its type through an alias, e.g.
+/// `type Key = [u8; 32];`, and the field is passed through untouched like any other.
+#[macro_export]
+macro_rules! array {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $($fields:tt)*
+        }
+    ) => {
+        $crate::array!(@fields [$(#[$attr])* $vis struct $name] [] [] $($fields)*);
+    };
+
+    // An array whose length is named
+    (@fields $head:tt [$($out:tt)*] [$($consts:tt)*]
+        $(#[$fattr:meta])* $fvis:vis $field:ident : [$ty:ty; $len_name:ident = $len:expr]
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::array!(@fields $head
+            [$($out)* $(#[$fattr])* #[serde(with = "serde_arrays")] $fvis $field: [$ty; $len],]
+            [$($consts)* $fvis const $len_name: usize = $len;]
+            $($($rest)*)?
+        );
+    };
+
+    // Any other array
+    (@fields $head:tt [$($out:tt)*] [$($consts:tt)*]
+        $(#[$fattr:meta])* $fvis:vis $field:ident : [$ty:ty; $len:expr]
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::array!(@fields $head
+            [$($out)* $(#[$fattr])* #[serde(with = "serde_arrays")] $fvis $field: [$ty; $len],]
+            [$($consts)*]
+            $($($rest)*)?
+        );
+    };
+
+    // Any other field
+    (@fields $head:tt [$($out:tt)*] [$($consts:tt)*]
+        $(#[$fattr:meta])* $fvis:vis $field:ident : $fty:ty
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::array!(@fields $head
+            [$($out)* $(#[$fattr])* $fvis $field: $fty,]
+            [$($consts)*]
+            $($($rest)*)?
+        );
+    };
+
+    (@fields [$(#[$attr:meta])* $vis:vis struct $name:ident] [$($out:tt)*] [$($consts:tt)*]) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($out)*
+        }
+
+        impl $name {
+            $($consts)*
+        }
+    };
+}
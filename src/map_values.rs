@@ -0,0 +1,108 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize hash maps whose values are arrays
+//!
+//! The map itself is an ordinary Serde map; only its values are handled as [`serde_arrays`](crate)
+//! handles a single array:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::collections::HashMap;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Tables {
+//!     #[serde(with = "serde_arrays::map_values")]
+//!     tables: HashMap<String, [u32; 36]>,
+//! }
+//! # let mut tables = HashMap::new();
+//! # tables.insert("a".to_string(), [1; 36]);
+//! # let data = Tables { tables };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Should a value fail to deserialize, the error names the key it belongs to.
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use core::{
+    fmt,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+use std::collections::HashMap;
+
+/// Serialize a map with array values
+pub fn serialize<S, K, T, H, const N: usize>(
+    data: &HashMap<K, [T; N], H>,
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize,
+    T: Serialize,
+{
+    let mut map = ser.serialize_map(Some(data.len()))?;
+    for (key, value) in data {
+        map.serialize_entry(key, &ArrayWrap::new(value))?;
+    }
+    map.end()
+}
+
+/// Deserialize a map with array values
+pub fn deserialize<'de, D, K, T, H, const N: usize>(
+    deserializer: D,
+) -> Result<HashMap<K, [T; N], H>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    T: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    deserializer.deserialize_map(MapValuesVisitor::<K, T, H, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for maps of [T; N] arrays
+struct MapValuesVisitor<K, T, H, const N: usize> {
+    _marker: PhantomData<(K, T, H)>,
+}
+
+impl<'de, K, T, H, const N: usize> Visitor<'de> for MapValuesVisitor<K, T, H, N>
+where
+    K: Deserialize<'de> + Eq + Hash + fmt::Debug,
+    T: Deserialize<'de>,
+    H: BuildHasher + Default,
+{
+    type Value = HashMap<K, [T; N], H>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map with arrays of size {} as values", N)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Don't trust the size hint so far as to allocate an unbounded amount of memory up front
+        let mut data =
+            HashMap::with_capacity_and_hasher(map.size_hint().unwrap_or(0).min(4096), H::default());
+        while let Some(key) = map.next_key::<K>()? {
+            let value = map
+                .next_value_seed(ArraySeed::<T, N>::new())
+                .map_err(|err| de::Error::custom(format_args!("for key {:?}: {}", key, err)))?;
+            data.insert(key, value);
+        }
+        Ok(data)
+    }
+}
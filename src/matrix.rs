@@ -0,0 +1,256 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize matrices stored as arrays of arrays
+//!
+//! [`Matrix`] wraps `[[T; C]; R]` and implements `Serialize` and `Deserialize` itself. For a
+//! square `[[T; N]; N]` field, [`serialize_transposed`] and [`deserialize_transposed`] write the
+//! transpose of the in-memory layout, e.g. to keep a column-major matrix row-major on the wire:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Camera {
+//!     // Stored column-major, written row-major
+//!     #[serde(
+//!         serialize_with = "serde_arrays::matrix::serialize_transposed",
+//!         deserialize_with = "serde_arrays::matrix::deserialize_transposed"
+//!     )]
+//!     view: [[f32; 2]; 2],
+//! }
+//!
+//! let camera = Camera { view: [[1.0, 2.0], [3.0, 4.0]] };
+//! let json = serde_json::to_string(&camera)?;
+//! assert_eq!(json, r#"{"view":[[1.0,3.0],[2.0,4.0]]}"#);
+//! assert_eq!(camera, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! For matrices that aren't square, see
+//! [`nested::serialize_row_major`](crate::nested::serialize_row_major).
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use core::{
+    convert::Infallible,
+    fmt,
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+use serde::{
+    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// A matrix of `R` rows of `C` elements that implements `Serialize` and `Deserialize` itself
+///
+/// The matrix is stored as `[[T; C]; R]` and serialized as an array of its rows, each of which is
+/// serialized as [`serde_arrays`](crate) serializes a `[T; C]` field:
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrays::Matrix;
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Transform {
+///     matrix: Matrix<f32, 3, 4>,
+/// }
+///
+/// let transform = Transform {
+///     matrix: Matrix([[1.0, 0.0, 0.0, 5.0], [0.0, 1.0, 0.0, 6.0], [0.0, 0.0, 1.0, 7.0]]),
+/// };
+/// assert_eq!(transform.matrix[(1, 3)], 6.0);
+/// assert_eq!(transform.matrix.get(3, 0), None);
+/// # let json = serde_json::to_string(&transform)?;
+/// # assert_eq!(json, r#"{"matrix":[[1.0,0.0,0.0,5.0],[0.0,1.0,0.0,6.0],[0.0,0.0,1.0,7.0]]}"#);
+/// # assert_eq!(transform, serde_json::from_str(&json)?);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Matrix<T, const R: usize, const C: usize>(pub [[T; C]; R]);
+
+impl<T, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Number of rows, usable in const contexts without a value
+    pub const ROWS: usize = R;
+
+    /// Number of columns, usable in const contexts without a value
+    pub const COLS: usize = C;
+
+    /// Number of rows
+    pub const fn rows(&self) -> usize {
+        R
+    }
+
+    /// Number of columns
+    pub const fn cols(&self) -> usize {
+        C
+    }
+
+    /// Get the element at row `r` and column `c`, or `None` if out of bounds
+    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
+        self.0.get(r).and_then(|row| row.get(c))
+    }
+
+    /// Get a mutable reference to the element at row `r` and column `c`, or `None` if out of bounds
+    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        self.0.get_mut(r).and_then(|row| row.get_mut(c))
+    }
+
+    /// Unwrap the rows of the matrix
+    pub fn into_inner(self) -> [[T; C]; R] {
+        self.0
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for Matrix<T, R, C> {
+    fn default() -> Self {
+        match crate::try_build_array(|_| Ok::<_, Infallible>(crate::default_array())) {
+            Ok(rows) => Matrix(rows),
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (r, c): (usize, usize)) -> &Self::Output {
+        &self.0[r][c]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (r, c): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[r][c]
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T, R, C> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        Matrix(rows)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<Matrix<T, R, C>> for [[T; C]; R] {
+    fn from(matrix: Matrix<T, R, C>) -> Self {
+        matrix.0
+    }
+}
+
+impl<T: Serialize, const R: usize, const C: usize> Serialize for Matrix<T, R, C> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(R)?;
+        for row in &self.0 {
+            s.serialize_element(&ArrayWrap::new(row))?;
+        }
+        s.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const R: usize, const C: usize> Deserialize<'de>
+    for Matrix<T, R, C>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_tuple(
+                R,
+                MatrixVisitor::<T, R, C> {
+                    _marker: PhantomData,
+                },
+            )
+            .map(Matrix)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for the rows of a `Matrix`
+struct MatrixVisitor<T, const R: usize, const C: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const R: usize, const C: usize> Visitor<'de> for MatrixVisitor<T, R, C>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [[T; C]; R];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a matrix of {} rows of {} elements", R, C)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Should a row fail, the rows already built are dropped in turn
+        crate::fill_array(seq, &self, |_| ArraySeed::<T, C>::new())
+    }
+}
+
+/// Serialize a square matrix as its transpose, i.e. as an array of its columns
+pub fn serialize_transposed<S, T, const N: usize>(
+    data: &[[T; N]; N],
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_tuple(N)?;
+    for col in 0..N {
+        s.serialize_element(&Column { data, col })?;
+    }
+    s.end()
+}
+
+/// Deserialize a square matrix written as its transpose by [`serialize_transposed`]
+pub fn deserialize_transposed<'de, D, T, const N: usize>(
+    deserializer: D,
+) -> Result<[[T; N]; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Copy,
+{
+    let wire = deserializer.deserialize_tuple(
+        N,
+        MatrixVisitor::<T, N, N> {
+            _marker: PhantomData,
+        },
+    )?;
+
+    let mut data = wire;
+    for (r, row) in data.iter_mut().enumerate() {
+        for (c, elem) in row.iter_mut().enumerate() {
+            *elem = wire[c][r];
+        }
+    }
+    Ok(data)
+}
+
+/// A single column of a square matrix, serialized as an array
+struct Column<'a, T, const N: usize> {
+    data: &'a [[T; N]; N],
+    col: usize,
+}
+
+impl<'a, T: Serialize, const N: usize> Serialize for Column<'a, T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(N)?;
+        for row in self.data {
+            s.serialize_element(&row[self.col])?;
+        }
+        s.end()
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize numeric arrays as their mean plus each element's deviation from it
+//!
+//! For arrays whose values cluster around some mean, the deviations are much smaller numbers than
+//! the values themselves, which makes for shorter text and better compression. The array is
+//! written as a pair `[mean, [deviation; N]]`:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Readings {
+//!     #[serde(with = "serde_arrays::mean_centered")]
+//!     values: [u32; 4],
+//! }
+//!
+//! let readings = Readings { values: [100_001, 99_998, 100_003, 99_999] };
+//! let json = serde_json::to_string(&readings)?;
+//! assert_eq!(json, r#"{"values":[100000,[1,-2,3,-1]]}"#);
+//! # assert_eq!(readings, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Integer arrays use the mean rounded down and are reconstructed exactly; floating point arrays
+//! are reconstructed to within the precision of `mean + deviation`.
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use core::{
+    convert::{Infallible, TryFrom},
+    fmt,
+    marker::PhantomData,
+};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// A numeric type that can be encoded relative to the mean of its array
+pub trait Centered: Copy + Sized {
+    /// Type of each element's deviation from the mean, wide enough to never overflow
+    type Deviation: Copy;
+
+    /// Compute the mean of `data`
+    fn mean(data: &[Self]) -> Self;
+
+    /// Compute the deviation of `self` from `mean`
+    fn deviation(self, mean: Self) -> Self::Deviation;
+
+    /// Reconstruct a value from the mean and its deviation, or `None` if it doesn't fit
+    fn restore(mean: Self, deviation: Self::Deviation) -> Option<Self>;
+}
+
+macro_rules! impl_centered_int {
+    ($dev:ty => $($t:ty),*) => {
+        $(
+            impl Centered for $t {
+                type Deviation = $dev;
+
+                fn mean(data: &[Self]) -> Self {
+                    if data.is_empty() {
+                        return 0;
+                    }
+                    let sum: i128 = data.iter().map(|&x| x as i128).sum();
+                    // The mean lies between the smallest and largest values, so it always fits
+                    sum.div_euclid(data.len() as i128) as $t
+                }
+
+                fn deviation(self, mean: Self) -> $dev {
+                    (self as i128 - mean as i128) as $dev
+                }
+
+                fn restore(mean: Self, deviation: $dev) -> Option<Self> {
+                    (mean as i128)
+                        .checked_add(deviation as i128)
+                        .and_then(|value| <$t>::try_from(value).ok())
+                }
+            }
+        )*
+    };
+}
+
+impl_centered_int!(i64 => u8, u16, u32, i8, i16, i32);
+impl_centered_int!(i128 => u64, i64);
+
+macro_rules! impl_centered_float {
+    ($($t:ty),*) => {
+        $(
+            impl Centered for $t {
+                type Deviation = $t;
+
+                fn mean(data: &[Self]) -> Self {
+                    if data.is_empty() {
+                        return 0.0;
+                    }
+                    let sum: f64 = data.iter().map(|&x| f64::from(x)).sum();
+                    (sum / data.len() as f64) as $t
+                }
+
+                fn deviation(self, mean: Self) -> $t {
+                    self - mean
+                }
+
+                fn restore(mean: Self, deviation: $t) -> Option<Self> {
+                    Some(mean + deviation)
+                }
+            }
+        )*
+    };
+}
+
+impl_centered_float!(f32, f64);
+
+/// Serialize an array as its mean and each element's deviation from the mean
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Centered + Serialize,
+    T::Deviation: Serialize,
+{
+    let mean = T::mean(data);
+    let deviations: [T::Deviation; N] =
+        match crate::try_build_array(|idx| Ok::<_, Infallible>(data[idx].deviation(mean))) {
+            Ok(deviations) => deviations,
+            Err(never) => match never {},
+        };
+
+    let mut s = ser.serialize_tuple(2)?;
+    s.serialize_element(&mean)?;
+    s.serialize_element(&ArrayWrap::new(&deviations))?;
+    s.end()
+}
+
+/// Deserialize an array from its mean and each element's deviation from the mean
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Centered + Deserialize<'de> + fmt::Display,
+    T::Deviation: Deserialize<'de> + fmt::Display,
+{
+    deserializer.deserialize_tuple(
+        2,
+        CenteredVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for mean-centered [T; N] arrays
+struct CenteredVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for CenteredVisitor<T, N>
+where
+    T: Centered + Deserialize<'de> + fmt::Display,
+    T::Deviation: Deserialize<'de> + fmt::Display,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a mean and an array of {} deviations", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mean: T = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let deviations = seq
+            .next_element_seed(ArraySeed::<T::Deviation, N>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        crate::try_build_array(|idx| {
+            T::restore(mean, deviations[idx]).ok_or_else(|| {
+                de::Error::custom(format_args!(
+                    "mean {} with deviation {} at position {} is out of range",
+                    mean, deviations[idx], idx
+                ))
+            })
+        })
+    }
+}
@@ -0,0 +1,131 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays with a descriptive name in error messages
+//!
+//! Errors normally describe what was expected as "an array of size N". For friendlier messages,
+//! the name of what the array represents can be supplied by implementing [`ArrayName`] on a
+//! marker type, which is then passed to [`Named`]:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use serde_arrays::named::ArrayName;
+//!
+//! struct Rgba;
+//! impl ArrayName for Rgba {
+//!     const NAME: &'static str = "an RGBA pixel";
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Style {
+//!     #[serde(with = "serde_arrays::named::Named::<Rgba>")]
+//!     background: [u8; 4],
+//! }
+//!
+//! let err = serde_json::from_str::<Style>(r#"{"background":[255,0,0]}"#).unwrap_err();
+//! assert!(err.to_string().starts_with(
+//!     "invalid length 3, expected an RGBA pixel (array of size 4)"
+//! ));
+//! # let style = Style { background: [1, 2, 3, 4] };
+//! # let json = serde_json::to_string(&style)?;
+//! # assert_eq!(style, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The serialized form is identical to that of [`serde_arrays`](crate). For manual `Deserialize`
+//! implementations, [`deserialize_named`](crate::deserialize_named) takes the name at runtime.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// The name of what an array represents, for use with [`Named`]
+pub trait ArrayName {
+    /// Name to use in error messages, e.g. "an RGBA pixel"
+    const NAME: &'static str;
+}
+
+/// Serialize and deserialize `[T; N]`, naming the array `A::NAME` in error messages
+///
+/// Use as `#[serde(with = "serde_arrays::named::Named::<MyArrayName>")]`.
+pub struct Named<A> {
+    _marker: PhantomData<A>,
+}
+
+impl<A: ArrayName> Named<A> {
+    /// Serialize an array exactly as [`serde_arrays`](crate) does
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        crate::serialize(data, ser)
+    }
+
+    /// Deserialize an array, naming it `A::NAME` in error messages
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserialize_named(A::NAME, deserializer)
+    }
+}
+
+/// Deserialize an array, naming it `name` in error messages
+///
+/// This is not usable with `#[serde(with)]` (see [`Named`] for that), but rather intended for
+/// manual `Deserialize` implementations or direct use with a `Deserializer`:
+///
+/// ```
+/// let mut de = serde_json::Deserializer::from_str("[1.0, 0.5]");
+/// let err = serde_arrays::deserialize_named::<_, f32, 3>("a position", &mut de).unwrap_err();
+///
+/// assert!(err.to_string().starts_with("invalid length 2, expected a position (array of size 3)"));
+/// ```
+pub fn deserialize_named<'de, D, T, const N: usize>(
+    name: &str,
+    deserializer: D,
+) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N,
+        NamedVisitor::<T, N> {
+            name,
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays with a descriptive name
+struct NamedVisitor<'a, T, const N: usize> {
+    name: &'a str,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, 'a, T, const N: usize> Visitor<'de> for NamedVisitor<'a, T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} (array of size {})", self.name, N)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| PhantomData)
+    }
+}
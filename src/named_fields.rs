@@ -0,0 +1,134 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays as objects with a name for each element
+//!
+//! Small arrays whose elements each have a meaning of their own, such as the channels of a color,
+//! read better as an object. The names are supplied by implementing [`FieldNames`] on a marker
+//! type, which is then passed to [`NamedFields`]:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use serde_arrays::named_fields::FieldNames;
+//!
+//! struct Rgba;
+//! impl FieldNames<4> for Rgba {
+//!     const NAMES: &'static [&'static str; 4] = &["r", "g", "b", "a"];
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Style {
+//!     #[serde(with = "serde_arrays::named_fields::NamedFields::<Rgba>")]
+//!     color: [u8; 4],
+//! }
+//!
+//! let style = Style { color: [255, 128, 0, 255] };
+//! let json = serde_json::to_string(&style)?;
+//! assert_eq!(json, r#"{"color":{"r":255,"g":128,"b":0,"a":255}}"#);
+//! # assert_eq!(style, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! When deserializing, the fields may come in any order but every one must be present. As with
+//! Serde's derived implementations, any other fields are ignored.
+
+use crate::field::FieldSeed;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+
+/// The name of each element of an array of `N` elements, for use with [`NamedFields`]
+pub trait FieldNames<const N: usize> {
+    /// Names of the fields, in the order of the elements they hold
+    const NAMES: &'static [&'static str; N];
+}
+
+/// Serialize and deserialize `[T; N]` as an object with the fields `F::NAMES`
+///
+/// Use as `#[serde(with = "serde_arrays::named_fields::NamedFields::<MyFieldNames>")]`.
+pub struct NamedFields<F> {
+    _marker: PhantomData<F>,
+}
+
+impl<F> NamedFields<F> {
+    /// Serialize an array as an object, each element under its own name
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+        F: FieldNames<N>,
+    {
+        let mut map = ser.serialize_map(Some(N))?;
+        for (name, item) in F::NAMES.iter().zip(data) {
+            map.serialize_entry(name, item)?;
+        }
+        map.end()
+    }
+
+    /// Deserialize an array from an object holding every one of its named elements
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+        F: FieldNames<N>,
+    {
+        deserializer.deserialize_map(NamedFieldsVisitor::<F, T, N> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays stored as an object of named elements
+struct NamedFieldsVisitor<F, T, const N: usize> {
+    _marker: PhantomData<(F, T)>,
+}
+
+impl<'de, F, T, const N: usize> Visitor<'de> for NamedFieldsVisitor<F, T, N>
+where
+    F: FieldNames<N>,
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an object with the fields ")?;
+        for (i, name) in F::NAMES.iter().enumerate() {
+            if i > 0 {
+                write!(formatter, ", ")?;
+            }
+            write!(formatter, "`{}`", name)?;
+        }
+        Ok(())
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut fields: [Option<T>; N] = crate::try_build_array(|_| Ok::<_, M::Error>(None))?;
+
+        while let Some(key) = map.next_key_seed(FieldSeed(F::NAMES))? {
+            match key {
+                Some(idx) if fields[idx].is_some() => {
+                    return Err(de::Error::duplicate_field(F::NAMES[idx]))
+                }
+                Some(idx) => fields[idx] = Some(map.next_value()?),
+                None => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        crate::try_build_array(|idx| {
+            fields[idx]
+                .take()
+                .ok_or_else(|| de::Error::missing_field(F::NAMES[idx]))
+        })
+    }
+}
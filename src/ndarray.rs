@@ -0,0 +1,142 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize [`ndarray`] matrices with a fixed number of columns
+//!
+//! Requires the `ndarray` feature. An [`Array2<T>`] of shape `(M, N)` is written as a sequence of
+//! `M` rows, each an array of `N` elements exactly as [`serde_arrays`](crate) writes `[T; N]`, so
+//! its serialized form is that of `Vec<[T; N]>`. The number of columns `N` is fixed in the type of
+//! [`Cols`], and every row is checked against it when deserializing, so ragged input is rejected
+//! with the index of the first row that doesn't fit:
+//!
+//! ```
+//! use ndarray::{array, Array2};
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Points {
+//!     #[serde(with = "serde_arrays::ndarray::Cols::<3>")]
+//!     xyz: Array2<f32>,
+//! }
+//!
+//! let points: Points = serde_json::from_str(r#"{"xyz":[[1,2,3],[4,5,6]]}"#)?;
+//! assert_eq!(points.xyz, array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+//!
+//! let ragged = serde_json::from_str::<Points>(r#"{"xyz":[[1,2,3],[4,5]]}"#);
+//! assert!(ragged.is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`ndarray`]: https://docs.rs/ndarray
+//! [`Array2<T>`]: ::ndarray::Array2
+
+use crate::ArraySeed;
+use ::ndarray::{Array2, ArrayView1};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{self, Serialize, SerializeSeq, SerializeTuple, Serializer},
+};
+
+/// Serialize and deserialize an `Array2<T>` with exactly `N` columns
+///
+/// Use as `#[serde(with = "serde_arrays::ndarray::Cols::<N>")]`.
+pub struct Cols<const N: usize>;
+
+impl<const N: usize> Cols<N> {
+    /// Serialize a matrix as a sequence of its rows, failing unless it has exactly `N` columns
+    pub fn serialize<S, T>(data: &Array2<T>, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        if data.ncols() != N {
+            return Err(ser::Error::custom(format_args!(
+                "expected a matrix of {} columns, found {}",
+                N,
+                data.ncols()
+            )));
+        }
+
+        let mut s = ser.serialize_seq(Some(data.nrows()))?;
+        for row in data.rows() {
+            s.serialize_element(&Row::<T, N>(row))?;
+        }
+        s.end()
+    }
+
+    /// Deserialize a matrix from a sequence of rows of exactly `N` elements each
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<Array2<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_seq(RowsVisitor::<T, N> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A single row of a matrix, serialized as an array of `N` elements
+struct Row<'a, T, const N: usize>(ArrayView1<'a, T>);
+
+impl<'a, T: Serialize, const N: usize> Serialize for Row<'a, T, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(N)?;
+        for item in self.0.iter() {
+            s.serialize_element(item)?;
+        }
+        s.end()
+    }
+}
+
+/// A Serde Deserializer `Visitor` for the rows of an `Array2<T>` with `N` columns
+struct RowsVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for RowsVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Array2<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of arrays of size {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Don't trust the size hint so far as to allocate an unbounded amount of memory up front
+        let hint = seq.size_hint().map(|rows| rows.saturating_mul(N));
+        let mut data = Vec::with_capacity(crate::cautious_capacity::<T>(hint));
+        let mut rows = 0;
+        loop {
+            let row = seq
+                .next_element_seed(ArraySeed::<T, N>::new())
+                .map_err(|err| de::Error::custom(format_args!("at index {}: {}", rows, err)))?;
+            match row {
+                Some(row) => {
+                    data.extend(IntoIterator::into_iter(row));
+                    rows += 1;
+                }
+                None => break,
+            }
+        }
+
+        match Array2::from_shape_vec((rows, N), data) {
+            Ok(arr) => Ok(arr),
+            Err(_) => unreachable!("exactly N elements were collected for every row"),
+        }
+    }
+}
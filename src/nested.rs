@@ -0,0 +1,980 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize nested arrays, such as `[[T; N]; M]`, `Vec<[T; N]>`, and
+//! `VecDeque<[T; N]>`
+//!
+//! Each level of nesting is handled exactly as [`serde_arrays`](crate) handles a single array, so
+//! arrays of any size may be nested to any depth:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Matrix {
+//!     #[serde(with = "serde_arrays::nested")]
+//!     cells: [[u32; 40]; 40],
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Voxels {
+//!     #[serde(with = "serde_arrays::nested")]
+//!     grid: [[[u8; 64]; 8]; 8],
+//! }
+//! # let data = Matrix { cells: [[7; 40]; 40] };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # let data = Voxels { grid: [[[7; 64]; 8]; 8] };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! `Vec`s and `VecDeque`s may appear at any level too, e.g. `Vec<[[f32; 4]; 4]>`; should one of
+//! their elements fail to deserialize, the error gives its index along with the expected and
+//! actual lengths, e.g. `at index 2: invalid length 4, expected an array of size 3`, so a single
+//! bad row in a large input can be found directly. Any level may also be an `Option`, e.g.
+//! `Option<Vec<[f32; 8]>>`, with `None` written as Serde's "none" value. For serializing only,
+//! borrowed arrays such as `&[[T; N]; M]` and slices such as `&[[T; N]]` are supported as well.
+//!
+//! The innermost elements must implement [`Element`], which marks them as the point where the
+//! nesting stops. It is implemented for Serde's primitive types and strings; for your own types,
+//! simply implement it:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Pixel(u8, u8, u8);
+//!
+//! impl serde_arrays::nested::Element for Pixel {}
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Image {
+//!     #[serde(with = "serde_arrays::nested")]
+//!     pixels: [[Pixel; 2]; 2],
+//! }
+//! # let data = Image { pixels: [[Pixel(1, 2, 3), Pixel(4, 5, 6)], [Pixel(7, 8, 9), Pixel(0, 0, 0)]] };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! # Tuples and sequences
+//!
+//! Each level is written according to its own type: arrays, whose length is fixed by the type, as
+//! tuples, and `Vec`s, `VecDeque`s, and slices, whose length is not, as sequences. A
+//! `Vec<[[u8; 4]; 2]>` is thus a sequence of tuples of tuples. This is the same policy
+//! [`serde_arrays`](crate) itself follows for `Vec<[T; N]>`, and it lets formats such as bincode
+//! write a length only where one is needed. To write every level as a sequence instead, for
+//! formats or consumers that tell the two apart, use [`nested_seq`](crate::nested_seq).
+//!
+//! # Nesting depth and stack usage
+//!
+//! There is no limit on how deeply arrays may be nested, and none is needed: each level of the
+//! type is handled by one level of calls, and the elements within a level are read in a loop, so
+//! the stack depth is fixed by the type and never by the input. What does grow the stack is the
+//! size of the array itself, since a `[A; N]` is built on the stack before it is returned. For
+//! large arrays, box the outermost level: a `Box<[A; N]>` is filled directly on the heap, so only
+//! one of its elements is ever on the stack.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Volume {
+//!     #[serde(with = "serde_arrays::nested")]
+//!     voxels: Box<[[[u8; 4]; 4]; 100_000]>,
+//! }
+//! # let json = format!(r#"{{"voxels":{}}}"#, serde_json::to_string(&vec![[[1u8; 4]; 4]; 100_000])?);
+//! # let volume: Volume = serde_json::from_str(&json)?;
+//! # assert_eq!(volume.voxels[99_999], [[1; 4]; 4]);
+//! # }
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, collections::VecDeque, string::String, vec::Vec};
+use core::{array::IntoIter, convert::Infallible, fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, SerializeTuple, Serializer},
+};
+
+/// Marker for the innermost element type of nested arrays
+///
+/// Arrays themselves (and `Vec`s) are never elements; that is what lets the nesting be taken
+/// apart one level at a time, whatever the size of each array.
+pub trait Element {}
+
+macro_rules! impl_element {
+    ($($ty:ty),* $(,)?) => {
+        $(impl Element for $ty {})*
+    };
+}
+
+impl_element!(bool, char, ());
+impl_element!(i8, i16, i32, i64, i128, isize);
+impl_element!(u8, u16, u32, u64, u128, usize);
+impl_element!(f32, f64);
+impl Element for &str {}
+#[cfg(feature = "alloc")]
+impl Element for String {}
+impl<T: Element> Element for Option<T> {}
+
+/// Nested array types serializable using [`nested::serialize`](serialize)
+pub trait NestedArray {
+    /// Serialize the nested array
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    /// Serialize the nested array with every level written as a sequence, as
+    /// [`nested_seq`](crate::nested_seq) does
+    #[doc(hidden)]
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize(ser)
+    }
+}
+
+impl<T: Element + Serialize> NestedArray for T {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Serialize::serialize(self, ser)
+    }
+}
+
+impl<A: NestedArray, const N: usize> NestedArray for [A; N] {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Fixed-length structures, including arrays, are supported in Serde as tuples
+        serialize_level(ser, self.iter(), true, false)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_level(ser, self.iter(), true, true)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: NestedArray> NestedArray for Vec<A> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        <[A] as NestedArray>::serialize(self, ser)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        <[A] as NestedArray>::serialize_seqs(self, ser)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: NestedArray> NestedArray for VecDeque<A> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_level(ser, self.iter(), false, false)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_level(ser, self.iter(), false, true)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: NestedArray, const N: usize> NestedArray for Box<[A; N]> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        <[A; N] as NestedArray>::serialize(self, ser)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        <[A; N] as NestedArray>::serialize_seqs(self, ser)
+    }
+}
+
+impl<A: NestedArray> NestedArray for [A] {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // A slice's length isn't part of its type, so it's written as a sequence like a `Vec`
+        serialize_level(ser, self.iter(), false, false)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_level(ser, self.iter(), false, true)
+    }
+}
+
+/// References serialize exactly as whatever they refer to, so borrowed nested data can be
+/// serialized without cloning it
+impl<A: NestedArray, const N: usize> NestedArray for &[A; N] {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize(ser)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize_seqs(ser)
+    }
+}
+
+impl<A: NestedArray> NestedArray for &[A] {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize(ser)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize_seqs(ser)
+    }
+}
+
+// `Option`s of elements are themselves elements, so the nested levels are covered one by one
+impl<A: NestedArray, const N: usize> NestedArray for Option<[A; N]> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_option(self, ser, false)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_option(self, ser, true)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: NestedArray> NestedArray for Option<Vec<A>> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_option(self, ser, false)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_option(self, ser, true)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A: NestedArray> NestedArray for Option<VecDeque<A>> {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_option(self, ser, false)
+    }
+
+    fn serialize_seqs<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_option(self, ser, true)
+    }
+}
+
+/// Serialize `Some` nested array as the array itself, and `None` as Serde's "none" value
+fn serialize_option<S, A>(data: &Option<A>, ser: S, seqs: bool) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: NestedArray,
+{
+    match data {
+        Some(inner) => ser.serialize_some(&Wrap { inner, seqs }),
+        None => ser.serialize_none(),
+    }
+}
+
+/// Serialize one level of a nested array, as a tuple if its length is `fixed` by its type and
+/// otherwise as a sequence; with `seqs`, as for [`nested_seq`](crate::nested_seq), it and every
+/// level below it are written as sequences
+fn serialize_level<'a, S, A, I>(
+    ser: S,
+    items: I,
+    fixed: bool,
+    seqs: bool,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: NestedArray + 'a,
+    I: ExactSizeIterator<Item = &'a A>,
+{
+    if fixed && !seqs {
+        let mut s = ser.serialize_tuple(items.len())?;
+        for inner in items {
+            s.serialize_element(&Wrap { inner, seqs })?;
+        }
+        s.end()
+    } else {
+        let mut s = ser.serialize_seq(Some(items.len()))?;
+        for inner in items {
+            s.serialize_element(&Wrap { inner, seqs })?;
+        }
+        s.end()
+    }
+}
+
+/// Nested array types deserializable using [`nested::deserialize`](deserialize)
+pub trait NestedDeserialize<'de>: Sized {
+    /// Deserialize the nested array
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    /// Deserialize the nested array with every level read as a sequence, as
+    /// [`nested_seq`](crate::nested_seq) does
+    #[doc(hidden)]
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer)
+    }
+
+    /// Describe several values of this type, e.g. "arrays of size 3"
+    #[doc(hidden)]
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "elements")
+    }
+
+    /// Describe the size and contents of an array of `len` values of this type, e.g. " of size 3"
+    #[doc(hidden)]
+    fn expecting_array_of(formatter: &mut fmt::Formatter, len: usize) -> fmt::Result {
+        write!(formatter, " of size {}", len)
+    }
+}
+
+impl<'de, T: Element + Deserialize<'de>> NestedDeserialize<'de> for T {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Deserialize::deserialize(deserializer)
+    }
+}
+
+impl<'de, A: NestedDeserialize<'de>, const N: usize> NestedDeserialize<'de> for [A; N] {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            N,
+            NestedVisitor::<A, N> {
+                seqs: false,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(NestedVisitor::<A, N> {
+            seqs: true,
+            _marker: PhantomData,
+        })
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "arrays")?;
+        A::expecting_array_of(formatter, N)
+    }
+
+    fn expecting_array_of(formatter: &mut fmt::Formatter, len: usize) -> fmt::Result {
+        write!(formatter, " of {} ", len)?;
+        Self::expecting_plural(formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A: NestedDeserialize<'de>> NestedDeserialize<'de> for Vec<A> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(VecVisitor::<A> {
+            seqs: false,
+            _marker: PhantomData,
+        })
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(VecVisitor::<A> {
+            seqs: true,
+            _marker: PhantomData,
+        })
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "sequences of ")?;
+        A::expecting_plural(formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A: NestedDeserialize<'de>> NestedDeserialize<'de> for VecDeque<A> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Converting from a `Vec` reuses its allocation
+        <Vec<A> as NestedDeserialize>::deserialize(deserializer).map(VecDeque::from)
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <Vec<A> as NestedDeserialize>::deserialize_seqs(deserializer).map(VecDeque::from)
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        Vec::<A>::expecting_plural(formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A: NestedDeserialize<'de>, const N: usize> NestedDeserialize<'de> for Box<[A; N]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(
+            N,
+            BoxedVisitor::<A, N> {
+                seqs: false,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(BoxedVisitor::<A, N> {
+            seqs: true,
+            _marker: PhantomData,
+        })
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        <[A; N]>::expecting_plural(formatter)
+    }
+
+    fn expecting_array_of(formatter: &mut fmt::Formatter, len: usize) -> fmt::Result {
+        <[A; N]>::expecting_array_of(formatter, len)
+    }
+}
+
+impl<'de, A: NestedDeserialize<'de>, const N: usize> NestedDeserialize<'de> for Option<[A; N]> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, false)
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, true)
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "optional ")?;
+        <[A; N]>::expecting_plural(formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A: NestedDeserialize<'de>> NestedDeserialize<'de> for Option<Vec<A>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, false)
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, true)
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "optional ")?;
+        Vec::<A>::expecting_plural(formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A: NestedDeserialize<'de>> NestedDeserialize<'de> for Option<VecDeque<A>> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, false)
+    }
+
+    fn deserialize_seqs<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_option(deserializer, true)
+    }
+
+    fn expecting_plural(formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "optional ")?;
+        VecDeque::<A>::expecting_plural(formatter)
+    }
+}
+
+/// Deserialize an optional nested array, which is an error rather than `None` if it is invalid
+fn deserialize_option<'de, D, A>(deserializer: D, seqs: bool) -> Result<Option<A>, D::Error>
+where
+    D: Deserializer<'de>,
+    A: NestedDeserialize<'de>,
+{
+    deserializer.deserialize_option(OptionVisitor::<A> {
+        seqs,
+        _marker: PhantomData,
+    })
+}
+
+/// Serialize a nested array
+pub fn serialize<S, A>(data: &A, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: NestedArray + ?Sized,
+{
+    data.serialize(ser)
+}
+
+/// Deserialize a nested array
+pub fn deserialize<'de, D, A>(deserializer: D) -> Result<A, D::Error>
+where
+    D: Deserializer<'de>,
+    A: NestedDeserialize<'de>,
+{
+    A::deserialize(deserializer)
+}
+
+/// Serialize and deserialize `[[T; N]; M]` for any `T`, without implementing [`Element`]
+///
+/// Each of the `M` rows is handled exactly as [`serde_arrays`](crate) handles a single `[T; N]`,
+/// so the elements need only implement `Serialize` and `Deserialize`. The serialized form is the
+/// same as that of [`nested::serialize`](serialize):
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Cell {
+///     alive: bool,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Board {
+///     #[serde(with = "serde_arrays::nested::Rows")]
+///     cells: [[Cell; 3]; 40],
+/// }
+/// # let json = serde_json::to_string(&vec![vec![serde_json::json!({"alive": true}); 3]; 40])?;
+/// # let board: Board = serde_json::from_str(&format!(r#"{{"cells":{}}}"#, json))?;
+/// # assert!(board.cells[39][2].alive);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// To go deeper than two levels, implement [`Element`] for the innermost type and use
+/// [`nested`](self) itself, or give the rows a type of their own such as [`Arr`](crate::Arr).
+pub struct Rows;
+
+impl Rows {
+    /// Serialize an array of arrays
+    pub fn serialize<S, T, const N: usize, const M: usize>(
+        data: &[[T; N]; M],
+        ser: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut s = ser.serialize_tuple(M)?;
+        for row in data {
+            s.serialize_element(&ArrayWrap::new(row))?;
+        }
+        s.end()
+    }
+
+    /// Deserialize an array of arrays
+    pub fn deserialize<'de, D, T, const N: usize, const M: usize>(
+        deserializer: D,
+    ) -> Result<[[T; N]; M], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(
+            M,
+            RowsVisitor::<T, N, M> {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// Serialize a column-major matrix `[[T; M]; N]` of `N` columns in row-major order
+///
+/// The wire format is the `M` rows of `N` elements each, exactly as [`Rows`] would write a
+/// row-major `[[T; N]; M]`, so it reads the same whatever the layout in memory. The matrix need
+/// not be square:
+///
+/// ```
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Projection {
+///     // Three columns of two rows each
+///     #[serde(
+///         serialize_with = "serde_arrays::nested::serialize_row_major",
+///         deserialize_with = "serde_arrays::nested::deserialize_row_major"
+///     )]
+///     columns: [[u32; 2]; 3],
+/// }
+///
+/// let proj = Projection { columns: [[1, 4], [2, 5], [3, 6]] };
+/// let json = serde_json::to_string(&proj)?;
+/// assert_eq!(json, r#"{"columns":[[1,2,3],[4,5,6]]}"#);
+/// assert_eq!(proj, serde_json::from_str(&json)?);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn serialize_row_major<S, T, const M: usize, const N: usize>(
+    data: &[[T; M]; N],
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_tuple(M)?;
+    for row in 0..M {
+        s.serialize_element(&RowOf { data, row })?;
+    }
+    s.end()
+}
+
+/// Deserialize a column-major matrix `[[T; M]; N]` written in row-major order by
+/// [`serialize_row_major`]
+///
+/// The elements are moved into place, not copied, so any `T` is supported. Should any element
+/// fail to deserialize, every element already read is dropped.
+pub fn deserialize_row_major<'de, D, T, const M: usize, const N: usize>(
+    deserializer: D,
+) -> Result<[[T; M]; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let wire: [[T; N]; M] = Rows::deserialize(deserializer)?;
+
+    // Column `c` takes the next element of every row in turn, which is the row's `c`th element
+    #[allow(deprecated)] // Arrays only implement `IntoIterator` by value since Rust 1.53
+    let mut rows: [IntoIter<T, N>; M] =
+        crate::collect_array(IntoIter::new(wire).map(IntoIter::new))
+            .unwrap_or_else(|_| unreachable!());
+    let columns = crate::try_build_array::<_, Infallible, _, N>(|_| {
+        crate::try_build_array(|r| Ok(rows[r].next().unwrap_or_else(|| unreachable!())))
+    });
+    match columns {
+        Ok(columns) => Ok(columns),
+        Err(never) => match never {},
+    }
+}
+
+/// A single row of a column-major matrix, serialized as an array
+struct RowOf<'a, T, const M: usize, const N: usize> {
+    data: &'a [[T; M]; N],
+    row: usize,
+}
+
+impl<'a, T: Serialize, const M: usize, const N: usize> Serialize for RowOf<'a, T, M, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = serializer.serialize_tuple(N)?;
+        for col in self.data {
+            s.serialize_element(&col[self.row])?;
+        }
+        s.end()
+    }
+}
+
+/// Wrapper to serialize one level of a nested array as an element of the level above it
+struct Wrap<'a, A> {
+    inner: &'a A,
+    seqs: bool,
+}
+
+impl<'a, A: NestedArray> Serialize for Wrap<'a, A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.seqs {
+            self.inner.serialize_seqs(serializer)
+        } else {
+            self.inner.serialize(serializer)
+        }
+    }
+}
+
+/// Seed to deserialize one level of a nested array as an element of the level above it
+struct NestedSeed<A> {
+    seqs: bool,
+    _marker: PhantomData<A>,
+}
+
+impl<'de, A: NestedDeserialize<'de>> DeserializeSeed<'de> for NestedSeed<A> {
+    type Value = A;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.seqs {
+            A::deserialize_seqs(deserializer)
+        } else {
+            A::deserialize(deserializer)
+        }
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [A; N] arrays of nested elements
+struct NestedVisitor<A, const N: usize> {
+    seqs: bool,
+    _marker: PhantomData<A>,
+}
+
+impl<'de, A, const N: usize> Visitor<'de> for NestedVisitor<A, N>
+where
+    A: NestedDeserialize<'de>,
+{
+    type Value = [A; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array")?;
+        A::expecting_array_of(formatter, N)
+    }
+
+    fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        // Should an inner array fail, the inner arrays already built are dropped in turn
+        crate::fill_array(seq, &self, |_| NestedSeed {
+            seqs: self.seqs,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A Serde Deserializer `Visitor` for Box<[A; N]> arrays of nested elements
+#[cfg(feature = "alloc")]
+struct BoxedVisitor<A, const N: usize> {
+    seqs: bool,
+    _marker: PhantomData<A>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A, const N: usize> Visitor<'de> for BoxedVisitor<A, N>
+where
+    A: NestedDeserialize<'de>,
+{
+    type Value = Box<[A; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array")?;
+        A::expecting_array_of(formatter, N)
+    }
+
+    fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        // The outer level is built in place on the heap, one element at a time, so only a single
+        // inner element is ever on the stack however large `N` is
+        crate::fill_boxed_array(seq, &self, |_| NestedSeed {
+            seqs: self.seqs,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A Serde Deserializer `Visitor` for Option<A> of nested arrays
+struct OptionVisitor<A> {
+    seqs: bool,
+    _marker: PhantomData<A>,
+}
+
+impl<'de, A> Visitor<'de> for OptionVisitor<A>
+where
+    A: NestedDeserialize<'de>,
+{
+    type Value = Option<A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "optional ")?;
+        A::expecting_plural(formatter)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if self.seqs {
+            A::deserialize_seqs(deserializer).map(Some)
+        } else {
+            A::deserialize(deserializer).map(Some)
+        }
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [[T; N]; M] arrays of rows
+struct RowsVisitor<T, const N: usize, const M: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize, const M: usize> Visitor<'de> for RowsVisitor<T, N, M>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [[T; N]; M];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of {} arrays of size {}", M, N)
+    }
+
+    fn visit_seq<S>(self, seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| ArraySeed::<T, N>::new())
+    }
+}
+
+/// A Serde Deserializer `Visitor` for Vec<A> sequences of nested elements
+#[cfg(feature = "alloc")]
+struct VecVisitor<A> {
+    seqs: bool,
+    _marker: PhantomData<A>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, A> Visitor<'de> for VecVisitor<A>
+where
+    A: NestedDeserialize<'de>,
+{
+    type Value = Vec<A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of ")?;
+        A::expecting_plural(formatter)
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        // Don't trust the size hint so far as to allocate an unbounded amount of memory up front
+        let mut data = Vec::with_capacity(crate::cautious_capacity::<A>(seq.size_hint()));
+        loop {
+            let item = seq
+                .next_element_seed(NestedSeed {
+                    seqs: self.seqs,
+                    _marker: PhantomData,
+                })
+                .map_err(|err| {
+                    serde::de::Error::custom(format_args!("at index {}: {}", data.len(), err))
+                })?;
+            match item {
+                Some(item) => data.push(item),
+                None => return Ok(data),
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize nested arrays with every level as a sequence
+//!
+//! This supports exactly the same types as [`nested`](crate::nested), but where that writes
+//! arrays as tuples, this writes every level as a sequence, arrays included. Self-describing
+//! formats such as JSON make no distinction and write the same output either way; the difference
+//! shows in formats that do, e.g. bincode gives each array a length prefix, and in consumers that
+//! expect sequences throughout:
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Frames {
+//!     #[serde(with = "serde_arrays::nested_seq")]
+//!     frames: Vec<[[u8; 4]; 2]>,
+//! }
+//!
+//! let data = Frames { frames: vec![[[1, 2, 3, 4], [5, 6, 7, 8]]] };
+//! let bytes = bincode::serialize(&data)?;
+//!
+//! // A length of 8 bytes for each of the three levels, plus the elements
+//! assert_eq!(bytes.len(), 8 + 8 + 2 * (8 + 4));
+//! assert_eq!(data, bincode::deserialize(&bytes)?);
+//! # }
+//! # Ok::<(), bincode::Error>(())
+//! ```
+//!
+//! Arrays are still checked to have exactly the right length when deserialized.
+
+use crate::nested::{NestedArray, NestedDeserialize};
+use serde::{Deserializer, Serializer};
+
+/// Serialize a nested array with every level as a sequence
+pub fn serialize<S, A>(data: &A, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: NestedArray + ?Sized,
+{
+    data.serialize_seqs(ser)
+}
+
+/// Deserialize a nested array with every level as a sequence
+pub fn deserialize<'de, D, A>(deserializer: D) -> Result<A, D::Error>
+where
+    D: Deserializer<'de>,
+    A: NestedDeserialize<'de>,
+{
+    A::deserialize_seqs(deserializer)
+}
@@ -0,0 +1,97 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays leniently, replacing any `null` elements with `T::default()`
+//!
+//! Each element is read as an `Option<T>`, so a `null` (or whatever the format uses for `None`)
+//! becomes the default value. The array must still have exactly `N` elements; see
+//! [`default_filled`](crate::default_filled) to default elements missing from the end instead.
+//! Serialization always writes the full array.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "serde_arrays::null_as_default")]
+//!     samples: [u32; 3],
+//! }
+//!
+//! let reading: Reading = serde_json::from_str(r#"{"samples":[1,null,3]}"#)?;
+//! assert_eq!(reading.samples, [1, 0, 3]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize the full array
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::serialize(data, ser)
+}
+
+/// Deserialize an array of `N` elements, defaulting any that are `null`
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    deserializer.deserialize_tuple(
+        N,
+        NullAsDefaultVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays with possibly-null elements
+struct NullAsDefaultVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for NullAsDefaultVisitor<T, N>
+where
+    T: Deserialize<'de> + Default,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| OrDefault::<T> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Seed to deserialize a single element, or its default value in place of `null`
+struct OrDefault<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de> + Default> DeserializeSeed<'de> for OrDefault<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Option::unwrap_or_default)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize optional arrays
+//!
+//! `None` is written as Serde's "none" value (e.g. `null` in JSON) and `Some` as the array itself:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Config {
+//!     #[serde(with = "serde_arrays::option", default)]
+//!     weights: Option<[u32; 48]>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"weights":null}"#)?;
+//! assert_eq!(config.weights, None);
+//! let config: Config = serde_json::from_str("{}")?;
+//! assert_eq!(config.weights, None);
+//! # let config = Config { weights: Some([1; 48]) };
+//! # let json = serde_json::to_string(&config)?;
+//! # assert_eq!(config, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! As with any field using Serde's `with` attribute, add `default` to the field's attributes if
+//! the field may be missing entirely.
+
+use crate::wrapper::ArrayWrap;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize an optional array
+pub fn serialize<S, T, const N: usize>(data: &Option<[T; N]>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    match data {
+        Some(arr) => ser.serialize_some(&ArrayWrap::new(arr)),
+        None => ser.serialize_none(),
+    }
+}
+
+/// Deserialize an optional array
+///
+/// A value that is present but is not a valid array of size `N` is an error, not `None`.
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<Option<[T; N]>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_option(OptionVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for Option<[T; N]>
+struct OptionVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for OptionVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = Option<[T; N]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an optional array of size {}", N)
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::deserialize(deserializer).map(Some)
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays whose last `K` elements may be omitted
+//!
+//! This supports extending an array over time in a backwards-compatible way: data written before
+//! the trailing elements were added is still accepted, with the missing tail filled in with
+//! `T::default()`. The first `N - K` elements are always required. Serialization always writes
+//! the full array, as a sequence like [`seq`](crate::seq) rather than a tuple: formats that
+//! aren't self-describing, such as bincode, then record how many elements follow, so data written
+//! with an older, shorter array is still read correctly rather than running into the next field.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Version {
+//!     // The patch and pre-release numbers were added in later versions of the format
+//!     #[serde(with = "serde_arrays::optional_tail::OptionalTail::<2>")]
+//!     parts: [u32; 4],
+//! }
+//!
+//! let v: Version = serde_json::from_str(r#"{"parts":[1,2]}"#)?;
+//! assert_eq!(v.parts, [1, 2, 0, 0]);
+//! # assert!(serde_json::from_str::<Version>(r#"{"parts":[1]}"#).is_err());
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize and deserialize `[T; N]` where the last `K` elements are optional on input
+///
+/// Use as `#[serde(with = "serde_arrays::optional_tail::OptionalTail::<K>")]`.
+pub struct OptionalTail<const K: usize>;
+
+impl<const K: usize> OptionalTail<K> {
+    /// Serialize the full array as a sequence
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        crate::seq::serialize(data, ser)
+    }
+
+    /// Deserialize an array of between `N - K` and `N` elements, defaulting any missing tail
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + Default,
+    {
+        deserialize_tail(deserializer, N.saturating_sub(K), T::default)
+    }
+}
+
+/// Deserialize a sequence of between `required` and `N` elements, filling any missing tail
+///
+/// Each missing element is produced by a call to `fill`. This is shared by the modules that accept
+/// short input, which differ only in how many elements they require and what they fill in with.
+pub(crate) fn deserialize_tail<'de, D, T, F, const N: usize>(
+    deserializer: D,
+    required: usize,
+    fill: F,
+) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    F: FnMut() -> T,
+{
+    deserializer.deserialize_seq(TailVisitor::<T, F, N> {
+        required,
+        fill,
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays whose tail may be missing
+struct TailVisitor<T, F, const N: usize> {
+    /// Number of leading elements that must be present
+    required: usize,
+    fill: F,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, F, const N: usize> Visitor<'de> for TailVisitor<T, F, N>
+where
+    T: Deserialize<'de>,
+    F: FnMut() -> T,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of {} to {} elements", self.required, N)
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut ended = false;
+        let arr = crate::try_build_array(|idx| {
+            if !ended {
+                match seq.next_element()? {
+                    Some(val) => return Ok(val),
+                    None if idx < self.required => {
+                        return Err(de::Error::invalid_length(idx, &self))
+                    }
+                    None => ended = true,
+                }
+            }
+            Ok((self.fill)())
+        })?;
+
+        // Make sure there aren't any elements left over
+        if !ended && seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(crate::too_long(seq, N, &self));
+        }
+        Ok(arr)
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays leniently, padding any missing elements with an explicit value
+//!
+//! Input may hold anywhere from zero to `N` elements; whatever is missing from the end is filled
+//! in with the pad value `P`, converted with `T::from(P)`. More than `N` elements is still an
+//! error. Serialization always writes the full array, pad values included, as a sequence so that
+//! binary formats such as bincode record how many elements follow.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     // Unused slots are 0xFF on the wire
+//!     #[serde(with = "serde_arrays::padded::Padded::<0xFF>")]
+//!     slots: [u8; 6],
+//! }
+//!
+//! let record: Record = serde_json::from_str(r#"{"slots":[3,1,4]}"#)?;
+//! assert_eq!(record.slots, [3, 1, 4, 0xFF, 0xFF, 0xFF]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Unlike [`default_filled`](crate::default_filled), the pad value need not be `T::default()`.
+
+use crate::optional_tail::deserialize_tail;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize and deserialize `[T; N]`, padding short input with `T::from(P)`
+///
+/// Use as `#[serde(with = "serde_arrays::padded::Padded::<P>")]`.
+pub struct Padded<const P: u8>;
+
+impl<const P: u8> Padded<P> {
+    /// Serialize the full array as a sequence
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        crate::seq::serialize(data, ser)
+    }
+
+    /// Deserialize an array of up to `N` elements, padding any that are missing with `P`
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + From<u8>,
+    {
+        deserialize_tail(deserializer, 0, || T::from(P))
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize a pair of arrays of differing sizes, `([A; N], [B; M])`
+//!
+//! The pair is written as a 2-tuple of arrays, each handled exactly as [`serde_arrays`](crate)
+//! handles a single array, and each checked against its own length when deserializing:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Cipher {
+//!     #[serde(with = "serde_arrays::pair")]
+//!     iv_and_key: ([u8; 16], [u8; 32]),
+//! }
+//! # let cipher = Cipher { iv_and_key: ([1; 16], [2; 32]) };
+//! # let json = serde_json::to_string(&cipher)?;
+//! # assert_eq!(cipher, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// Serialize a pair of arrays as a 2-tuple of arrays
+pub fn serialize<S, A, B, const N: usize, const M: usize>(
+    data: &([A; N], [B; M]),
+    ser: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: Serialize,
+    B: Serialize,
+{
+    let mut s = ser.serialize_tuple(2)?;
+    s.serialize_element(&ArrayWrap::new(&data.0))?;
+    s.serialize_element(&ArrayWrap::new(&data.1))?;
+    s.end()
+}
+
+/// Deserialize a pair of arrays, checking each against its own length
+pub fn deserialize<'de, D, A, B, const N: usize, const M: usize>(
+    deserializer: D,
+) -> Result<([A; N], [B; M]), D::Error>
+where
+    D: Deserializer<'de>,
+    A: Deserialize<'de>,
+    B: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        2,
+        PairVisitor::<A, B, N, M> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for ([A; N], [B; M]) pairs
+struct PairVisitor<A, B, const N: usize, const M: usize> {
+    _marker: PhantomData<(A, B)>,
+}
+
+impl<'de, A, B, const N: usize, const M: usize> Visitor<'de> for PairVisitor<A, B, N, M>
+where
+    A: Deserialize<'de>,
+    B: Deserialize<'de>,
+{
+    type Value = ([A; N], [B; M]);
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a pair of arrays of sizes {} and {}", N, M)
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(ArraySeed::<A, N>::new())?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let second = seq
+            .next_element_seed(ArraySeed::<B, M>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        // Make sure there isn't anything left over
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(crate::too_long(seq, 2, &self));
+        }
+
+        Ok((first, second))
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays with an explicit length prefix
+//!
+//! Binary formats typically write a tuple's elements with no length in front, since the reader
+//! knows it from the type. A generic reader that doesn't know the type, and so needs the length to
+//! skip over the array, can't read that. This module writes `[T; N]` with `serialize_seq(Some(N))`
+//! instead, which such formats prefix with the length, and when deserializing checks that the
+//! prefix is exactly `N` before reading any elements:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Record {
+//!     #[serde(with = "serde_arrays::prefixed")]
+//!     values: [u8; 40],
+//! }
+//!
+//! let record = Record { values: [1; 40] };
+//! let bytes = bincode::serialize(&record).unwrap();
+//! assert_eq!(bytes[..8], 40u64.to_le_bytes());
+//! # assert_eq!(record, bincode::deserialize(&bytes).unwrap());
+//! ```
+//!
+//! This is the same representation as [`seq`](crate::seq), under a name that says what it is for.
+
+pub use crate::fixed_size_list::{deserialize, serialize};
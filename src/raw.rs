@@ -0,0 +1,149 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Read arrays directly out of fixed binary record layouts
+//!
+//! This is lower-level than Serde's usual flow, intended for bridging `#[repr(C)]` buffers where
+//! an array sits at a known byte offset within a larger record:
+//!
+//! ```
+//! // A record with a 2-byte header, then 3 `u16`s each padded out to 4 bytes
+//! let mut record = vec![0xAA, 0xBB];
+//! for value in &[1u16, 2, 3] {
+//!     record.extend_from_slice(&value.to_ne_bytes());
+//!     record.extend_from_slice(&[0, 0]);
+//! }
+//!
+//! let arr: [u16; 3] = serde_arrays::raw::read_at(&record, 2, 4)?;
+//! assert_eq!(arr, [1, 2, 3]);
+//! # Ok::<(), serde_arrays::raw::RawError>(())
+//! ```
+
+use core::fmt;
+
+/// "Plain old data" types that can be read directly from bytes
+pub trait Pod: Copy {
+    /// Size of the type in bytes
+    const SIZE: usize;
+
+    /// Read a value from exactly `SIZE` bytes in native byte order
+    fn read_ne(bytes: &[u8]) -> Self;
+
+    /// Read a value from exactly `SIZE` bytes in little-endian byte order
+    fn read_le(bytes: &[u8]) -> Self;
+
+    /// Write the value into exactly `SIZE` bytes in little-endian byte order
+    fn write_le(self, out: &mut [u8]);
+}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(
+            impl Pod for $ty {
+                const SIZE: usize = core::mem::size_of::<$ty>();
+
+                fn read_ne(bytes: &[u8]) -> Self {
+                    let mut buf = [0; core::mem::size_of::<$ty>()];
+                    buf.copy_from_slice(bytes);
+                    <$ty>::from_ne_bytes(buf)
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut buf = [0; core::mem::size_of::<$ty>()];
+                    buf.copy_from_slice(bytes);
+                    <$ty>::from_le_bytes(buf)
+                }
+
+                fn write_le(self, out: &mut [u8]) {
+                    out.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Errors reading an array from a raw byte buffer
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RawError {
+    /// The stride is smaller than the size of an element, so elements would overlap
+    InvalidStride {
+        /// The requested stride
+        stride: usize,
+        /// The size of each element
+        size: usize,
+    },
+    /// The array extends past the end of the buffer
+    OutOfBounds {
+        /// The byte offset the array would need to extend to
+        end: usize,
+        /// The length of the buffer
+        len: usize,
+    },
+}
+
+impl fmt::Display for RawError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RawError::InvalidStride { stride, size } => write!(
+                f,
+                "stride of {} bytes is smaller than the element size of {} bytes",
+                stride, size
+            ),
+            RawError::OutOfBounds { end, len } => write!(
+                f,
+                "array extends to byte {} but the buffer is only {} bytes",
+                end, len
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RawError {}
+
+/// Read `N` elements from `bytes`, starting at byte `offset` and advancing `stride` bytes between
+/// the start of each element
+///
+/// Elements are read in native byte order, matching a `#[repr(C)]` buffer produced on the same
+/// machine. The buffer is bounds-checked before anything is read.
+pub fn read_at<T: Pod, const N: usize>(
+    bytes: &[u8],
+    offset: usize,
+    stride: usize,
+) -> Result<[T; N], RawError> {
+    if stride < T::SIZE {
+        return Err(RawError::InvalidStride {
+            stride,
+            size: T::SIZE,
+        });
+    }
+
+    let end = match N {
+        0 => Some(offset),
+        _ => stride
+            .checked_mul(N - 1)
+            .and_then(|last| last.checked_add(offset))
+            .and_then(|last| last.checked_add(T::SIZE)),
+    };
+    match end {
+        Some(end) if end <= bytes.len() => {}
+        _ => {
+            return Err(RawError::OutOfBounds {
+                end: end.unwrap_or(usize::MAX),
+                len: bytes.len(),
+            })
+        }
+    }
+
+    crate::try_build_array(|i| {
+        let start = offset + i * stride;
+        Ok(T::read_ne(&bytes[start..start + T::SIZE]))
+    })
+}
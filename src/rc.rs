@@ -0,0 +1,75 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize reference-counted arrays, `Rc<[T; N]>` and `Arc<[T; N]>`
+//!
+//! Requires the `rc` feature.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::{rc::Rc, sync::Arc};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Tables {
+//!     #[serde(with = "serde_arrays::rc")]
+//!     local: Rc<[u32; 256]>,
+//!     #[serde(with = "serde_arrays::rc")]
+//!     shared: Arc<[u32; 256]>,
+//! }
+//! # let data = Tables { local: Rc::new([1; 256]), shared: Arc::new([2; 256]) };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! As with Serde's own `rc` feature, serializing does not preserve sharing: every pointer to the
+//! same array is written out in full, and deserializing gives each its own separate allocation.
+
+use alloc::{rc::Rc, sync::Arc};
+use core::ops::Deref;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+/// Reference-counted pointers supported by [`rc::deserialize`](deserialize)
+pub trait Shared: Deref + Sized {
+    /// Move `value` into a new allocation
+    fn new(value: Self::Target) -> Self;
+}
+
+impl<T> Shared for Rc<T> {
+    fn new(value: T) -> Self {
+        Rc::new(value)
+    }
+}
+
+impl<T> Shared for Arc<T> {
+    fn new(value: T) -> Self {
+        Arc::new(value)
+    }
+}
+
+/// Serialize a reference-counted array exactly as the array itself
+pub fn serialize<S, P, T, const N: usize>(data: &P, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    P: Deref<Target = [T; N]>,
+    T: Serialize,
+{
+    crate::serialize(&**data, ser)
+}
+
+/// Deserialize an array into a new reference-counted allocation
+pub fn deserialize<'de, D, P, T, const N: usize>(deserializer: D) -> Result<P, D::Error>
+where
+    D: Deserializer<'de>,
+    P: Shared<Target = [T; N]>,
+    T: Deserialize<'de>,
+{
+    crate::deserialize(deserializer).map(P::new)
+}
@@ -0,0 +1,138 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays with run-length encoding
+//!
+//! Arrays that are mostly one value, such as a large buffer of zeroes, are written as a sequence
+//! of `(value, count)` runs, each giving a value and how many times in a row it appears:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Page {
+//!     #[serde(with = "serde_arrays::rle")]
+//!     bytes: [u8; 4096],
+//! }
+//!
+//! let mut page = Page { bytes: [0; 4096] };
+//! page.bytes[100] = 7;
+//! let json = serde_json::to_string(&page)?;
+//! assert_eq!(json, r#"{"bytes":[[0,100],[7,1],[0,3995]]}"#);
+//! assert_eq!(page, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! When deserializing, the counts must add up to exactly `N`, and a run with a count of zero is
+//! rejected. Elements are cloned from their run's value, so `T` must implement `Clone`.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+/// Serialize an array as a sequence of `(value, count)` runs
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + PartialEq,
+{
+    let runs = data.windows(2).filter(|pair| pair[0] != pair[1]).count() + (N > 0) as usize;
+
+    let mut s = ser.serialize_seq(Some(runs))?;
+    let mut start = 0;
+    while start < N {
+        let value = &data[start];
+        let count = data[start..]
+            .iter()
+            .take_while(|item| *item == value)
+            .count();
+        s.serialize_element(&(value, count as u64))?;
+        start += count;
+    }
+    s.end()
+}
+
+/// Deserialize an array from a sequence of `(value, count)` runs adding up to exactly `N`
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Clone,
+{
+    deserializer.deserialize_seq(RleVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays encoded as runs
+struct RleVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for RleVisitor<T, N>
+where
+    T: Deserialize<'de> + Clone,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of runs adding up to {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut runs = 0;
+        let mut value: Option<T> = None;
+        let mut remaining = 0u64;
+
+        let arr = crate::try_build_array(|idx| {
+            if remaining == 0 {
+                let (next, count) = seq
+                    .next_element::<(T, u64)>()
+                    .map_err(|err| de::Error::custom(format_args!("in run {}: {}", runs, err)))?
+                    .ok_or_else(|| de::Error::invalid_length(idx, &self))?;
+                if count == 0 {
+                    return Err(de::Error::custom(format_args!(
+                        "run {} has a count of zero",
+                        runs
+                    )));
+                }
+                if count > (N - idx) as u64 {
+                    return Err(too_many::<A::Error>(N));
+                }
+                runs += 1;
+                value = Some(next);
+                remaining = count;
+            }
+
+            remaining -= 1;
+            let element = if remaining == 0 {
+                value.take()
+            } else {
+                value.clone()
+            };
+            Ok(element.expect("a run is always read before its elements"))
+        })?;
+
+        match seq.next_element::<(IgnoredAny, u64)>()? {
+            Some((_, 0)) => Err(de::Error::custom(format_args!(
+                "run {} has a count of zero",
+                runs
+            ))),
+            Some(_) => Err(too_many(N)),
+            None => Ok(arr),
+        }
+    }
+}
+
+/// The error for runs adding up to more than `len` elements
+fn too_many<E: de::Error>(len: usize) -> E {
+    E::custom(format_args!("runs add up to more than {} elements", len))
+}
@@ -0,0 +1,91 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize float arrays rounded to a fixed number of decimal places
+//!
+//! Floats such as `0.1f32` often print noisily once widened, e.g. as `0.10000000149011612`. For
+//! human-readable formats, [`Rounded`] rounds each element to `PLACES` decimal places before
+//! serializing it; binary formats receive the values unchanged. Deserialization is the same as
+//! plain [`serde_arrays`](crate).
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Calibration {
+//!     #[serde(with = "serde_arrays::rounded::Rounded::<2>")]
+//!     offsets: [f64; 3],
+//! }
+//!
+//! let cal = Calibration { offsets: [0.1 + 0.2, 1.0 / 3.0, 2.0] };
+//! assert_eq!(serde_json::to_string(&cal)?, r#"{"offsets":[0.3,0.33,2.0]}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// A floating-point type that can be rounded to a number of decimal places
+pub trait Float: Copy + Serialize {
+    /// Round to `places` decimal places
+    fn round_to(self, places: u32) -> Self;
+}
+
+impl Float for f64 {
+    fn round_to(self, places: u32) -> Self {
+        let scale = 10f64.powi(places as i32);
+        let rounded = (self * scale).round() / scale;
+        // Very large values (or very many places) can overflow the scaling; keep those as-is
+        if rounded.is_finite() {
+            rounded
+        } else {
+            self
+        }
+    }
+}
+
+impl Float for f32 {
+    fn round_to(self, places: u32) -> Self {
+        // Round in double precision, then take the nearest `f32` to the rounded value
+        f64::from(self).round_to(places) as f32
+    }
+}
+
+/// Serialize `[T; N]` with each float rounded to `PLACES` decimal places in human-readable formats
+///
+/// Use as `#[serde(with = "serde_arrays::rounded::Rounded::<PLACES>")]`.
+pub struct Rounded<const PLACES: u32>;
+
+impl<const PLACES: u32> Rounded<PLACES> {
+    /// Serialize an array, rounding each element if the format is human-readable
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Float,
+    {
+        if !ser.is_human_readable() {
+            return crate::serialize(data, ser);
+        }
+
+        let mut s = ser.serialize_tuple(N)?;
+        for item in data {
+            s.serialize_element(&item.round_to(PLACES))?;
+        }
+        s.end()
+    }
+
+    /// Deserialize an array; values are read back as-is
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        crate::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Describe arrays in JSON Schema for `schemars`
+//!
+//! `schemars` only knows the schema of arrays of up to 32 elements, and can't see through
+//! `#[serde(with = "serde_arrays")]` to find out what such a field looks like. Pair that attribute
+//! with `#[schemars(with = "serde_arrays::schemars::Array<T, N>")]` to describe the field as a JSON
+//! Schema `array` of exactly `N` items:
+//!
+//! ```
+//! use schemars::JsonSchema;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, JsonSchema)]
+//! struct Reading {
+//!     #[serde(with = "serde_arrays")]
+//!     #[schemars(with = "serde_arrays::schemars::Array<u32, 40>")]
+//!     samples: [u32; 40],
+//! }
+//!
+//! let schema = schemars::schema_for!(Reading);
+//! let samples = schema.pointer("/properties/samples").unwrap();
+//! assert_eq!(samples["type"], "array");
+//! assert_eq!(samples["minItems"], 40);
+//! assert_eq!(samples["maxItems"], 40);
+//! ```
+//!
+//! The schema matches the one `schemars` itself gives arrays it does know. To build it directly,
+//! e.g. for `#[schemars(schema_with = "...")]`, use [`schema`].
+
+use ::schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use alloc::{borrow::Cow, format};
+use core::marker::PhantomData;
+
+/// Stand-in type describing a `[T; N]` array to `schemars`
+///
+/// Use as `#[schemars(with = "serde_arrays::schemars::Array<T, N>")]`.
+pub struct Array<T, const N: usize>(PhantomData<T>);
+
+impl<T: JsonSchema, const N: usize> JsonSchema for Array<T, N> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        format!("Array_size_{}_of_{}", N, T::schema_name()).into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        format!("[{}; {}]", N, T::schema_id()).into()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        schema::<T, N>(generator)
+    }
+}
+
+/// Build the JSON Schema for a `[T; N]` array: an `array` of exactly `N` items
+pub fn schema<T: JsonSchema, const N: usize>(generator: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "array",
+        "items": generator.subschema_for::<T>(),
+        "minItems": N,
+        "maxItems": N,
+    })
+}
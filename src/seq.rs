@@ -0,0 +1,53 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays as Serde sequences rather than tuples
+//!
+//! [`serde_arrays`](crate) represents `[T; N]` as a tuple, just as Serde itself does for small
+//! arrays. A tuple tells the format up front that exactly `N` elements follow, which lets compact
+//! binary formats such as bincode leave the length out entirely. Some formats and producers work
+//! better with sequences, however: a sequence carries its own length (or end marker), so it suits
+//! formats that only know how to tag variable-length arrays, at the cost of writing that length.
+//!
+//! This module uses `serialize_seq(Some(N))` and `deserialize_seq` instead, while still requiring
+//! exactly `N` elements. Self-describing formats such as JSON make no distinction, so the output
+//! there is identical:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Samples {
+//!     #[serde(with = "serde_arrays::seq")]
+//!     values: [u32; 40],
+//! }
+//! # let data = Samples { values: [1; 40] };
+//! # let json = serde_json::to_string(&data)?;
+//! # assert_eq!(data, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! This is the same representation as [`fixed_size_list`](crate::fixed_size_list), which exists
+//! under that name for Arrow users, and [`prefixed`](crate::prefixed).
+//!
+//! # Length checking
+//!
+//! Because a tuple's length is implied by its type, a format that leaves it out cannot tell when
+//! the input holds a different number of elements. How each representation catches a wrong
+//! length, when reading `[T; N]` from data written with some other length:
+//!
+//! | Format      | Tuple ([`serde_arrays`](crate))              | Sequence (this module) |
+//! |-------------|----------------------------------------------|------------------------|
+//! | JSON        | `invalid length` error                       | `invalid length` error |
+//! | MessagePack | `invalid length` error                       | `invalid length` error |
+//! | bincode     | too few: end of input; too many: **misread** | `invalid length` error |
+//!
+//! With bincode, a tuple written with more than `N` elements is read as `N` elements, and the rest
+//! as whatever comes next. Truncated input is an error in every case. Where the writer may not
+//! agree with the reader on `N`, use this module for strict length checking.
+
+pub use crate::fixed_size_list::{deserialize, serialize};
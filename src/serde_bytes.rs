@@ -0,0 +1,51 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize byte arrays exactly as [`serde_bytes`][serde_bytes] does
+//!
+//! Where [`bytes`](crate::bytes) falls back to an ordinary array for human-readable formats, this
+//! module always asks for a byte string, just as `serde_bytes::ByteArray` does. The two encodings
+//! are interchangeable, so `[u8; N]` fields can be exchanged with peers using `serde_bytes`:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Nonce {
+//!     #[serde(with = "serde_arrays::serde_bytes")]
+//!     nonce: [u8; 12],
+//! }
+//! # let nonce = Nonce { nonce: [7; 12] };
+//! # let b = bincode::serialize(&nonce).unwrap();
+//! # assert_eq!(nonce, bincode::deserialize(&b).unwrap());
+//! ```
+//!
+//! This matters for formats such as CBOR and MessagePack, which encode byte strings as a distinct
+//! type from arrays. Formats without byte strings decide for themselves what to do: JSON writes an
+//! array of numbers, while YAML refuses to serialize bytes at all, so prefer
+//! [`bytes`](crate::bytes) for those.
+//!
+//! [serde_bytes]: https://crates.io/crates/serde_bytes
+
+use crate::bytes::BytesVisitor;
+use serde::{de::Deserializer, ser::Serializer};
+
+/// Serialize a byte array as a byte string
+pub fn serialize<S, const N: usize>(data: &[u8; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.serialize_bytes(data)
+}
+
+/// Deserialize a byte array from a byte string, length-checked against `N`
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(BytesVisitor::<N>)
+}
@@ -6,7 +6,9 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::wrapper::ArrayWrap;
-use serde::ser::{Serialize, SerializeSeq, SerializeTuple, Serializer};
+#[cfg(feature = "alloc")]
+use serde::ser::SerializeSeq;
+use serde::ser::{Serialize, SerializeTuple, Serializer};
 
 /// Trait for types serializable using `serde_arrays`
 ///
@@ -16,11 +18,29 @@ use serde::ser::{Serialize, SerializeSeq, SerializeTuple, Serializer};
 ///
 /// If the greater flexibility lost by this approach is needed, see [`serde_with`][serde_with].
 ///
+/// Elements are always serialized in index order; nested arrays are written outermost first, so
+/// `[[1, 2, 3], [4, 5, 6]]` is written exactly as it reads, never transposed.
+///
 /// [serde_with]: https://crates.io/crates/serde_with/
 pub trait Serializable<T: Serialize, const N: usize> {
     fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer;
+
+    /// Number of elements passed to the outermost `serialize_tuple` or `serialize_seq` call
+    ///
+    /// This is `N` for `[T; N]`, `M` for `[[T; N]; M]`, and the number of arrays for
+    /// `Vec<[T; N]>`, letting formats that pre-size their buffers reserve capacity up front. Since
+    /// arrays and `Vec`s have an inherent `len`, call this through the trait, e.g.
+    /// `Serializable::<T, N>::len(&data)`.
+    fn len(&self) -> usize {
+        N
+    }
+
+    /// Whether [`len`](Serializable::len) is zero
+    fn is_empty(&self) -> bool {
+        Serializable::len(self) == 0
+    }
 }
 
 impl<T: Serialize, const N: usize, const M: usize> Serializable<T, N> for [[T; N]; M] {
@@ -30,16 +50,39 @@ impl<T: Serialize, const N: usize, const M: usize> Serializable<T, N> for [[T; N
     {
         // Fixed-length structures, including arrays, are supported in Serde as tuples
         // See: https://serde.rs/impl-serialize.html#serializing-a-tuple
-        let mut s = ser.serialize_tuple(N)?;
+        let mut s = ser.serialize_tuple(M)?;
+        for item in self {
+            let wrapped = ArrayWrap::new(item);
+            s.serialize_element(&wrapped)?;
+        }
+        s.end()
+    }
+
+    fn len(&self) -> usize {
+        M
+    }
+}
+
+impl<T: Serialize, const N: usize, const M: usize> Serializable<T, N> for [&[T; N]; M] {
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut s = ser.serialize_tuple(M)?;
         for item in self {
             let wrapped = ArrayWrap::new(item);
             s.serialize_element(&wrapped)?;
         }
         s.end()
     }
+
+    fn len(&self) -> usize {
+        M
+    }
 }
 
-impl<T: Serialize, const N: usize> Serializable<T, N> for Vec<[T; N]> {
+#[cfg(feature = "alloc")]
+impl<T: Serialize, const N: usize> Serializable<T, N> for alloc::vec::Vec<[T; N]> {
     fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -51,6 +94,10 @@ impl<T: Serialize, const N: usize> Serializable<T, N> for Vec<[T; N]> {
         }
         s.end()
     }
+
+    fn len(&self) -> usize {
+        alloc::vec::Vec::len(self)
+    }
 }
 
 impl<T: Serialize, const N: usize> Serializable<T, N> for [T; N] {
@@ -77,3 +124,21 @@ where
     }
     s.end()
 }
+
+/// References serialize exactly as whatever they refer to, so borrowed arrays can be passed
+/// wherever a `Serializable` is expected
+impl<T: Serialize, const N: usize, A> Serializable<T, N> for &A
+where
+    A: Serializable<T, N> + ?Sized,
+{
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize(ser)
+    }
+
+    fn len(&self) -> usize {
+        Serializable::len(*self)
+    }
+}
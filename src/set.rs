@@ -0,0 +1,104 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize sets of arrays, such as `HashSet<[T; N]>` and `BTreeSet<[T; N]>`
+//!
+//! A set is written as a sequence of its members, each handled exactly as
+//! [`serde_arrays`](crate) handles a single array:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::collections::{BTreeSet, HashSet};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Known {
+//!     #[serde(with = "serde_arrays::set")]
+//!     ids: HashSet<[u8; 40]>,
+//!     #[serde(with = "serde_arrays::set")]
+//!     sorted: BTreeSet<[u8; 40]>,
+//! }
+//! # let known = Known {
+//! #     ids: vec![[1; 40], [2; 40]].into_iter().collect(),
+//! #     sorted: vec![[3; 40]].into_iter().collect(),
+//! # };
+//! # let json = serde_json::to_string(&known)?;
+//! # assert_eq!(known, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Any collection that can be iterated by reference and extended with arrays will do. As with
+//! Serde's own sets, members that appear more than once are simply deduplicated by the set, not
+//! treated as an error. Should a member fail to deserialize, the error gives its index in the
+//! sequence.
+
+use crate::{wrapper::ArrayWrap, ArraySeed};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+/// Serialize a set of arrays as a sequence
+pub fn serialize<'a, S, C, T, const N: usize>(data: &'a C, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    &'a C: IntoIterator<Item = &'a [T; N]>,
+    <&'a C as IntoIterator>::IntoIter: ExactSizeIterator,
+    T: Serialize + 'a,
+{
+    let members = data.into_iter();
+    let mut s = ser.serialize_seq(Some(members.len()))?;
+    for member in members {
+        s.serialize_element(&ArrayWrap::new(member))?;
+    }
+    s.end()
+}
+
+/// Deserialize a set of arrays from a sequence
+pub fn deserialize<'de, D, C, T, const N: usize>(deserializer: D) -> Result<C, D::Error>
+where
+    D: Deserializer<'de>,
+    C: Default + Extend<[T; N]>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(SetVisitor::<C, T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for sets of [T; N] arrays
+struct SetVisitor<C, T, const N: usize> {
+    _marker: PhantomData<(C, T)>,
+}
+
+impl<'de, C, T, const N: usize> Visitor<'de> for SetVisitor<C, T, N>
+where
+    C: Default + Extend<[T; N]>,
+    T: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence of arrays of size {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut data = C::default();
+        let mut idx = 0;
+        while let Some(member) = seq
+            .next_element_seed(ArraySeed::<T, N>::new())
+            .map_err(|err| de::Error::custom(format_args!("at index {}: {}", idx, err)))?
+        {
+            data.extend(Some(member));
+            idx += 1;
+        }
+        Ok(data)
+    }
+}
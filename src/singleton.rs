@@ -0,0 +1,178 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize a single-element array that may also be written as its bare element
+//!
+//! Some producers "unwrap" single-element arrays, writing `5` rather than `[5]`. With this module,
+//! a `[T; 1]` in a human-readable format accepts either, so both `5` and `[5]` deserialize as
+//! `[5]`. The array is always serialized as an array, and formats that aren't human-readable read
+//! it exactly as [`serde_arrays`](crate) does.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Tags {
+//!     #[serde(with = "serde_arrays::singleton")]
+//!     tag: [u32; 1],
+//! }
+//!
+//! assert_eq!(Tags { tag: [5] }, serde_json::from_str(r#"{"tag":5}"#)?);
+//! assert_eq!(Tags { tag: [5] }, serde_json::from_str(r#"{"tag":[5]}"#)?);
+//! assert_eq!(r#"{"tag":[5]}"#, serde_json::to_string(&Tags { tag: [5] })?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Reading the bare element relies on `deserialize_any`, so this is only of use with
+//! self-describing formats.
+
+use crate::ArrayVisitor;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        self,
+        value::{BorrowedBytesDeserializer, BorrowedStrDeserializer, BytesDeserializer},
+        Deserialize, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+    },
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize a single-element array as an array
+pub fn serialize<S, T>(data: &[T; 1], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::serialize(data, ser)
+}
+
+/// Deserialize a single-element array, or in human-readable formats its bare element
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<[T; 1], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(SingletonVisitor::<T>::new())
+    } else {
+        crate::deserialize(deserializer)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for a [T; 1] array that may also be written as its bare element
+///
+/// A sequence or map is read exactly as [`ArrayVisitor`] reads it, and any scalar is read as the
+/// array's one element.
+struct SingletonVisitor<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<T> SingletonVisitor<T> {
+    fn new() -> Self {
+        SingletonVisitor {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, T> SingletonVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    /// Deserialize the array's single element from a scalar
+    fn element<D>(deserializer: D) -> Result<[T; 1], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(|element| [element])
+    }
+}
+
+macro_rules! visit_scalar {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E>(self, v: $ty) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Self::element(v.into_deserializer())
+            }
+        )*
+    };
+}
+
+impl<'de, T> Visitor<'de> for SingletonVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; 1];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, 1>(formatter)?;
+        write!(formatter, " or its single element")
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        ArrayVisitor::<T, 1>::new().visit_seq(seq)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        ArrayVisitor::<T, 1>::new().visit_map(map)
+    }
+
+    visit_scalar! {
+        visit_bool(bool),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f64(f64),
+        visit_char(char),
+        visit_str(&str),
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::element(BorrowedStrDeserializer::new(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::element(BytesDeserializer::new(v))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::element(BorrowedBytesDeserializer::new(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Self::element(().into_deserializer())
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        self.visit_unit()
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::ser::{self, Serialize, SerializeTuple, Serializer};
+
+/// Serialize a slice as an array of size `N`, failing if it doesn't hold exactly `N` elements
+///
+/// The output is identical to that of [`serialize`](crate::serialize) for a `[T; N]`, without
+/// first copying the slice into an array:
+///
+/// ```
+/// let data = vec![1u32, 2, 3, 4];
+///
+/// let mut json = Vec::new();
+/// serde_arrays::serialize_slice::<_, _, 4>(&data, &mut serde_json::Serializer::new(&mut json))?;
+/// assert_eq!(json, b"[1,2,3,4]");
+///
+/// let mut json = Vec::new();
+/// let err = serde_arrays::serialize_slice::<_, _, 5>(&data, &mut serde_json::Serializer::new(&mut json));
+/// assert!(err.is_err());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn serialize_slice<S, T, const N: usize>(data: &[T], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    if data.len() != N {
+        return Err(ser::Error::custom(format_args!(
+            "expected a slice of {} elements, found {}",
+            N,
+            data.len()
+        )));
+    }
+
+    let mut s = ser.serialize_tuple(N)?;
+    for item in data {
+        s.serialize_element(item)?;
+    }
+    s.end()
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize small vectors backed by an inline array, using [`smallvec`]
+//!
+//! Requires the `smallvec` feature. A [`SmallVec<[T; N]>`] is written as a sequence of its
+//! elements and read back from a sequence of any length; past `N` elements it simply spills onto
+//! the heap, exactly as it would when pushed to:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use smallvec::SmallVec;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Path {
+//!     #[serde(with = "serde_arrays::smallvec")]
+//!     points: SmallVec<[u16; 32]>,
+//! }
+//!
+//! let path: Path = serde_json::from_str(r#"{"points":[1,2,3]}"#)?;
+//! assert_eq!(path.points.as_slice(), &[1, 2, 3]);
+//! assert!(!path.points.spilled());
+//! # assert_eq!(serde_json::to_string(&path)?, r#"{"points":[1,2,3]}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! [`smallvec`]: https://docs.rs/smallvec
+//! [`SmallVec<[T; N]>`]: ::smallvec::SmallVec
+
+use ::smallvec::{Array, SmallVec};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+/// Serialize a `SmallVec` as a sequence of its elements
+pub fn serialize<S, A>(data: &SmallVec<A>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    A: Array,
+    A::Item: Serialize,
+{
+    let mut s = ser.serialize_seq(Some(data.len()))?;
+    for item in data {
+        s.serialize_element(item)?;
+    }
+    s.end()
+}
+
+/// Deserialize a `SmallVec` from a sequence of any length
+pub fn deserialize<'de, D, A>(deserializer: D) -> Result<SmallVec<A>, D::Error>
+where
+    D: Deserializer<'de>,
+    A: Array,
+    A::Item: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(SmallVecVisitor::<A> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for SmallVec<A>
+struct SmallVecVisitor<A> {
+    _marker: PhantomData<A>,
+}
+
+impl<'de, A> Visitor<'de> for SmallVecVisitor<A>
+where
+    A: Array,
+    A::Item: Deserialize<'de>,
+{
+    type Value = SmallVec<A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        // Don't trust the size hint so far as to allocate an unbounded amount of memory up front
+        let mut data = SmallVec::new();
+        data.reserve(crate::cautious_capacity::<A::Item>(seq.size_hint()));
+        while let Some(item) = seq.next_element()? {
+            data.push(item);
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize numeric arrays with a moving-average smoothing window applied
+//!
+//! Each element of the result is the mean of the `W` raw values centered on it: for element `i`
+//! that is positions `i - (W - 1) / 2` through `i + W / 2`. Near either end of the array the
+//! window shrinks to the positions that exist, rather than padding with zeros or repeating the
+//! edge values, so the first element of a `W = 3` smoothing is the mean of the first two raw
+//! values. A `W` of 0 or 1 leaves the data unchanged.
+//!
+//! Integer results are rounded to the nearest integer. Serialization writes the values as-is.
+//!
+//! ```
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Sensor {
+//!     #[serde(with = "serde_arrays::smoothed::Smoothed::<3>")]
+//!     readings: [f64; 5],
+//! }
+//!
+//! let sensor: Sensor = serde_json::from_str(r#"{"readings":[1,2,6,2,1]}"#)?;
+//! assert_eq!(sensor.readings, [1.5, 3.0, 10.0 / 3.0, 3.0, 1.5]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use alloc::vec::Vec;
+use serde::{
+    de::{Deserialize, Deserializer},
+    ser::{Serialize, Serializer},
+};
+
+/// A numeric type that can be averaged by [`Smoothed`]
+pub trait Sample: Copy {
+    /// Convert to `f64` for averaging
+    fn to_f64(self) -> f64;
+
+    /// Convert an average back, rounding (and saturating) as needed
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_sample_int {
+    ($($t:ty),*) => {
+        $(
+            impl Sample for $t {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value.round() as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl Sample for f32 {
+    fn to_f64(self) -> f64 {
+        self.into()
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl Sample for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// Deserialize `[T; N]`, smoothing it with a moving average over a window of `W` elements
+///
+/// Use as `#[serde(with = "serde_arrays::smoothed::Smoothed::<W>")]`.
+pub struct Smoothed<const W: usize>;
+
+impl<const W: usize> Smoothed<W> {
+    /// Serialize an array unchanged
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        crate::serialize(data, ser)
+    }
+
+    /// Deserialize an array, then apply the moving average
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + Sample,
+    {
+        let raw: [T; N] = crate::deserialize(deserializer)?;
+        if W <= 1 {
+            return Ok(raw);
+        }
+
+        // Running sums let each window be averaged in constant time
+        let mut sums = Vec::with_capacity(N + 1);
+        sums.push(0.0);
+        for (idx, item) in raw.iter().enumerate() {
+            sums.push(sums[idx] + item.to_f64());
+        }
+
+        crate::try_build_array(|idx| {
+            let start = idx.saturating_sub((W - 1) / 2);
+            let end = (idx + W / 2 + 1).min(N);
+            let mean = (sums[end] - sums[start]) / (end - start) as f64;
+            Ok(T::from_f64(mean))
+        })
+    }
+}
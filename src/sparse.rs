@@ -0,0 +1,110 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize mostly-default arrays as maps of only their other elements
+//!
+//! `[0, 0, 99, 0, 0, 42]` is written as `{"2": 99, "5": 42}`: only the elements that differ from
+//! `T::default()` are written, keyed by their index. On input, every index that is missing is
+//! filled in with `T::default()`, while an index that is out of range or given more than once is
+//! an error.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Update {
+//!     #[serde(with = "serde_arrays::sparse")]
+//!     registers: [u32; 64],
+//! }
+//!
+//! let update: Update = serde_json::from_str(r#"{"registers":{"2":99,"5":42}}"#)?;
+//! assert_eq!(update.registers[..6], [0, 0, 99, 0, 0, 42]);
+//! assert_eq!(serde_json::to_string(&update)?, r#"{"registers":{"2":99,"5":42}}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Formats whose map keys may be integers, such as MessagePack or YAML, get integer keys.
+
+use crate::indexed_map::IndexSeed;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, Visitor},
+    ser::{Serialize, SerializeMap, Serializer},
+};
+
+/// Serialize an array as a map of its non-default elements, keyed by index
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize + Default + PartialEq,
+{
+    let default = T::default();
+    let len = data.iter().filter(|item| **item != default).count();
+
+    let mut map = ser.serialize_map(Some(len))?;
+    for (idx, item) in data.iter().enumerate() {
+        if *item != default {
+            map.serialize_entry(&idx, item)?;
+        }
+    }
+    map.end()
+}
+
+/// Deserialize an array from a map keyed by index, filling missing indices with `T::default()`
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    deserializer.deserialize_map(SparseVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays represented as maps of non-default elements
+struct SparseVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for SparseVisitor<T, N>
+where
+    T: Deserialize<'de> + Default,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a map with indices below {} as keys, for an array of size {}",
+            N, N
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut data: [T; N] = crate::default_array();
+        let mut seen = [false; N];
+
+        while let Some(idx) = map.next_key_seed(IndexSeed)? {
+            if idx >= N {
+                return Err(de::Error::custom(format_args!(
+                    "index {} is out of range for an array of size {}",
+                    idx, N
+                )));
+            }
+            if seen[idx] {
+                return Err(de::Error::custom(format_args!("duplicate index {}", idx)));
+            }
+            seen[idx] = true;
+            data[idx] = map.next_value()?;
+        }
+
+        Ok(data)
+    }
+}
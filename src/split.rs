@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::array::IntoIter;
+
+/// Split a `[T; N]` into its first `A` and last `B` elements
+///
+/// The elements are moved, not copied or cloned, so this works for any `T`:
+///
+/// ```
+/// let key: [u8; 64] = [7; 64];
+/// let (secret, public): ([u8; 32], [u8; 32]) = serde_arrays::split_array(key);
+/// # assert_eq!(secret, [7; 32]);
+/// # assert_eq!(public, [7; 32]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `A + B` is not `N`.
+pub fn split_array<T, const N: usize, const A: usize, const B: usize>(
+    arr: [T; N],
+) -> ([T; A], [T; B]) {
+    assert_eq!(
+        A + B,
+        N,
+        "cannot split an array of size {} into {} and {}",
+        N,
+        A,
+        B
+    );
+
+    #[allow(deprecated)] // Arrays only implement `IntoIterator` by value since Rust 1.53
+    let mut elements = IntoIter::new(arr);
+    // The lengths were checked above, so neither of these can fail
+    let first = crate::collect_array(elements.by_ref().take(A)).unwrap_or_else(|_| unreachable!());
+    let last = crate::collect_array(elements).unwrap_or_else(|_| unreachable!());
+    (first, last)
+}
+
+/// Concatenate a `[T; A]` and a `[T; B]` into a single `[T; N]`
+///
+/// This is the inverse of [`split_array`]:
+///
+/// ```
+/// let whole: [u8; 64] = serde_arrays::concat_array([1; 32], [2; 32]);
+/// # assert_eq!(whole[..32], [1; 32]);
+/// # assert_eq!(whole[32..], [2; 32]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `A + B` is not `N`.
+pub fn concat_array<T, const A: usize, const B: usize, const N: usize>(
+    first: [T; A],
+    last: [T; B],
+) -> [T; N] {
+    assert_eq!(
+        A + B,
+        N,
+        "cannot concatenate arrays of size {} and {} into {}",
+        A,
+        B,
+        N
+    );
+
+    #[allow(deprecated)] // Arrays only implement `IntoIterator` by value since Rust 1.53
+    let elements = IntoIter::new(first).chain(IntoIter::new(last));
+    // The lengths were checked above, so this can't fail
+    crate::collect_array(elements).unwrap_or_else(|_| unreachable!())
+}
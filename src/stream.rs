@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArrayVisitor;
+use core::{fmt, marker::PhantomData};
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+
+/// Deserialize an array of exactly `N` elements, handing each element to a callback as it is read
+///
+/// `f` is called with the index and value of each element in turn, and takes ownership of the
+/// value, so the array is never held in memory as a whole. This suits processing large arrays on
+/// the fly, e.g. hashing or summing them:
+///
+/// ```
+/// let mut de = serde_json::Deserializer::from_str("[1, 2, 3, 4]");
+/// let mut sum = 0;
+/// serde_arrays::deserialize_stream::<_, u32, _, _, 4>(&mut de, |_, n| {
+///     sum += n;
+///     Ok::<_, std::convert::Infallible>(())
+/// })?;
+///
+/// assert_eq!(sum, 10);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// Should `f` return an error, deserialization stops immediately with that error as a custom Serde
+/// error. Exactly `N` elements are still required, but since elements are handed over as they
+/// arrive, `f` will already have seen the leading elements of a sequence that turns out to be too
+/// short or too long; only if the format reports the sequence's length up front is a mismatch
+/// caught before `f` is first called.
+///
+/// This is not usable with `#[serde(with)]`, but rather intended for manual `Deserialize`
+/// implementations or direct use with a `Deserializer`.
+pub fn deserialize_stream<'de, D, T, F, E, const N: usize>(
+    deserializer: D,
+    f: F,
+) -> Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    F: FnMut(usize, T) -> Result<(), E>,
+    E: fmt::Display,
+{
+    deserializer.deserialize_tuple(
+        N,
+        StreamVisitor::<T, F, N> {
+            f,
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` handing each element of a [T; N] array to a callback
+struct StreamVisitor<T, F, const N: usize> {
+    f: F,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, F, E, const N: usize> Visitor<'de> for StreamVisitor<T, F, N>
+where
+    T: Deserialize<'de>,
+    F: FnMut(usize, T) -> Result<(), E>,
+    E: fmt::Display,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut f = self.f;
+        let expected = ArrayVisitor::<T, N>::new();
+
+        match seq.size_hint() {
+            Some(len) if len != N => return Err(de::Error::invalid_length(len, &expected)),
+            _ => {}
+        }
+
+        for idx in 0..N {
+            let val = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(idx, &expected))?;
+            f(idx, val)
+                .map_err(|err| de::Error::custom(format_args!("at index {}: {}", idx, err)))?;
+        }
+
+        // Make sure there aren't any elements left over
+        match seq.next_element::<de::IgnoredAny>()? {
+            Some(_) => Err(crate::too_long(seq, N, &expected)),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays of strings using a dictionary encoding
+//!
+//! Arrays with many repeated strings, such as labels, are written as a table of the distinct
+//! strings (in order of first appearance) plus one index into that table per element:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Labels {
+//!     #[serde(with = "serde_arrays::string_dict")]
+//!     labels: [String; 4],
+//! }
+//!
+//! let labels = Labels {
+//!     labels: ["cat".into(), "dog".into(), "cat".into(), "cat".into()],
+//! };
+//! let json = serde_json::to_string(&labels)?;
+//! assert_eq!(json, r#"{"labels":{"dict":["cat","dog"],"idx":[0,1,0,0]}}"#);
+//! # assert_eq!(labels, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{field::FieldSeed, wrapper::ArrayWrap, ArraySeed};
+use core::fmt;
+use serde::{
+    de::{self, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+};
+use std::{collections::HashMap, string::String, vec::Vec};
+
+const FIELDS: &[&str] = &["dict", "idx"];
+
+/// Serialize an array of strings as a dictionary of distinct strings plus indices into it
+pub fn serialize<S, const N: usize>(data: &[String; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut dict: Vec<&str> = Vec::new();
+    let mut lookup: HashMap<&str, u32> = HashMap::new();
+    let mut idx = [0u32; N];
+    for (place, item) in idx.iter_mut().zip(data) {
+        *place = *lookup.entry(item).or_insert_with(|| {
+            dict.push(item);
+            (dict.len() - 1) as u32
+        });
+    }
+
+    let mut s = ser.serialize_struct("StringDict", 2)?;
+    s.serialize_field("dict", &dict)?;
+    s.serialize_field("idx", &ArrayWrap::new(&idx))?;
+    s.end()
+}
+
+/// Deserialize an array of strings from a dictionary and indices into it
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[String; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct("StringDict", FIELDS, DictVisitor::<N>)
+}
+
+/// A Serde Deserializer `Visitor` for dictionary-encoded [String; N] arrays
+struct DictVisitor<const N: usize>;
+
+impl<const N: usize> DictVisitor<N> {
+    /// Look up each index in the dictionary to reconstruct the array
+    fn resolve<E: de::Error>(dict: Vec<String>, idx: [u32; N]) -> Result<[String; N], E> {
+        crate::try_build_array(|i| {
+            dict.get(idx[i] as usize).cloned().ok_or_else(|| {
+                E::custom(format_args!(
+                    "index {} at position {} is out of range for a dictionary of {} strings",
+                    idx[i],
+                    i,
+                    dict.len()
+                ))
+            })
+        })
+    }
+}
+
+impl<'de, const N: usize> Visitor<'de> for DictVisitor<N> {
+    type Value = [String; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "a string dictionary with an array of {} indices",
+            N
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let dict = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let idx = seq
+            .next_element_seed(ArraySeed::<u32, N>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::resolve(dict, idx)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dict = None;
+        let mut idx = None;
+        while let Some(key) = map.next_key_seed(FieldSeed(FIELDS))? {
+            match key.map(|idx| FIELDS[idx]) {
+                Some("dict") if dict.is_some() => return Err(de::Error::duplicate_field("dict")),
+                Some("dict") => dict = Some(map.next_value()?),
+                Some("idx") if idx.is_some() => return Err(de::Error::duplicate_field("idx")),
+                Some("idx") => idx = Some(map.next_value_seed(ArraySeed::<u32, N>::new())?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let dict = dict.ok_or_else(|| de::Error::missing_field("dict"))?;
+        let idx = idx.ok_or_else(|| de::Error::missing_field("idx"))?;
+        Self::resolve(dict, idx)
+    }
+}
@@ -0,0 +1,393 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays of enums in a compact positional tagged form
+//!
+//! By default Serde represents enums with payloads as externally tagged maps, e.g.
+//! `{"Click":[1,2]}`, which gets heavy for large arrays of events. This module instead writes each
+//! element as a `[discriminant, payload]` pair, where `discriminant` is the variant's index:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! enum Event {
+//!     Idle,
+//!     Click((i32, i32)),
+//!     Key(char),
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Log {
+//!     #[serde(with = "serde_arrays::tagged")]
+//!     events: [Event; 3],
+//! }
+//!
+//! let log = Log { events: [Event::Click((1, 2)), Event::Idle, Event::Key('x')] };
+//! let json = serde_json::to_string(&log)?;
+//! assert_eq!(json, r#"{"events":[[1,[1,2]],[0,null],[2,"x"]]}"#);
+//! # assert_eq!(log, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Unit variants are written with a unit payload (`null` in JSON). Only unit and newtype variants
+//! are supported; wrap the fields of tuple or struct variants in a struct of their own to use them
+//! with this module.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        self, Deserialize, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, SeqAccess,
+        VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{self, Impossible, Serialize, SerializeTuple, Serializer},
+};
+
+/// Serialize an array of enums as `[discriminant, payload]` pairs
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut s = ser.serialize_tuple(N)?;
+    for item in data {
+        s.serialize_element(&Tagged(item))?;
+    }
+    s.end()
+}
+
+/// Deserialize an array of enums from `[discriminant, payload]` pairs
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N,
+        TaggedArrayVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// Wrapper to serialize an enum as a `[discriminant, payload]` pair
+struct Tagged<'a, T>(&'a T);
+
+impl<'a, T: Serialize> Serialize for Tagged<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(TagSerializer { inner: serializer })
+    }
+}
+
+/// A `Serializer` that only accepts unit and newtype enum variants, writing them as pairs
+struct TagSerializer<S> {
+    inner: S,
+}
+
+impl<S: Serializer> TagSerializer<S> {
+    fn pair<P: Serialize + ?Sized>(self, index: u32, payload: &P) -> Result<S::Ok, S::Error> {
+        let mut s = self.inner.serialize_tuple(2)?;
+        s.serialize_element(&index)?;
+        s.serialize_element(payload)?;
+        s.end()
+    }
+}
+
+/// Error for any value that isn't a supported enum variant
+fn unsupported<E: ser::Error>() -> E {
+    E::custom("tagged arrays only support unit and newtype enum variants")
+}
+
+impl<S: Serializer> Serializer for TagSerializer<S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Impossible<S::Ok, S::Error>;
+    type SerializeTuple = Impossible<S::Ok, S::Error>;
+    type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = Impossible<S::Ok, S::Error>;
+    type SerializeStruct = Impossible<S::Ok, S::Error>;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.pair(variant_index, &())
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.pair(variant_index, value)
+    }
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn collect_str<T: fmt::Display + ?Sized>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, _value: &T) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(unsupported())
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(unsupported())
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays of tagged enums
+struct TaggedArrayVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for TaggedArrayVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| TaggedVisitor::<T> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Seed and `Visitor` to deserialize a single `[discriminant, payload]` pair
+struct TaggedVisitor<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for TaggedVisitor<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for TaggedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a [discriminant, payload] pair")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let value = T::deserialize(PairDeserializer { seq: &mut seq })?;
+
+        // Make sure there isn't anything after the payload
+        match seq.next_element::<de::IgnoredAny>()? {
+            Some(_) => Err(crate::too_long(seq, 2, &self)),
+            None => Ok(value),
+        }
+    }
+}
+
+/// A `Deserializer` presenting a `[discriminant, payload]` sequence as an enum
+struct PairDeserializer<A> {
+    seq: A,
+}
+
+impl<'de, A: SeqAccess<'de>> Deserializer<'de> for PairDeserializer<A> {
+    type Error = A::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> EnumAccess<'de> for PairDeserializer<A> {
+    type Error = A::Error;
+    type Variant = Self;
+
+    fn variant_seed<V>(mut self, seed: V) -> Result<(V::Value, Self), Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        // Read the discriminant as an integer first; some formats (notably JSON) only accept
+        // strings when asked for an identifier
+        let index: u32 = self
+            .seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &"a [discriminant, payload] pair"))?;
+        let variant = seed.deserialize(index.into_deserializer())?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> PairDeserializer<A> {
+    fn payload<S: DeserializeSeed<'de>>(mut self, seed: S) -> Result<S::Value, A::Error> {
+        self.seq
+            .next_element_seed(seed)?
+            .ok_or_else(|| de::Error::invalid_length(1, &"a [discriminant, payload] pair"))
+    }
+}
+
+impl<'de, A: SeqAccess<'de>> VariantAccess<'de> for PairDeserializer<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.payload(PhantomData::<()>)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        self.payload(seed)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::custom(
+            "tagged arrays only support unit and newtype enum variants",
+        ))
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(de::Error::custom(
+            "tagged arrays only support unit and newtype enum variants",
+        ))
+    }
+}
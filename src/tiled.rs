@@ -0,0 +1,124 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays from a shorter pattern repeated to fill every element
+//!
+//! A sequence of `k` elements, where `k` divides `N`, is tiled `N / k` times, so `[1, 2]` reads
+//! as `[1, 2, 1, 2, 1, 2]` for a `[u32; 6]`. A full array of `N` elements is simply the case
+//! `k = N`. Serialization always writes the full array, as a sequence like [`seq`](crate::seq)
+//! so that binary formats such as bincode record how many elements follow.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug)]
+//! struct Schedule {
+//!     #[serde(with = "serde_arrays::tiled")]
+//!     shifts: [u8; 6],
+//! }
+//!
+//! let schedule: Schedule = serde_json::from_str(r#"{"shifts":[1,2]}"#)?;
+//! assert_eq!(schedule.shifts, [1, 2, 1, 2, 1, 2]);
+//!
+//! let err = serde_json::from_str::<Schedule>(r#"{"shifts":[1,2,3,4]}"#).unwrap_err();
+//! assert!(err.to_string().starts_with(
+//!     "invalid length 4, expected a non-empty sequence whose length divides 6"
+//! ));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! An empty sequence is only accepted for an empty array.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize the full array as a sequence
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::seq::serialize(data, ser)
+}
+
+/// Deserialize an array from a pattern of elements repeated to fill it
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Clone,
+{
+    deserializer.deserialize_seq(TiledVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays tiled from a shorter pattern
+struct TiledVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<T, const N: usize> TiledVisitor<T, N> {
+    /// Whether a pattern of `len` elements can be tiled to fill the array
+    // `usize::is_multiple_of` is newer than this crate's MSRV
+    #[allow(clippy::manual_is_multiple_of)]
+    fn fits(len: usize) -> bool {
+        match len {
+            0 => N == 0,
+            len => N % len == 0,
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone, const N: usize> Visitor<'de> for TiledVisitor<T, N> {
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a non-empty sequence whose length divides {}", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        match seq.size_hint() {
+            Some(len) if !Self::fits(len) => return Err(de::Error::invalid_length(len, &self)),
+            _ => {}
+        }
+
+        // The pattern can be no longer than the array itself, so read it into an array of slots
+        // rather than needing an allocation; once the sequence ends, the remaining slots are empty
+        let mut len = 0;
+        let mut ended = false;
+        let pattern: [Option<T>; N] = crate::try_build_array(|_| {
+            if !ended {
+                match seq.next_element()? {
+                    Some(val) => {
+                        len += 1;
+                        return Ok(Some(val));
+                    }
+                    None => ended = true,
+                }
+            }
+            Ok(None)
+        })?;
+
+        if !ended && seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(crate::too_long(seq, N, &self));
+        }
+        if !Self::fits(len) {
+            return Err(de::Error::invalid_length(len, &self));
+        }
+
+        crate::try_build_array(|idx| match &pattern[idx % len] {
+            Some(val) => Ok(val.clone()),
+            None => unreachable!("the first `len` slots are all filled"),
+        })
+    }
+}
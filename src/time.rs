@@ -0,0 +1,108 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize arrays of `Duration`s compactly as nanosecond counts
+//!
+//! Serde writes each `Duration` as a struct of seconds and nanoseconds, e.g.
+//! `{"secs":1,"nanos":500000000}` in JSON, which is a lot of text for a buffer of timings. For
+//! human-readable formats, this module instead writes each element as a single `u64` count of
+//! nanoseconds; binary formats, where the struct is already compact, are unaffected:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use std::time::Duration;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Timings {
+//!     #[serde(with = "serde_arrays::time")]
+//!     laps: [Duration; 3],
+//! }
+//!
+//! let timings = Timings {
+//!     laps: [Duration::from_millis(1500), Duration::from_nanos(250), Duration::from_secs(2)],
+//! };
+//! let json = serde_json::to_string(&timings)?;
+//! assert_eq!(json, r#"{"laps":[1500000000,250,2000000000]}"#);
+//! # assert_eq!(timings, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! A `u64` holds a little over 584 years' worth of nanoseconds; serializing any longer `Duration`
+//! is an error.
+
+use core::{fmt, time::Duration};
+use serde::{
+    de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{self, SerializeTuple, Serializer},
+};
+
+/// Serialize an array of `Duration`s, as nanoseconds if the format is human-readable
+pub fn serialize<S, const N: usize>(data: &[Duration; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !ser.is_human_readable() {
+        return crate::serialize(data, ser);
+    }
+
+    let mut s = ser.serialize_tuple(N)?;
+    for (idx, item) in data.iter().enumerate() {
+        let nanos = item.as_nanos();
+        if nanos > u128::from(u64::MAX) {
+            return Err(ser::Error::custom(format_args!(
+                "at index {}: duration of {:?} is too long to write as u64 nanoseconds",
+                idx, item
+            )));
+        }
+        s.serialize_element(&(nanos as u64))?;
+    }
+    s.end()
+}
+
+/// Deserialize an array of `Duration`s, from nanoseconds if the format is human-readable
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[Duration; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if !deserializer.is_human_readable() {
+        return crate::deserialize(deserializer);
+    }
+
+    deserializer.deserialize_tuple(N, NanosArrayVisitor::<N>)
+}
+
+/// A Serde Deserializer `Visitor` for [Duration; N] arrays of nanosecond counts
+struct NanosArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for NanosArrayVisitor<N> {
+    type Value = [Duration; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<Duration, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| NanosSeed)
+    }
+}
+
+/// Seed to deserialize a single `Duration` from a count of nanoseconds
+struct NanosSeed;
+
+impl<'de> DeserializeSeed<'de> for NanosSeed {
+    type Value = Duration;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize arrays of newtype elements as arrays of the types they wrap
+//!
+//! A derived newtype such as `struct Fixed16(i16)` is serialized as a newtype struct, which some
+//! formats mark or wrap for every element. With this module, an array of [`TransparentElement`]s
+//! is written exactly as the array of inner values would be:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use serde_arrays::transparent::TransparentElement;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! struct Fixed16(i16);
+//!
+//! impl TransparentElement for Fixed16 {
+//!     type Inner = i16;
+//!
+//!     fn as_inner(&self) -> &i16 {
+//!         &self.0
+//!     }
+//!
+//!     fn from_inner(inner: i16) -> Self {
+//!         Fixed16(inner)
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Buffer {
+//!     #[serde(with = "serde_arrays::transparent")]
+//!     samples: [Fixed16; 4],
+//! }
+//!
+//! let buffer = Buffer { samples: [Fixed16(-2), Fixed16(0), Fixed16(7), Fixed16(1)] };
+//! let json = serde_json::to_string(&buffer)?;
+//! assert_eq!(json, r#"{"samples":[-2,0,7,1]}"#);
+//! # assert_eq!(buffer, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The elements need not implement `Serialize` or `Deserialize` themselves.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{Deserialize, DeserializeSeed, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeTuple, Serializer},
+};
+
+/// A newtype element that is serialized as the single value it wraps
+pub trait TransparentElement: Sized {
+    /// Type of the wrapped value
+    type Inner;
+
+    /// Borrow the wrapped value
+    fn as_inner(&self) -> &Self::Inner;
+
+    /// Wrap a value
+    fn from_inner(inner: Self::Inner) -> Self;
+}
+
+/// Serialize an array of newtypes as an array of their inner values
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: TransparentElement,
+    T::Inner: Serialize,
+{
+    let mut s = ser.serialize_tuple(N)?;
+    for item in data {
+        s.serialize_element(item.as_inner())?;
+    }
+    s.end()
+}
+
+/// Deserialize an array of newtypes from an array of their inner values
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: TransparentElement,
+    T::Inner: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N,
+        TransparentVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// Seed to deserialize a single element from its inner value
+struct InnerSeed<T> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T> DeserializeSeed<'de> for InnerSeed<T>
+where
+    T: TransparentElement,
+    T::Inner: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::Inner::deserialize(deserializer).map(T::from_inner)
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays of newtypes written as their inner values
+struct TransparentVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for TransparentVisitor<T, N>
+where
+    T: TransparentElement,
+    T::Inner: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| InnerSeed::<T> {
+            _marker: PhantomData,
+        })
+    }
+}
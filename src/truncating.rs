@@ -0,0 +1,88 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Deserialize arrays leniently, ignoring any elements past the first `N`
+//!
+//! Input must hold at least `N` elements; any more are skipped over without being deserialized.
+//! Serialization always writes the array as-is, as a sequence like [`seq`](crate::seq) rather than
+//! a tuple, so that formats that aren't self-describing, such as bincode, record how many elements
+//! follow.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "serde_arrays::truncating")]
+//!     values: [u32; 3],
+//! }
+//!
+//! let reading: Reading = serde_json::from_str(r#"{"values":[3,1,4,1,5]}"#)?;
+//! assert_eq!(reading.values, [3, 1, 4]);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! Skipping the excess relies on `deserialize_ignored_any`, so truncation only works in
+//! self-describing formats. Formats such as bincode can't skip a value without knowing its type;
+//! there, input longer than `N` fails with the format's error rather than being misread as
+//! whatever follows the array.
+
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor},
+    ser::{Serialize, Serializer},
+};
+
+/// Serialize the full array as a sequence
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    crate::seq::serialize(data, ser)
+}
+
+/// Deserialize the first `N` elements of an array of at least `N` elements
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_seq(TruncatingVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays that skips any excess elements
+struct TruncatingVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for TruncatingVisitor<T, N>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of at least {} elements", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let arr = crate::try_build_array(|idx| match seq.next_element()? {
+            Some(val) => Ok(val),
+            None => Err(de::Error::invalid_length(idx, &self)),
+        })?;
+
+        // The rest of the sequence must still be consumed; should that fail, `arr` is dropped
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+        Ok(arr)
+    }
+}
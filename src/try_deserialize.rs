@@ -0,0 +1,92 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArrayLengthError;
+use core::{fmt, marker::PhantomData};
+use serde::de::{Deserialize, Deserializer, IgnoredAny, SeqAccess, Visitor};
+
+/// Deserialize an array, reporting a wrong number of elements as an [`ArrayLengthError`]
+///
+/// Serde's errors can only describe a length mismatch as a message. This instead returns
+/// `Ok(Err(ArrayLengthError))` when the input is otherwise valid but holds the wrong number of
+/// elements, so callers can tell a short array from a long one without matching on strings; any
+/// other error, including an element that fails to deserialize, is returned as `Err` as usual:
+///
+/// ```
+/// let mut de = serde_json::Deserializer::from_str("[1,2,3]");
+/// let res = serde_arrays::try_deserialize::<_, u32, 4>(&mut de)?;
+///
+/// let err = res.unwrap_err();
+/// assert!(err.is_too_short());
+/// assert_eq!((4, 3), (err.expected, err.found));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// Every element of an over-long sequence is read (and discarded) to count them. This is not
+/// usable with `#[serde(with)]`, but rather intended for manual `Deserialize` implementations or
+/// direct use with a `Deserializer`.
+pub fn try_deserialize<'de, D, T, const N: usize>(
+    deserializer: D,
+) -> Result<Result<[T; N], ArrayLengthError>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    deserializer.deserialize_tuple(
+        N,
+        TryVisitor::<T, N> {
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// Why an array could not be filled
+enum Unfilled<E> {
+    /// The sequence ended after this many elements
+    Short(usize),
+    /// An element failed to deserialize
+    Failed(E),
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays that reports length mismatches separately
+struct TryVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for TryVisitor<T, N> {
+    type Value = Result<[T; N], ArrayLengthError>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let arr = crate::try_build_array(|idx| match seq.next_element() {
+            Ok(Some(val)) => Ok(val),
+            Ok(None) => Err(Unfilled::Short(idx)),
+            Err(err) => Err(Unfilled::Failed(err)),
+        });
+        let arr = match arr {
+            Ok(arr) => arr,
+            Err(Unfilled::Short(found)) => return Ok(Err(ArrayLengthError { expected: N, found })),
+            Err(Unfilled::Failed(err)) => return Err(err),
+        };
+
+        let mut found = N;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            found += 1;
+        }
+        if found > N {
+            return Ok(Err(ArrayLengthError { expected: N, found }));
+        }
+
+        Ok(Ok(arr))
+    }
+}
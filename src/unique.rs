@@ -0,0 +1,46 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::{array::IntoIter, hash::Hash};
+use serde::de::{Deserialize, Deserializer};
+use std::{collections::HashSet, vec::Vec};
+
+/// Deserialize an array of exactly `N` elements, returning only its unique values
+///
+/// The values are returned in the order they were first seen. This is useful for e.g. building a
+/// palette from a fixed-size input; the input must still contain exactly `N` elements, just as with
+/// [`deserialize`](crate::deserialize).
+///
+/// This is not usable with `#[serde(with)]`, but rather intended for manual `Deserialize`
+/// implementations or direct use with a `Deserializer`:
+///
+/// ```
+/// let mut de = serde_json::Deserializer::from_str("[3, 1, 3, 2, 1, 3]");
+/// let palette = serde_arrays::deserialize_unique::<_, u8, 6>(&mut de)?;
+///
+/// assert_eq!(palette, vec![3, 1, 2]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn deserialize_unique<'de, D, T, const N: usize>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Eq + Hash,
+{
+    let arr: [T; N] = crate::deserialize(deserializer)?;
+
+    // Find the first occurrence of each value by reference, so the values can then be moved out
+    let mut seen = HashSet::with_capacity(N);
+    let first: Vec<bool> = arr.iter().map(|item| seen.insert(item)).collect();
+
+    #[allow(deprecated)] // Arrays only implement `IntoIterator` by value since Rust 1.53
+    let unique = IntoIter::new(arr)
+        .zip(first)
+        .filter_map(|(item, first)| if first { Some(item) } else { None })
+        .collect();
+
+    Ok(unique)
+}
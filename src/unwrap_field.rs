@@ -0,0 +1,173 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays whose elements are each wrapped in a single-field object
+//!
+//! Some producers emit arrays like `[{"v":1},{"v":2},{"v":3}]`. The name of the wrapping field is
+//! supplied by implementing [`FieldName`] on a marker type, which is then passed to
+//! [`UnwrapField`]:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//! use serde_arrays::unwrap_field::FieldName;
+//!
+//! struct V;
+//! impl FieldName for V {
+//!     const NAME: &'static str = "v";
+//! }
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Samples {
+//!     #[serde(with = "serde_arrays::unwrap_field::UnwrapField::<V>")]
+//!     values: [i32; 3],
+//! }
+//!
+//! let samples: Samples = serde_json::from_str(r#"{"values":[{"v":1},{"v":2},{"v":3}]}"#)?;
+//! assert_eq!(samples.values, [1, 2, 3]);
+//! # assert_eq!(serde_json::to_string(&samples)?, r#"{"values":[{"v":1},{"v":2},{"v":3}]}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! As with Serde's derived implementations, any other fields in the wrapping objects are ignored.
+
+use crate::field::FieldSeed;
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{
+        self, Deserialize, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor,
+    },
+    ser::{Serialize, SerializeMap, SerializeTuple, Serializer},
+};
+
+/// The name of the field wrapping each element, for use with [`UnwrapField`]
+pub trait FieldName {
+    /// Name of the field
+    const NAME: &'static str;
+}
+
+/// Serialize and deserialize `[T; N]` with each element wrapped in an object with the single field
+/// `F::NAME`
+///
+/// Use as `#[serde(with = "serde_arrays::unwrap_field::UnwrapField::<MyFieldName>")]`.
+pub struct UnwrapField<F> {
+    _marker: PhantomData<F>,
+}
+
+impl<F: FieldName> UnwrapField<F> {
+    /// Serialize an array, wrapping each element in a single-field object
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut s = ser.serialize_tuple(N)?;
+        for item in data {
+            s.serialize_element(&Wrapped::<F, T> {
+                value: item,
+                _marker: PhantomData,
+            })?;
+        }
+        s.end()
+    }
+
+    /// Deserialize an array, unwrapping each element from a single-field object
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(
+            N,
+            UnwrapVisitor::<F, T, N> {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// Wrapper to serialize an element inside a single-field object
+struct Wrapped<'a, F, T> {
+    value: &'a T,
+    _marker: PhantomData<F>,
+}
+
+impl<'a, F: FieldName, T: Serialize> Serialize for Wrapped<'a, F, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(F::NAME, self.value)?;
+        map.end()
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays of wrapped elements
+struct UnwrapVisitor<F, T, const N: usize> {
+    _marker: PhantomData<(F, T)>,
+}
+
+impl<'de, F: FieldName, T: Deserialize<'de>, const N: usize> Visitor<'de>
+    for UnwrapVisitor<F, T, N>
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        crate::fill_array(seq, &self, |_| ElementVisitor::<F, T> {
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Seed and `Visitor` to deserialize a single wrapped element
+struct ElementVisitor<F, T> {
+    _marker: PhantomData<(F, T)>,
+}
+
+impl<'de, F: FieldName, T: Deserialize<'de>> DeserializeSeed<'de> for ElementVisitor<F, T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, F: FieldName, T: Deserialize<'de>> Visitor<'de> for ElementVisitor<F, T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an object with the field `{}`", F::NAME)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut value = None;
+        let names = [F::NAME];
+        while let Some(key) = map.next_key_seed(FieldSeed(&names))? {
+            if key.is_none() {
+                map.next_value::<IgnoredAny>()?;
+            } else if value.is_some() {
+                return Err(de::Error::duplicate_field(F::NAME));
+            } else {
+                value = Some(map.next_value()?);
+            }
+        }
+        value.ok_or_else(|| de::Error::missing_field(F::NAME))
+    }
+}
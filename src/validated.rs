@@ -0,0 +1,95 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArrayVisitor;
+use core::{fmt, marker::PhantomData};
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+
+/// Deserialize an array of exactly `N` elements, validating each element as soon as it is read
+///
+/// `validate` is called with the index and value of each element in turn; should it return an
+/// error, deserialization stops immediately with that error as a custom Serde error, and every
+/// element built so far is dropped. This fails faster than deserializing the whole array and then
+/// checking it:
+///
+/// ```
+/// let mut de = serde_json::Deserializer::from_str("[10, 20, 9000, 40]");
+/// let res = serde_arrays::deserialize_validated::<_, u16, _, _, 4>(&mut de, |_, &reading| {
+///     if reading > 1000 {
+///         Err(format!("{} is out of range", reading))
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// assert!(res.unwrap_err().to_string().starts_with("invalid element at index 2: 9000 is out of range"));
+/// ```
+///
+/// This is not usable with `#[serde(with)]`, but rather intended for manual `Deserialize`
+/// implementations or direct use with a `Deserializer`.
+pub fn deserialize_validated<'de, D, T, F, E, const N: usize>(
+    deserializer: D,
+    validate: F,
+) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    F: FnMut(usize, &T) -> Result<(), E>,
+    E: fmt::Display,
+{
+    deserializer.deserialize_tuple(
+        N,
+        ValidatedVisitor::<T, F, N> {
+            validate,
+            _marker: PhantomData,
+        },
+    )
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays validating each element
+struct ValidatedVisitor<T, F, const N: usize> {
+    validate: F,
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, F, E, const N: usize> Visitor<'de> for ValidatedVisitor<T, F, N>
+where
+    T: Deserialize<'de>,
+    F: FnMut(usize, &T) -> Result<(), E>,
+    E: fmt::Display,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        crate::expecting_array::<T, N>(formatter)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut validate = self.validate;
+        let expected = ArrayVisitor::<T, N>::new();
+
+        let arr = crate::try_build_array(|idx| match seq.next_element()? {
+            Some(val) => match validate(idx, &val) {
+                Ok(()) => Ok(val),
+                Err(err) => Err(de::Error::custom(format_args!(
+                    "invalid element at index {}: {}",
+                    idx, err
+                ))),
+            },
+            None => Err(de::Error::invalid_length(idx, &expected)),
+        })?;
+
+        // Make sure there aren't any elements left over
+        match seq.next_element::<de::IgnoredAny>()? {
+            Some(_) => Err(crate::too_long(seq, N, &expected)),
+            None => Ok(arr),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use alloc::vec::Vec;
+
+/// Convert a `Vec<T>` of exactly `N` elements into a `[T; N]`
+///
+/// If the vector has any other length it is handed back unchanged, just as the standard library's
+/// `TryFrom<Vec<T>>` does; this is the same length check deserializing an array makes, without
+/// involving Serde at all:
+///
+/// ```
+/// let arr: [String; 2] = serde_arrays::try_from_vec(vec!["a".to_string(), "b".to_string()]).unwrap();
+/// assert_eq!(arr, ["a", "b"]);
+///
+/// let short = serde_arrays::try_from_vec::<u32, 4>(vec![1, 2, 3]).unwrap_err();
+/// assert_eq!(short, [1, 2, 3]);
+/// ```
+pub fn try_from_vec<T, const N: usize>(vec: Vec<T>) -> Result<[T; N], Vec<T>> {
+    if vec.len() != N {
+        return Err(vec);
+    }
+
+    match crate::collect_array(vec) {
+        Ok(arr) => Ok(arr),
+        Err(_) => unreachable!("the vector holds exactly N elements"),
+    }
+}
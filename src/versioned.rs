@@ -0,0 +1,147 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize arrays together with a schema version
+//!
+//! [`Versioned`] writes `[T; N]` as a struct with the fields `v`, holding the version `V`, and
+//! `data`, holding the array, e.g. `{"v":1,"data":[1,2,3]}` in JSON. Deserializing checks the
+//! version before accepting the data, so a future change in representation can be detected instead
+//! of being silently misread:
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Snapshot {
+//!     #[serde(with = "serde_arrays::versioned::Versioned::<1>")]
+//!     values: [u32; 3],
+//! }
+//!
+//! let snapshot = Snapshot { values: [1, 2, 3] };
+//! let json = serde_json::to_string(&snapshot)?;
+//! assert_eq!(json, r#"{"values":{"v":1,"data":[1,2,3]}}"#);
+//! # assert_eq!(snapshot, serde_json::from_str(&json)?);
+//!
+//! let err = serde_json::from_str::<Snapshot>(r#"{"values":{"v":2,"data":[1,2,3]}}"#).unwrap_err();
+//! assert!(err.to_string().starts_with("unsupported version 2 (expected 1)"));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! The array itself is read exactly as by [`serde_arrays`](crate).
+
+use crate::{field::FieldSeed, wrapper::ArrayWrap, ArraySeed};
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+const FIELDS: &[&str] = &["v", "data"];
+
+/// Serialize `[T; N]` tagged with the schema version `V`
+///
+/// Use as `#[serde(with = "serde_arrays::versioned::Versioned::<V>")]`.
+pub struct Versioned<const V: u32>;
+
+impl<const V: u32> Versioned<V> {
+    /// Serialize an array as a `v`/`data` struct
+    pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut s = ser.serialize_struct("Versioned", 2)?;
+        s.serialize_field("v", &V)?;
+        s.serialize_field("data", &ArrayWrap::new(data))?;
+        s.end()
+    }
+
+    /// Deserialize an array from a `v`/`data` struct, rejecting any version other than `V`
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_struct(
+            "Versioned",
+            FIELDS,
+            VersionedVisitor::<T, N, V> {
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays tagged with version `V`
+struct VersionedVisitor<T, const N: usize, const V: u32> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize, const V: u32> Visitor<'de> for VersionedVisitor<T, N, V>
+where
+    T: Deserialize<'de>,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "an array of size {} with schema version {}",
+            N, V
+        )
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        check_version::<_, V>(version)?;
+        seq.next_element_seed(ArraySeed::<T, N>::new())?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut version = None;
+        let mut data = None;
+        while let Some(key) = map.next_key_seed(FieldSeed(FIELDS))? {
+            match key.map(|idx| FIELDS[idx]) {
+                Some("v") if version.is_some() => return Err(de::Error::duplicate_field("v")),
+                Some("v") => {
+                    // Check as soon as the version is seen, so mismatched data isn't even parsed
+                    let v = map.next_value()?;
+                    check_version::<_, V>(v)?;
+                    version = Some(v);
+                }
+                Some("data") if data.is_some() => return Err(de::Error::duplicate_field("data")),
+                Some("data") => data = Some(map.next_value_seed(ArraySeed::<T, N>::new())?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        version.ok_or_else(|| de::Error::missing_field("v"))?;
+        data.ok_or_else(|| de::Error::missing_field("data"))
+    }
+}
+
+/// Check the decoded version against the supported version `V`
+fn check_version<E: de::Error, const V: u32>(version: u32) -> Result<(), E> {
+    if version == V {
+        Ok(())
+    } else {
+        Err(de::Error::custom(format_args!(
+            "unsupported version {} (expected {})",
+            version, V
+        )))
+    }
+}
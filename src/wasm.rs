@@ -0,0 +1,60 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays as little-endian bytes matching WebAssembly linear memory
+//!
+//! WebAssembly memory is always little-endian, so to pass an array between a host and a Wasm
+//! module the elements must be laid out little-endian regardless of the host's own byte order.
+//! This module writes `[T; N]` as a single byte string of `N * T::SIZE` bytes in exactly that
+//! layout, and reads it back by copying out of such bytes.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Buffer {
+//!     #[serde(with = "serde_arrays::wasm")]
+//!     words: [u32; 2],
+//! }
+//!
+//! let buffer = Buffer { words: [1, 0x0A0B0C0D] };
+//! let json = serde_json::to_string(&buffer)?;
+//! assert_eq!(json, r#"{"words":[1,0,0,0,13,12,11,10]}"#);
+//! # assert_eq!(buffer, serde_json::from_str(&json)?);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{byte_buf::ByteBufVisitor, raw::Pod};
+use alloc::vec;
+use serde::{de::Deserializer, ser::Serializer};
+
+/// Serialize an array as little-endian bytes
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Pod,
+{
+    let mut bytes = vec![0; N * T::SIZE];
+    for (item, out) in data.iter().zip(bytes.chunks_exact_mut(T::SIZE)) {
+        item.write_le(out);
+    }
+    ser.serialize_bytes(&bytes)
+}
+
+/// Deserialize an array from exactly `N * T::SIZE` little-endian bytes
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Pod,
+{
+    let len = N * T::SIZE;
+    let bytes = deserializer.deserialize_bytes(ByteBufVisitor {
+        len,
+        expected: format_args!("{} little-endian bytes holding an array of size {}", len, N),
+    })?;
+    crate::try_build_array(|idx| Ok(T::read_le(&bytes[idx * T::SIZE..(idx + 1) * T::SIZE])))
+}
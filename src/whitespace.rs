@@ -0,0 +1,85 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Serialize and deserialize arrays as a single whitespace-separated string
+//!
+//! This is a common format for vectors and matrices in scientific data files, e.g.
+//! `"1.0 2.0 3.0 4.0"`. Elements are serialized using their `Display` implementation joined by a
+//! single space, and deserialized by splitting on any run of whitespace and parsing each token
+//! with `FromStr`.
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Vertex {
+//!     #[serde(with = "serde_arrays::whitespace")]
+//!     pos: [f32; 3],
+//! }
+//!
+//! let v: Vertex = serde_json::from_str(r#"{"pos":" 1.0\t2.5   -3 "}"#)?;
+//! assert_eq!(v.pos, [1.0, 2.5, -3.0]);
+//! assert_eq!(serde_json::to_string(&v)?, r#"{"pos":"1 2.5 -3"}"#);
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::delimited::{FromStrSeed, Joined, Tokens};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    de::{self, Deserializer, Visitor},
+    ser::Serializer,
+};
+
+/// Serialize an array as a space-separated string
+pub fn serialize<S, T, const N: usize>(data: &[T; N], ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: fmt::Display,
+{
+    ser.collect_str(&Joined {
+        items: data,
+        sep: " ",
+    })
+}
+
+/// Deserialize an array from a string of exactly `N` whitespace-separated tokens
+pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    deserializer.deserialize_str(WhitespaceVisitor::<T, N> {
+        _marker: PhantomData,
+    })
+}
+
+/// A Serde Deserializer `Visitor` for [T; N] arrays encoded as whitespace-separated strings
+struct WhitespaceVisitor<T, const N: usize> {
+    _marker: PhantomData<T>,
+}
+
+impl<'de, T, const N: usize> Visitor<'de> for WhitespaceVisitor<T, N>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a string of {} whitespace-separated values", N)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        crate::fill_array(Tokens::new(v.split_whitespace()), &self, |_| {
+            FromStrSeed::new()
+        })
+    }
+}
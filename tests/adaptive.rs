@@ -0,0 +1,59 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::ramp;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Adaptive {
+    #[serde(with = "serde_arrays::adaptive")]
+    arr: [u32; 36],
+}
+
+fn sample() -> Adaptive {
+    Adaptive { arr: ramp(3) }
+}
+
+#[test]
+fn adaptive_json_is_bare() {
+    let j = serde_json::to_string(&sample()).unwrap();
+
+    assert!(j.starts_with(r#"{"arr":[0,3,6,"#));
+    assert_eq!(sample(), serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn adaptive_bincode_is_length_prefixed() {
+    let bytes = bincode::serialize(&sample()).unwrap();
+
+    assert_eq!(8 + 36 * 4, bytes.len());
+    assert_eq!(36u64.to_le_bytes(), bytes[..8]);
+    assert_eq!(3u32.to_le_bytes(), bytes[12..16]);
+    assert_eq!(sample(), bincode::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn adaptive_formats_agree() {
+    let from_json: Adaptive =
+        serde_json::from_str(&serde_json::to_string(&sample()).unwrap()).unwrap();
+    let from_bincode: Adaptive =
+        bincode::deserialize(&bincode::serialize(&sample()).unwrap()).unwrap();
+
+    assert_eq!(from_json, from_bincode);
+}
+
+#[test]
+fn adaptive_bincode_wrong_length() {
+    let mut bytes = bincode::serialize(&sample()).unwrap();
+    bytes[..8].copy_from_slice(&35u64.to_le_bytes());
+
+    let err = bincode::deserialize::<Adaptive>(&bytes).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid length 35, expected a length-prefixed array of size 36"));
+}
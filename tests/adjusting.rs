@@ -0,0 +1,80 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Deserializer};
+use serde_arrays::adjusting::Adjustment;
+use std::cell::RefCell;
+
+thread_local! {
+    static SEEN: RefCell<Vec<Adjustment>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record(adjustment: Adjustment) {
+    SEEN.with(|seen| seen.borrow_mut().push(adjustment));
+}
+
+fn take_seen() -> Vec<Adjustment> {
+    SEEN.with(|seen| seen.borrow_mut().drain(..).collect())
+}
+
+fn lenient<'de, D: Deserializer<'de>, const N: usize>(de: D) -> Result<[u32; N], D::Error> {
+    serde_arrays::adjusting::deserialize(de, record)
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Readings {
+    #[serde(deserialize_with = "lenient")]
+    values: [u32; 40],
+}
+
+fn values_json(count: u32) -> String {
+    format!(r#"{{"values":{:?}}}"#, (1..=count).collect::<Vec<_>>())
+}
+
+#[test]
+fn adjusting_pads_short_input() {
+    let readings: Readings = serde_json::from_str(&values_json(35)).unwrap();
+
+    let mut expected = [0; 40];
+    for (i, v) in expected.iter_mut().take(35).enumerate() {
+        *v = i as u32 + 1;
+    }
+    assert_eq!(expected, readings.values);
+    assert_eq!(vec![Adjustment::Padded(5)], take_seen());
+}
+
+#[test]
+fn adjusting_truncates_long_input() {
+    let readings: Readings = serde_json::from_str(&values_json(43)).unwrap();
+
+    assert_eq!(40, readings.values[39]);
+    assert_eq!(vec![Adjustment::Truncated(3)], take_seen());
+}
+
+#[test]
+fn adjusting_exact_input() {
+    let readings: Readings = serde_json::from_str(&values_json(40)).unwrap();
+
+    assert_eq!(40, readings.values[39]);
+    assert_eq!(vec![Adjustment::Exact], take_seen());
+}
+
+#[test]
+fn adjusting_empty_input() {
+    let readings: Readings = serde_json::from_str(r#"{"values":[]}"#).unwrap();
+
+    assert_eq!([0; 40], readings.values);
+    assert_eq!(vec![Adjustment::Padded(40)], take_seen());
+}
+
+#[test]
+fn adjusting_reports_nothing_on_error() {
+    let res: Result<Readings, _> = serde_json::from_str(r#"{"values":[1,"two",3]}"#);
+
+    assert!(res.is_err());
+    assert!(take_seen().is_empty());
+}
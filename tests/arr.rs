@@ -0,0 +1,136 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use serde_arrays::Arr;
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Packets {
+    packets: Vec<Arr<u8, 64>>,
+    maybe: Option<Arr<u32, 40>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+enum Message {
+    Empty,
+    Data(Arr<u16, 36>),
+    Named { values: Arr<u16, 36> },
+}
+
+#[test]
+fn arr_in_vec_and_option() {
+    for maybe in [None, Some(Arr([7; 40]))] {
+        let obj = Packets {
+            packets: vec![Arr([1; 64]), Arr([2; 64])],
+            maybe,
+        };
+
+        let j = serde_json::to_string(&obj).unwrap();
+        assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+        let b = bincode::serialize(&obj).unwrap();
+        assert_eq!(obj, bincode::deserialize(&b).unwrap());
+    }
+}
+
+#[test]
+fn arr_matches_with_attribute() {
+    #[derive(Serialize)]
+    struct WithAttr {
+        #[serde(with = "serde_arrays")]
+        arr: [u32; 40],
+    }
+
+    #[derive(Serialize)]
+    struct WithArr {
+        arr: Arr<u32, 40>,
+    }
+
+    assert_eq!(
+        serde_json::to_string(&WithAttr { arr: [5; 40] }).unwrap(),
+        serde_json::to_string(&WithArr { arr: Arr([5; 40]) }).unwrap()
+    );
+    assert_eq!(
+        bincode::serialize(&WithAttr { arr: [5; 40] }).unwrap(),
+        bincode::serialize(&WithArr { arr: Arr([5; 40]) }).unwrap()
+    );
+}
+
+#[test]
+fn arr_in_enum_and_map() {
+    let messages = vec![
+        Message::Empty,
+        Message::Data(Arr([1; 36])),
+        Message::Named {
+            values: Arr([2; 36]),
+        },
+    ];
+    let j = serde_json::to_string(&messages).unwrap();
+    assert_eq!(messages, serde_json::from_str::<Vec<Message>>(&j).unwrap());
+
+    let mut map = BTreeMap::new();
+    map.insert("a".to_string(), Arr([3u8; 33]));
+    let j = serde_json::to_string(&map).unwrap();
+    assert_eq!(map, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn arr_conversions() {
+    let mut arr: Arr<u32, 40> = [1; 40].into();
+    arr[3] = 4;
+    assert_eq!(4, arr.iter().sum::<u32>() - 39);
+    assert_eq!(40, arr.len());
+
+    let inner: [u32; 40] = arr.into();
+    assert_eq!(4, inner[3]);
+    assert_eq!(inner, Arr(inner).into_inner());
+
+    assert_eq!(Arr([0u8; 64]), Arr::default());
+}
+
+#[test]
+#[should_panic(expected = "invalid length 39, expected an array of size 40")]
+fn arr_wrong_length() {
+    let json = format!("{:?}", [1; 39]);
+    let _: Arr<u32, 40> = serde_json::from_str(&json).unwrap();
+}
+
+// The length is available at compile time, without a value
+const PACKET_LEN: usize = Arr::<u8, 16>::LEN;
+const BUFFER: [u8; 2 * PACKET_LEN] = [0; 2 * PACKET_LEN];
+
+#[test]
+fn arr_len() {
+    assert_eq!(16, PACKET_LEN);
+    assert_eq!(32, BUFFER.len());
+
+    const PACKET: Arr<u8, 16> = Arr([0; 16]);
+    const RUNTIME_LEN: usize = PACKET.len();
+    assert_eq!(16, RUNTIME_LEN);
+    assert_eq!(40, Arr([1u32; 40]).len());
+    assert!(!PACKET.is_empty());
+    assert!(Arr::<u32, 0>([]).is_empty());
+}
+
+#[test]
+fn arr_in_option_vec() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Columns {
+        data: Option<Vec<Arr<f32, 8>>>,
+    }
+
+    for data in [None, Some(Vec::new()), Some(vec![Arr([0.5; 8]); 3])] {
+        let obj = Columns { data };
+
+        let j = serde_json::to_string(&obj).unwrap();
+        assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+        let b = bincode::serialize(&obj).unwrap();
+        assert_eq!(obj, bincode::deserialize(&b).unwrap());
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+serde_arrays::array! {
+    /// Fields of every kind the macro accepts
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    pub struct Frame {
+        pub id: u32,
+        #[serde(rename = "s")]
+        pub samples: [u16; SAMPLES = 36],
+        weights: [f32; WEIGHTS = 36],
+        pub unnamed: [u8; 40],
+        pub tags: Vec<String>,
+        #[serde(with = "serde_arrays::bytes")]
+        pub key: Key,
+    }
+}
+
+/// An array with its own `with` attribute, passed through by way of an alias
+type Key = [u8; 4];
+
+#[test]
+fn array_macro_exposes_lengths() {
+    const _: [(); Frame::SAMPLES] = [(); Frame::WEIGHTS];
+
+    assert_eq!(36, Frame::SAMPLES);
+    assert_eq!(36, Frame::WEIGHTS);
+}
+
+#[test]
+fn array_macro_round_trip() {
+    let obj = Frame {
+        id: 7,
+        samples: [1; 36],
+        weights: [0.5; 36],
+        unnamed: [2; 40],
+        tags: vec!["a".into()],
+        key: [0xde, 0xad, 0xbe, 0xef],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"id":7,"s":[1,1,"#));
+    assert!(j.ends_with(r#""key":[222,173,190,239]}"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
@@ -0,0 +1,64 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "arrayvec")]
+
+use arrayvec::ArrayVec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Buffer {
+    #[serde(with = "serde_arrays::arrayvec")]
+    arr: ArrayVec<u32, 40>,
+}
+
+#[test]
+fn arrayvec_full_round_trip() {
+    let obj = Buffer {
+        arr: (0..40).collect(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 40 * 4, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn arrayvec_partial_round_trip() {
+    let obj = Buffer {
+        arr: (0..3).collect(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[0,1,2]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 3 * 4, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn arrayvec_empty_round_trip() {
+    let obj = Buffer {
+        arr: ArrayVec::new(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "invalid length 41, expected a sequence of at most 40 elements")]
+fn arrayvec_over_capacity() {
+    let json = format!(r#"{{"arr":{:?}}}"#, vec![1; 41]);
+    let _: Buffer = serde_json::from_str(&json).unwrap();
+}
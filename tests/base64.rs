@@ -0,0 +1,89 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "base64")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Key {
+    #[serde(with = "serde_arrays::base64")]
+    key: [u8; 36],
+}
+
+fn key() -> Key {
+    let mut key = [0; 36];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = (i * 7) as u8 | 0xC0;
+    }
+    Key { key }
+}
+
+#[test]
+fn base64_json_round_trip() {
+    let obj = key();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"key":"wMfO1dzj6vH4"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn base64_binary_is_bytes() {
+    let obj = key();
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 36, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn base64_wrong_decoded_length() {
+    let err = serde_json::from_str::<Key>(r#"{"key":"3q2+7w=="}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid base64 string: expected 36 bytes, found 4"));
+}
+
+#[test]
+fn base64_invalid() {
+    let err = serde_json::from_str::<Key>(r#"{"key":"3q2+7w!="}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid base64 string: "));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Alphabets {
+    #[serde(with = "serde_arrays::base64::standard_no_pad")]
+    standard_no_pad: [u8; 4],
+    #[serde(with = "serde_arrays::base64::url_safe")]
+    url_safe: [u8; 4],
+    #[serde(with = "serde_arrays::base64::url_safe_no_pad")]
+    url_safe_no_pad: [u8; 4],
+}
+
+#[test]
+fn base64_alphabets() {
+    let bytes = [0xDE, 0xAD, 0xBE, 0xEF];
+    let obj = Alphabets {
+        standard_no_pad: bytes,
+        url_safe: bytes,
+        url_safe_no_pad: bytes,
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        r#"{"standard_no_pad":"3q2+7w","url_safe":"3q2-7w==","url_safe_no_pad":"3q2-7w"}"#,
+        j
+    );
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn base64_wrong_alphabet_is_error() {
+    let j = r#"{"standard_no_pad":"3q2+7w","url_safe":"3q2+7w==","url_safe_no_pad":"3q2-7w"}"#;
+    assert!(serde_json::from_str::<Alphabets>(j).is_err());
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "bitvec")]
+
+mod common;
+use bitvec::prelude::*;
+use common::recorder::{record, Event};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Bools {
+    #[serde(with = "serde_arrays::bit_array::bools")]
+    bits: [bool; 10],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Packed {
+    #[serde(with = "serde_arrays::bit_array")]
+    bits: BitArray<[u8; 40], Lsb0>,
+}
+
+const PATTERN: [bool; 10] = [
+    true, false, true, true, false, false, false, false, true, false,
+];
+
+#[test]
+fn bools_packed_layout() {
+    let events = record(&Bools { bits: PATTERN });
+
+    assert_eq!(Event::Bytes(vec![0b0000_1101, 0b0000_0001]), events[2]);
+}
+
+#[test]
+fn bools_round_trip() {
+    let obj = Bools { bits: PATTERN };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"bits":[13,1]}"#, j);
+
+    let de: Bools = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn bools_ignore_padding_bits() {
+    let de: Bools = serde_json::from_str(r#"{"bits":[13,255]}"#).unwrap();
+
+    assert_eq!(&PATTERN[..9], &de.bits[..9]);
+    assert!(de.bits[9]);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 3, expected 10 bits packed into 2 bytes")]
+fn bools_wrong_byte_count() {
+    let _: Bools = serde_json::from_str(r#"{"bits":[13,1,0]}"#).unwrap();
+}
+
+#[test]
+fn bools_excess_bytes_are_skipped_not_collected() {
+    let err = serde_json::from_str::<Bools>(r#"{"bits":[13,1,"not a byte",[0]]}"#).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 4, expected 10 bits packed into 2 bytes"));
+}
+
+#[test]
+fn bit_array_round_trip() {
+    let mut bits: BitArray<[u8; 40], Lsb0> = BitArray::ZERO;
+    for idx in [0, 2, 3, 8, 319].iter() {
+        bits.set(*idx, true);
+    }
+    let obj = Packed { bits };
+
+    let events = record(&obj);
+    let mut expected = vec![0u8; 40];
+    expected[0] = 0b0000_1101;
+    expected[1] = 0b0000_0001;
+    expected[39] = 0b1000_0000;
+    assert_eq!(Event::Bytes(expected), events[2]);
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Packed = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
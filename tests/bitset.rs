@@ -0,0 +1,85 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Flags(#[serde(with = "serde_arrays::bitset")] [bool; 10]);
+
+fn flags() -> Flags {
+    let mut flags = [false; 10];
+    flags[0] = true;
+    flags[3] = true;
+    flags[9] = true;
+    Flags(flags)
+}
+
+#[test]
+fn bitset_bincode_packs_into_two_bytes() {
+    let b = bincode::serialize(&flags()).unwrap();
+
+    // An 8-byte length prefix, then bits 0 and 3 in the first byte and bit 9 in the second
+    assert_eq!(8 + 2, b.len());
+    assert_eq!(&[0b0000_1001, 0b0000_0010], &b[8..]);
+    assert_eq!(flags(), bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn bitset_bincode_round_trip_whole_bytes() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Large(#[serde(with = "serde_arrays::bitset")] [bool; 64]);
+
+    let mut large = Large([false; 64]);
+    for idx in (0..64).step_by(3) {
+        large.0[idx] = true;
+    }
+
+    let b = bincode::serialize(&large).unwrap();
+    assert_eq!(8 + 8, b.len());
+    assert_eq!(large, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn bitset_ignores_padding_bits() {
+    let mut b = bincode::serialize(&flags()).unwrap();
+    b[9] |= 0b1111_1100;
+
+    assert_eq!(flags(), bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn bitset_wrong_byte_count() {
+    let b = bincode::serialize(&serde_bytes::ByteArray::new([0u8; 3])).unwrap();
+    let err = bincode::deserialize::<Flags>(&b).unwrap_err();
+    assert_eq!(
+        "invalid length 3, expected 10 bits packed into 2 bytes, or an array of 10 bools",
+        err.to_string()
+    );
+}
+
+#[test]
+fn bitset_human_readable_uses_bools() {
+    let json = serde_json::to_string(&flags()).unwrap();
+    assert_eq!(
+        "[true,false,false,true,false,false,false,false,false,true]",
+        json
+    );
+    assert_eq!(flags(), serde_json::from_str(&json).unwrap());
+
+    let err = serde_json::from_str::<Flags>("[true,false]").unwrap_err();
+    assert!(err.to_string().starts_with("invalid length 2"));
+}
+
+#[test]
+fn bitset_empty() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Empty(#[serde(with = "serde_arrays::bitset")] [bool; 0]);
+
+    let b = bincode::serialize(&Empty([])).unwrap();
+    assert_eq!(8, b.len());
+    assert_eq!(Empty([]), bincode::deserialize(&b).unwrap());
+}
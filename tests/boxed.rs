@@ -0,0 +1,161 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use bincode::Options;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Small {
+    #[serde(with = "serde_arrays::boxed")]
+    arr: Box<[u32; 4]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Large {
+    #[serde(with = "serde_arrays::boxed")]
+    arr: Box<[u64; 100_000]>,
+}
+
+/// Build a `Large` without ever putting the array itself on the stack
+fn large() -> Large {
+    let arr: Vec<u64> = (0..100_000).collect();
+    Large {
+        arr: arr.into_boxed_slice().try_into().unwrap(),
+    }
+}
+
+#[test]
+fn boxed_round_trip() {
+    let obj = Small {
+        arr: Box::new([1, 2, 3, 4]),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[1,2,3,4]}"#, j);
+
+    let de: Small = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn boxed_matches_unboxed() {
+    #[derive(Serialize)]
+    struct Unboxed {
+        #[serde(with = "serde_arrays")]
+        arr: [u32; 4],
+    }
+
+    let boxed = Small {
+        arr: Box::new([5, 6, 7, 8]),
+    };
+    let unboxed = Unboxed { arr: [5, 6, 7, 8] };
+
+    assert_eq!(
+        bincode::serialize(&unboxed).unwrap(),
+        bincode::serialize(&boxed).unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid length 3, expected an array of size 4")]
+fn boxed_too_short() {
+    let _: Small = serde_json::from_str(r#"{"arr":[1,2,3]}"#).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 5, expected an array of size 4")]
+fn boxed_too_long() {
+    let _: Small = serde_json::from_str(r#"{"arr":[1,2,3,4,5]}"#).unwrap();
+}
+
+#[test]
+fn boxed_large_array_stays_off_the_stack() {
+    // The array alone is about 800 KiB, so run on a thread with a much smaller stack to prove it
+    // is never built there
+    std::thread::Builder::new()
+        .stack_size(256 * 1024)
+        .spawn(|| {
+            let obj = large();
+
+            let b = bincode::serialize(&obj).unwrap();
+            assert_eq!(100_000 * 8, b.len());
+            let de: Large = bincode::deserialize(&b).unwrap();
+            assert_eq!(obj, de);
+
+            let j = serde_json::to_string(&obj).unwrap();
+            let de: Large = serde_json::from_str(&j).unwrap();
+            assert_eq!(obj, de);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+fn boxed_slice_json(json: &str, len: usize) -> Result<Box<[u32]>, serde_json::Error> {
+    let mut de = serde_json::Deserializer::from_str(json);
+    serde_arrays::deserialize_boxed_slice(&mut de, len)
+}
+
+#[test]
+fn boxed_slice_exact() {
+    let data = boxed_slice_json("[1,2,3,4,5]", 5).unwrap();
+    assert_eq!(&data[..], &[1, 2, 3, 4, 5]);
+
+    let data = boxed_slice_json("[]", 0).unwrap();
+    assert!(data.is_empty());
+}
+
+#[test]
+fn boxed_slice_too_short() {
+    let err = boxed_slice_json("[1,2,3]", 5).unwrap_err().to_string();
+    assert!(
+        err.starts_with("invalid length 3, expected an array of size 5"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn boxed_slice_too_long() {
+    let err = boxed_slice_json("[1,2,3,4,5,6]", 5)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.starts_with("invalid length 6, expected an array of size 5"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn boxed_slice_length_from_header() {
+    // The length is written first, as a header, followed by the elements themselves
+    let elements: Vec<u64> = (0..1000).collect();
+    let mut b = bincode::serialize(&(elements.len() as u32)).unwrap();
+    for elem in &elements {
+        b.extend(bincode::serialize(elem).unwrap());
+    }
+
+    let (header, body) = b.split_at(4);
+    let len: u32 = bincode::deserialize(header).unwrap();
+    let mut de = bincode::Deserializer::from_slice(
+        body,
+        bincode::DefaultOptions::new().with_fixint_encoding(),
+    );
+    let data: Box<[u64]> = serde_arrays::deserialize_boxed_slice(&mut de, len as usize).unwrap();
+    assert_eq!(&data[..], &elements[..]);
+
+    // A header claiming more elements than there are runs out of input
+    let mut de = bincode::Deserializer::from_slice(
+        body,
+        bincode::DefaultOptions::new().with_fixint_encoding(),
+    );
+    assert!(serde_arrays::deserialize_boxed_slice::<_, u64>(&mut de, usize::MAX).is_err());
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Counts {
+    #[serde(with = "serde_arrays::broadcast")]
+    counts: [u32; 3],
+}
+
+#[test]
+fn broadcast_full_array() {
+    let counts: Counts = serde_json::from_str(r#"{"counts":[1,2,3]}"#).unwrap();
+    assert_eq!([1, 2, 3], counts.counts);
+}
+
+#[test]
+fn broadcast_scalar() {
+    let counts: Counts = serde_json::from_str(r#"{"counts":5}"#).unwrap();
+    assert_eq!([5, 5, 5], counts.counts);
+
+    let json = serde_json::to_string(&counts).unwrap();
+    assert_eq!(r#"{"counts":[5,5,5]}"#, json);
+}
+
+#[test]
+fn broadcast_other_scalars() {
+    #[derive(Deserialize)]
+    struct Mixed {
+        #[serde(with = "serde_arrays::broadcast")]
+        floats: [f32; 4],
+        #[serde(with = "serde_arrays::broadcast")]
+        signed: [i8; 2],
+        #[serde(with = "serde_arrays::broadcast")]
+        flags: [bool; 40],
+        #[serde(with = "serde_arrays::broadcast")]
+        names: [String; 2],
+    }
+
+    let mixed: Mixed =
+        serde_json::from_str(r#"{"floats":0.5,"signed":-3,"flags":true,"names":"anon"}"#).unwrap();
+    assert_eq!([0.5; 4], mixed.floats);
+    assert_eq!([-3; 2], mixed.signed);
+    assert_eq!([true; 40], mixed.flags);
+    assert_eq!(["anon", "anon"], mixed.names);
+
+    let mixed: Mixed =
+        serde_yaml::from_str("floats: 0.25\nsigned: [1, 2]\nflags: false\nnames: [a, b]\n")
+            .unwrap();
+    assert_eq!([0.25; 4], mixed.floats);
+    assert_eq!([1, 2], mixed.signed);
+    assert_eq!(["a", "b"], mixed.names);
+}
+
+#[test]
+fn broadcast_borrowed_str() {
+    #[derive(Deserialize)]
+    struct Borrowed<'a> {
+        #[serde(borrow, with = "serde_arrays::broadcast")]
+        names: [&'a str; 3],
+    }
+
+    let borrowed: Borrowed = serde_json::from_str(r#"{"names":"same"}"#).unwrap();
+    assert_eq!(["same"; 3], borrowed.names);
+}
+
+#[test]
+fn broadcast_errors() {
+    let err = serde_json::from_str::<Counts>(r#"{"counts":[1,2]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 2, expected an array of size 3 or a single value"));
+
+    let err = serde_json::from_str::<Counts>(r#"{"counts":-1}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid value: integer `-1`"));
+
+    let err = serde_json::from_str::<Counts>(r#"{"counts":{}}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid type: map, expected an array of size 3 or a single value"));
+}
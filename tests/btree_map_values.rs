@@ -0,0 +1,78 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Tables {
+    #[serde(with = "serde_arrays::btree_map_values")]
+    tables: BTreeMap<String, [u32; 36]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Numbered {
+    #[serde(with = "serde_arrays::btree_map_values")]
+    tables: BTreeMap<u16, [u8; 3]>,
+}
+
+fn tables() -> Tables {
+    let mut tables = BTreeMap::new();
+    for (i, name) in ["delta", "alpha", "charlie", "bravo"].iter().enumerate() {
+        tables.insert(name.to_string(), [i as u32; 36]);
+    }
+    Tables { tables }
+}
+
+#[test]
+fn btree_map_values_round_trip() {
+    let obj = tables();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Tables = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Tables = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn btree_map_values_deterministic() {
+    let first = bincode::serialize(&tables()).unwrap();
+    let second = bincode::serialize(&tables()).unwrap();
+    assert_eq!(first, second);
+
+    // Maps built in a different order still serialize identically
+    let mut reversed = BTreeMap::new();
+    for (key, value) in tables().tables.into_iter().rev() {
+        reversed.insert(key, value);
+    }
+    let third = bincode::serialize(&Tables { tables: reversed }).unwrap();
+    assert_eq!(first, third);
+}
+
+#[test]
+fn btree_map_values_key_order() {
+    let mut tables = BTreeMap::new();
+    tables.insert(20, [2, 2, 2]);
+    tables.insert(3, [1, 1, 1]);
+    let obj = Numbered { tables };
+
+    assert_eq!(
+        r#"{"tables":{"3":[1,1,1],"20":[2,2,2]}}"#,
+        serde_json::to_string(&obj).unwrap()
+    );
+}
+
+#[test]
+#[should_panic(expected = "for key 9: invalid length 2, expected an array of size 3")]
+fn btree_map_values_error_names_key() {
+    let _: Numbered = serde_json::from_str(r#"{"tables":{"8":[1,2,3],"9":[1,2]}}"#).unwrap();
+}
@@ -0,0 +1,209 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::{
+    ramp,
+    recorder::{record, record_binary, Event},
+};
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, Configure, Token};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Bytes {
+    #[serde(with = "serde_arrays::bytes")]
+    arr: [u8; 64],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Elements {
+    #[serde(with = "serde_arrays")]
+    arr: [u8; 64],
+}
+
+fn data() -> [u8; 64] {
+    ramp(4)
+}
+
+#[test]
+fn bytes_binary_uses_serialize_bytes() {
+    let events = record_binary(&Bytes { arr: data() });
+
+    assert_eq!(
+        vec![
+            Event::Struct("Bytes", 1),
+            Event::Field("arr"),
+            Event::Bytes(data().to_vec()),
+            Event::End,
+        ],
+        events
+    );
+}
+
+#[test]
+fn bytes_human_readable_uses_array() {
+    let events = record(&Bytes { arr: data() });
+
+    assert_eq!(Event::Tuple(64), events[2]);
+    assert_eq!(64 + 5, events.len());
+}
+
+#[test]
+fn bytes_json_matches_elements() {
+    let bytes = serde_json::to_string(&Bytes { arr: data() }).unwrap();
+    let elements = serde_json::to_string(&Elements { arr: data() }).unwrap();
+    assert_eq!(elements, bytes);
+
+    let de: Bytes = serde_json::from_str(&bytes).unwrap();
+    assert_eq!(data(), de.arr);
+}
+
+#[test]
+fn bytes_bincode_round_trip() {
+    let obj = Bytes { arr: data() };
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Bytes = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn bytes_bincode_size() {
+    let bytes = bincode::serialize(&Bytes { arr: data() }).unwrap();
+    let elements = bincode::serialize(&Elements { arr: data() }).unwrap();
+
+    // bincode already writes each `u8` element as a single byte, so the byte string costs only its
+    // length prefix more, in exchange for being written and read as one contiguous slice
+    assert_eq!(64, elements.len());
+    assert_eq!(8 + 64, bytes.len());
+    assert_eq!(&elements[..], &bytes[8..]);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 63, expected a byte string or array of 64 bytes")]
+fn bytes_bincode_wrong_length() {
+    #[derive(Serialize)]
+    struct Short {
+        #[serde(with = "serde_arrays::bytes")]
+        arr: [u8; 63],
+    }
+
+    let b = bincode::serialize(&Short { arr: [0; 63] }).unwrap();
+    let _: Bytes = bincode::deserialize(&b).unwrap();
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct Short(#[serde(with = "serde_arrays::bytes")] [u8; 4]);
+
+#[test]
+fn bytes_from_borrowed_bytes() {
+    assert_de_tokens(
+        &Short([1, 2, 3, 4]).compact(),
+        &[
+            Token::NewtypeStruct { name: "Short" },
+            Token::BorrowedBytes(&[1, 2, 3, 4]),
+        ],
+    );
+}
+
+#[test]
+fn bytes_from_bytes_and_byte_buf() {
+    assert_de_tokens(
+        &Short([1, 2, 3, 4]).compact(),
+        &[
+            Token::NewtypeStruct { name: "Short" },
+            Token::Bytes(&[1, 2, 3, 4]),
+        ],
+    );
+    assert_de_tokens(
+        &Short([1, 2, 3, 4]).compact(),
+        &[
+            Token::NewtypeStruct { name: "Short" },
+            Token::ByteBuf(&[1, 2, 3, 4]),
+        ],
+    );
+}
+
+#[test]
+fn bytes_from_seq_when_human_readable() {
+    assert_de_tokens(
+        &Short([1, 2, 3, 4]).readable(),
+        &[
+            Token::NewtypeStruct { name: "Short" },
+            Token::Tuple { len: 4 },
+            Token::U8(1),
+            Token::U8(2),
+            Token::U8(3),
+            Token::U8(4),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn bytes_borrowed_wrong_length() {
+    assert_de_tokens_error::<serde_test::Compact<Short>>(
+        &[
+            Token::NewtypeStruct { name: "Short" },
+            Token::BorrowedBytes(&[1, 2, 3, 4, 5]),
+        ],
+        "invalid length 5, expected a byte string or array of 4 bytes",
+    );
+}
+
+#[test]
+fn bytes_from_borrowing_deserializer() {
+    use serde::de::value::{BorrowedBytesDeserializer, Error};
+
+    let buf = data();
+    let de = BorrowedBytesDeserializer::<Error>::new(&buf);
+    let arr: [u8; 64] = serde_arrays::bytes::deserialize(de).unwrap();
+    assert_eq!(data(), arr);
+
+    let de = BorrowedBytesDeserializer::<Error>::new(&buf[..60]);
+    let err = serde_arrays::bytes::deserialize::<_, 64>(de).unwrap_err();
+    assert_eq!(
+        "invalid length 60, expected a byte string or array of 64 bytes",
+        err.to_string()
+    );
+}
+
+#[test]
+fn bytes_bincode_from_borrowed_slice() {
+    #[derive(Deserialize, Debug, PartialEq, Eq)]
+    struct Packet<'a> {
+        #[serde(borrow)]
+        header: &'a [u8],
+        #[serde(with = "serde_arrays::bytes")]
+        body: [u8; 64],
+    }
+
+    #[derive(Serialize)]
+    struct Source<'a> {
+        #[serde(with = "byte_slice")]
+        header: &'a [u8],
+        #[serde(with = "serde_arrays::bytes")]
+        body: [u8; 64],
+    }
+
+    mod byte_slice {
+        pub fn serialize<S: serde::Serializer>(v: &&[u8], ser: S) -> Result<S::Ok, S::Error> {
+            ser.serialize_bytes(v)
+        }
+    }
+
+    let buf = bincode::serialize(&Source {
+        header: b"hdr",
+        body: data(),
+    })
+    .unwrap();
+
+    // bincode hands out slices of `buf` itself, so the body is copied out of it in one go
+    let packet: Packet = bincode::deserialize(&buf).unwrap();
+    assert_eq!(b"hdr", packet.header);
+    assert_eq!(data(), packet.body);
+}
@@ -0,0 +1,105 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Upload {
+    #[serde(with = "serde_arrays::capped::Capped::<3>")]
+    blocks: Vec<[u8; 40]>,
+}
+
+#[test]
+fn capped_round_trip() {
+    for len in 0..=3 {
+        let obj = Upload {
+            blocks: vec![[7; 40]; len],
+        };
+
+        let j = serde_json::to_string(&obj).unwrap();
+        assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+        let b = bincode::serialize(&obj).unwrap();
+        assert_eq!(obj, bincode::deserialize(&b).unwrap());
+    }
+}
+
+#[test]
+fn capped_matches_serializable() {
+    #[derive(Serialize)]
+    struct Plain {
+        #[serde(with = "serde_arrays")]
+        blocks: Vec<[u8; 40]>,
+    }
+
+    let blocks = vec![[1; 40], [2; 40]];
+    assert_eq!(
+        serde_json::to_string(&Plain {
+            blocks: blocks.clone()
+        })
+        .unwrap(),
+        serde_json::to_string(&Upload { blocks }).unwrap()
+    );
+}
+
+#[test]
+fn capped_stops_at_first_array_over_cap() {
+    // The input is truncated mid-way through, so reading it to the end would be a syntax error
+    let json = format!(
+        r#"{{"blocks":[{0},{0},{0},{0},{0},[1,2"#,
+        serde_json::to_string(&vec![0u8; 40]).unwrap()
+    );
+
+    let err = serde_json::from_str::<Upload>(&json).unwrap_err();
+    assert!(err.to_string().starts_with(
+        "expected a sequence of at most 3 arrays of size 40, found at least 4 arrays"
+    ));
+}
+
+#[test]
+fn capped_malformed_inner_array_names_index() {
+    let json = format!(
+        r#"{{"blocks":[{0},{0},[1,2]]}}"#,
+        serde_json::to_string(&vec![0u8; 40]).unwrap()
+    );
+
+    let err = serde_json::from_str::<Upload>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 2: invalid length 2, expected an array of size 40"));
+}
+
+#[test]
+fn capped_rejects_size_hint_over_cap() {
+    let obj = Upload {
+        blocks: vec![[0; 40]; 3],
+    };
+    let mut b = bincode::serialize(&obj).unwrap();
+    // Claim a length far beyond the cap in bincode's length prefix
+    b[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    let err = bincode::deserialize::<Upload>(&b).unwrap_err();
+    assert_eq!(
+        format!(
+            "invalid length {}, expected a sequence of at most 3 arrays of size 40",
+            u64::MAX
+        ),
+        err.to_string()
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid length 39, expected an array of size 40")]
+fn capped_checks_array_length() {
+    let json = format!(
+        r#"{{"blocks":[{}]}}"#,
+        serde_json::to_string(&vec![0u8; 39]).unwrap()
+    );
+    serde_json::from_str::<Upload>(&json).unwrap();
+}
@@ -0,0 +1,61 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Code {
+    #[serde(with = "serde_arrays::char_string")]
+    code: [char; 3],
+}
+
+#[test]
+fn char_string_round_trip() {
+    let obj = Code {
+        code: ['a', 'b', 'c'],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"code":"abc"}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn char_string_multi_byte() {
+    let obj = Code {
+        code: ['é', '中', '🦀'],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"code":"é中🦀"}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    // Escaped surrogate pairs decode to a single `char`
+    let de: Code = serde_json::from_str(r#"{"code":"a\ud83e\udd80b"}"#).unwrap();
+    assert_eq!(['a', '🦀', 'b'], de.code);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected a string of 3 chars")]
+fn char_string_too_short() {
+    serde_json::from_str::<Code>(r#"{"code":"ab"}"#).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 5, expected a string of 3 chars")]
+fn char_string_too_long() {
+    serde_json::from_str::<Code>(r#"{"code":"abcde"}"#).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid type: sequence, expected a string of 3 chars")]
+fn char_string_rejects_array() {
+    serde_json::from_str::<Code>(r#"{"code":["a","b","c"]}"#).unwrap();
+}
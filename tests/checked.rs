@@ -0,0 +1,68 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Permutation {
+    #[serde(with = "serde_arrays::checked")]
+    arr: [u8; 16],
+}
+
+#[derive(Deserialize, Debug)]
+struct Names {
+    #[serde(with = "serde_arrays::checked")]
+    arr: [String; 4],
+}
+
+#[test]
+fn checked_permutation_round_trip() {
+    let perm = Permutation {
+        arr: [3, 14, 15, 9, 2, 6, 5, 13, 0, 8, 7, 12, 1, 11, 10, 4],
+    };
+    let j = serde_json::to_string(&perm).unwrap();
+
+    assert_eq!(j, r#"{"arr":[3,14,15,9,2,6,5,13,0,8,7,12,1,11,10,4]}"#);
+    assert_eq!(perm, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn checked_duplicate_reports_first_repeat() {
+    let j = r#"{"arr":[3,14,15,9,2,6,5,13,0,8,7,12,1,9,10,14]}"#;
+    let err = serde_json::from_str::<Permutation>(j).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .starts_with("duplicate element at index 13, first seen at index 3"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn miri_checked_duplicate_strings() {
+    let names: Names = serde_json::from_str(r#"{"arr":["a","b","c","d"]}"#).unwrap();
+    assert_eq!(names.arr, ["a", "b", "c", "d"]);
+
+    // The strings already built must be dropped, which Miri's leak check confirms
+    let err = serde_json::from_str::<Names>(r#"{"arr":["a","b","c","b"]}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("duplicate element at index 3, first seen at index 1"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn checked_still_requires_exact_length() {
+    let err = serde_json::from_str::<Names>(r#"{"arr":["a","b","c"]}"#).unwrap_err();
+
+    assert!(err.to_string().starts_with("invalid length 3"), "{}", err);
+}
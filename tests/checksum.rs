@@ -0,0 +1,96 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::ramp;
+use serde::{Deserialize, Serialize};
+use serde_arrays::{verify_checksum, ArrayError};
+use std::convert::TryFrom;
+
+/// A simple (Adler-32-like) checksum over the array
+fn checksum(data: &[u32; 36]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &x| {
+        let a = a.wrapping_add(x);
+        (a, b.wrapping_add(a))
+    });
+    (b << 16) | (a & 0xffff)
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawRecord {
+    #[serde(with = "serde_arrays")]
+    data: [u32; 36],
+    checksum: u32,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+#[serde(try_from = "RawRecord")]
+struct Record {
+    data: [u32; 36],
+}
+
+impl TryFrom<RawRecord> for Record {
+    type Error = ArrayError;
+
+    fn try_from(raw: RawRecord) -> Result<Self, Self::Error> {
+        verify_checksum(&raw.data, raw.checksum, checksum)?;
+        Ok(Record { data: raw.data })
+    }
+}
+
+fn sample() -> [u32; 36] {
+    ramp(7)
+}
+
+#[test]
+fn verify_checksum_matches() {
+    let data = sample();
+
+    assert_eq!(Ok(()), verify_checksum(&data, checksum(&data), checksum));
+}
+
+#[test]
+fn verify_checksum_mismatch() {
+    let data = sample();
+    let expected = checksum(&data) ^ 1;
+
+    assert_eq!(
+        Err(ArrayError::ChecksumMismatch {
+            expected,
+            actual: checksum(&data),
+        }),
+        verify_checksum(&data, expected, checksum)
+    );
+}
+
+#[test]
+fn deserialize_with_valid_checksum() {
+    let raw = RawRecord {
+        data: sample(),
+        checksum: checksum(&sample()),
+    };
+    let j = serde_json::to_string(&raw).unwrap();
+
+    let record: Record = serde_json::from_str(&j).unwrap();
+
+    assert_eq!(Record { data: sample() }, record);
+}
+
+#[test]
+#[should_panic(expected = "checksum mismatch")]
+fn deserialize_with_invalid_checksum() {
+    let mut data = sample();
+    let raw = RawRecord {
+        data,
+        checksum: checksum(&data),
+    };
+    data[3] += 1;
+    let raw = RawRecord { data, ..raw };
+    let j = serde_json::to_string(&raw).unwrap();
+
+    let _: Record = serde_json::from_str(&j).unwrap();
+}
@@ -0,0 +1,109 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_arrays::codec::{Codec, DisplayFromStr, LowerHex};
+
+/// A user-defined codec that stores each element as a `[value, value * 2]` pair
+struct Doubled;
+
+impl Codec<u32> for Doubled {
+    fn encode_element<S>(value: &u32, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        [*value, *value * 2].serialize(ser)
+    }
+
+    fn decode_element<'de, D>(de: D) -> Result<u32, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [value, doubled] = <[u32; 2]>::deserialize(de)?;
+        if value * 2 != doubled {
+            return Err(serde::de::Error::custom("checksum mismatch"));
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct CustomCodec {
+    #[serde(with = "serde_arrays::codec::WithCodec::<Doubled>")]
+    arr: [u32; 3],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct DisplayArray {
+    #[serde(with = "serde_arrays::codec::WithCodec::<DisplayFromStr>")]
+    arr: [f64; 3],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct HexArray<const N: usize> {
+    #[serde(with = "serde_arrays::codec::WithCodec::<LowerHex>")]
+    arr: [u8; N],
+}
+
+#[test]
+fn custom_codec_round_trip() {
+    let obj = CustomCodec { arr: [1, 2, 3] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("{\"arr\":[[1,2],[2,4],[3,6]]}", &j);
+
+    let de: CustomCodec = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn custom_codec_element_error() {
+    let err = serde_json::from_str::<CustomCodec>("{\"arr\":[[1,2],[2,5],[3,6]]}").unwrap_err();
+    assert!(err.to_string().contains("checksum mismatch"));
+}
+
+#[test]
+fn display_codec_round_trip() {
+    let obj = DisplayArray {
+        arr: [1.5, -2.0, 0.25],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("{\"arr\":[\"1.5\",\"-2\",\"0.25\"]}", &j);
+
+    let de: DisplayArray = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn hex_codec_round_trip() {
+    let obj = HexArray::<36> { arr: [0xab; 36] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: HexArray<36> = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let obj = HexArray::<4> {
+        arr: [0x00, 0x0f, 0x10, 0xff],
+    };
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("{\"arr\":[\"0\",\"f\",\"10\",\"ff\"]}", &j);
+}
+
+#[test]
+#[should_panic(expected = "invalid value \\\"zz\\\"")]
+fn hex_codec_invalid_element() {
+    let _: HexArray<2> = serde_json::from_str("{\"arr\":[\"0a\",\"zz\"]}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "expected an array of size 3")]
+fn codec_wrong_length() {
+    let _: DisplayArray = serde_json::from_str("{\"arr\":[\"1\",\"2\"]}").unwrap();
+}
@@ -0,0 +1,102 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// These exercise the unsafe array-building code with heap-owning elements; run them under Miri to
+// check for undefined behavior and leaks:
+//
+//     cargo +nightly miri test --test collect_array
+
+mod common;
+use common::drops::{live, Counted};
+use serde_arrays::{collect_array, ArrayLengthError};
+
+#[test]
+fn collect_array_exact() {
+    let arr: [String; 4] = collect_array((0..4).map(|i| i.to_string())).unwrap();
+    assert_eq!(["0", "1", "2", "3"], arr);
+
+    let arr: [u32; 40] = collect_array(vec![7; 40]).unwrap();
+    assert_eq!([7; 40], arr);
+
+    let arr: [u32; 0] = collect_array(std::iter::empty()).unwrap();
+    assert_eq!([0u32; 0], arr);
+}
+
+#[test]
+fn collect_array_short() {
+    let err = collect_array::<_, _, 40>((0..36).map(|i| i.to_string())).unwrap_err();
+    assert_eq!(
+        ArrayLengthError {
+            expected: 40,
+            found: 36
+        },
+        err
+    );
+    assert!(err.is_too_short());
+}
+
+#[test]
+fn collect_array_over_long_takes_one_past_the_end() {
+    let mut iter = 0..100;
+    let err = collect_array::<_, _, 40>(&mut iter).unwrap_err();
+    assert_eq!(
+        ArrayLengthError {
+            expected: 40,
+            found: 41
+        },
+        err
+    );
+    assert!(err.is_too_long());
+    assert_eq!(Some(41), iter.next());
+
+    // Endless iterators are fine too
+    let err = collect_array::<_, _, 4>(std::iter::repeat(1u8)).unwrap_err();
+    assert!(err.is_too_long());
+}
+
+#[test]
+fn collect_array_short_drops_taken_items() {
+    let items = (0..36).map(|i| Counted::new(i.to_string()));
+
+    assert!(collect_array::<_, _, 40>(items).is_err());
+    assert_eq!(0, live());
+}
+
+#[test]
+fn collect_array_over_long_drops_everything() {
+    let items = (0..41).map(|i| Counted::new(i.to_string()));
+
+    assert!(collect_array::<_, _, 40>(items).is_err());
+    assert_eq!(0, live());
+}
+
+#[test]
+fn collect_array_exact_drops_nothing_early() {
+    let arr: [Counted<String>; 40] =
+        collect_array((0..40).map(|i| Counted::new(i.to_string()))).unwrap();
+
+    assert_eq!(40, live());
+    assert_eq!("39", arr[39].0);
+    drop(arr);
+    assert_eq!(0, live());
+}
+
+#[test]
+fn collect_array_panicking_iterator_drops_taken_items() {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let items = (0..40).map(|i| {
+            if i == 20 {
+                panic!("boom");
+            }
+            Counted::new(i.to_string())
+        });
+        collect_array::<_, _, 40>(items)
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(0, live());
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Table {
+    #[serde(with = "serde_arrays::columnar")]
+    rows: Vec<[u32; 4]>,
+}
+
+#[test]
+fn columnar_round_trip() {
+    let data = Table {
+        rows: vec![[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]],
+    };
+
+    let json = serde_json::to_string(&data).unwrap();
+    assert_eq!(r#"{"rows":[[1,5,9],[2,6,10],[3,7,11],[4,8,12]]}"#, json);
+
+    let de_data = serde_json::from_str(&json).unwrap();
+    assert_eq!(data, de_data);
+}
+
+#[test]
+fn columnar_no_rows() {
+    let data = Table { rows: Vec::new() };
+
+    let json = serde_json::to_string(&data).unwrap();
+    assert_eq!(r#"{"rows":[[],[],[],[]]}"#, json);
+
+    let de_data = serde_json::from_str(&json).unwrap();
+    assert_eq!(data, de_data);
+}
+
+#[test]
+#[should_panic(expected = "column 2 has 2 elements but column 0 has 3")]
+fn columnar_ragged_columns() {
+    let json = r#"{"rows":[[1,5,9],[2,6,10],[3,7],[4,8,12]]}"#;
+
+    let _: Table = serde_json::from_str(json).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "expected 4 columns of equal length")]
+fn columnar_wrong_column_count() {
+    let json = r#"{"rows":[[1,5,9],[2,6,10],[3,7,11]]}"#;
+
+    let _: Table = serde_json::from_str(json).unwrap();
+}
+
+struct A;
+impl serde_arrays::unwrap_field::FieldName for A {
+    const NAME: &'static str = "a";
+}
+
+struct B;
+impl serde_arrays::unwrap_field::FieldName for B {
+    const NAME: &'static str = "b";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Pairs {
+    #[serde(with = "serde_arrays::columnar::Pairs::<A, B>")]
+    rows: [(i32, i32); 2],
+}
+
+#[test]
+fn columnar_pairs() {
+    let pairs: Pairs = serde_json::from_str(r#"{"rows":{"a":[1,2],"b":[3,4]}}"#).unwrap();
+    assert_eq!([(1, 3), (2, 4)], pairs.rows);
+
+    let json = serde_json::to_string(&pairs).unwrap();
+    assert_eq!(r#"{"rows":{"a":[1,2],"b":[3,4]}}"#, json);
+
+    // Columns may come in any order, alongside other fields
+    let json = r#"{"rows":{"b":[3,4],"c":"ignored","a":[1,2]}}"#;
+    assert_eq!(pairs, serde_json::from_str(json).unwrap());
+
+    let b = bincode::serialize(&pairs).unwrap();
+    assert_eq!(pairs, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn columnar_pairs_wrong_length() {
+    let err = serde_json::from_str::<Pairs>(r#"{"rows":{"a":[1,2],"b":[3,4,5]}}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("column `b`: invalid length 3, expected an array of size 2"));
+
+    let err = serde_json::from_str::<Pairs>(r#"{"rows":{"a":[1],"b":[3]}}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("column `a`: invalid length 1, expected an array of size 2"));
+}
+
+#[test]
+fn columnar_pairs_missing_or_duplicate_column() {
+    let err = serde_json::from_str::<Pairs>(r#"{"rows":{"a":[1,2]}}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `b`"));
+
+    let err = serde_json::from_str::<Pairs>(r#"{"rows":{"a":[1,2],"a":[1,2]}}"#).unwrap_err();
+    assert!(err.to_string().starts_with("duplicate field `a`"));
+}
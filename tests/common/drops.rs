@@ -0,0 +1,65 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! An element that counts how many instances of itself are alive, for checking that every element
+//! built is dropped exactly once
+
+use serde::{Deserialize, Deserializer};
+use std::cell::Cell;
+
+std::thread_local! {
+    // Kept per thread, so that tests running in parallel don't share a count
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+}
+
+/// The number of `Counted` values currently alive on this thread
+pub fn live() -> isize {
+    LIVE.with(|live| live.get())
+}
+
+/// A value that tracks how many instances of `Counted` are currently alive
+///
+/// A `Counted<()>` is zero-sized, yet still needs dropping.
+#[derive(Debug)]
+pub struct Counted<T = ()>(pub T);
+
+impl<T> Counted<T> {
+    pub fn new(val: T) -> Self {
+        LIVE.with(|live| live.set(live.get() + 1));
+        Counted(val)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Counted<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Counted::new)
+    }
+}
+
+impl<T> Drop for Counted<T> {
+    fn drop(&mut self) {
+        LIVE.with(|live| live.set(live.get() - 1));
+    }
+}
+
+/// A `Counted` string that panics while deserializing the string `"panic"`
+#[derive(Debug)]
+pub struct Panicky(pub Counted<String>);
+
+impl<'de> Deserialize<'de> for Panicky {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let val = String::deserialize(deserializer)?;
+        assert_ne!("panic", val, "element deserializer panicked");
+        Ok(Panicky(Counted::new(val)))
+    }
+}
@@ -5,9 +5,37 @@
 // https://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+// Not every test binary uses every helper defined here
+#![allow(dead_code)]
+
 use serde::{Deserialize, Serialize};
 
+pub mod drops;
 pub mod nested;
+pub mod recorder;
+
+/// What error messages say was expected for an array of `n` elements of type `T`
+///
+/// With the `type-names` feature, the element type is named too.
+pub fn expected_array<T>(n: usize) -> String {
+    if cfg!(feature = "type-names") {
+        format!("an array of size {} of `{}`", n, std::any::type_name::<T>())
+    } else {
+        format!("an array of size {}", n)
+    }
+}
+
+/// An array counting up from zero in steps of `step`, i.e. `[0, step, 2 * step, ...]`
+pub fn ramp<T, const N: usize>(step: T) -> [T; N]
+where
+    T: Copy + Default + std::ops::Add<Output = T>,
+{
+    let mut arr = [T::default(); N];
+    for i in 1..N {
+        arr[i] = arr[i - 1] + step;
+    }
+    arr
+}
 
 /// A simple struct containing a const generic array
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
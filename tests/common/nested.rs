@@ -5,22 +5,23 @@
 // https://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct NestedArray<const N: usize> {
-    #[serde(with = "serde_arrays")]
+    #[serde(with = "serde_arrays::nested")]
     pub arr: [[u32; N]; 2],
 }
 
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct GenericNestedArray<const N: usize, const M: usize> {
-    #[serde(with = "serde_arrays")]
+    #[serde(with = "serde_arrays::nested")]
     pub arr: [[u32; N]; M],
 }
 
-#[derive(Serialize, Debug, PartialEq, Eq)]
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct VecArray<const N: usize> {
-    #[serde(with = "serde_arrays")]
+    #[serde(with = "serde_arrays::nested")]
     pub arr: Vec<[u32; N]>,
 }
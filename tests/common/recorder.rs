@@ -0,0 +1,339 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! A `Serializer` that records every call made to it, so tests can assert exactly which Serde
+//! data model types (and length hints) a serialize implementation emits.
+
+use serde::ser::{self, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Char(char),
+    Str(String),
+    Bytes(Vec<u8>),
+    None,
+    Some,
+    Unit,
+    UnitStruct(&'static str),
+    UnitVariant(&'static str, &'static str),
+    NewtypeStruct(&'static str),
+    NewtypeVariant(&'static str, &'static str),
+    Seq(Option<usize>),
+    Tuple(usize),
+    TupleStruct(&'static str, usize),
+    TupleVariant(&'static str, &'static str, usize),
+    Map(Option<usize>),
+    Struct(&'static str, usize),
+    StructVariant(&'static str, &'static str, usize),
+    Field(&'static str),
+    End,
+}
+
+#[derive(Debug)]
+pub struct Error(pub String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Recorder {
+    pub events: Vec<Event>,
+    pub binary: bool,
+}
+
+impl Recorder {
+    /// A recorder that reports itself as human-readable, like JSON
+    pub fn new() -> Self {
+        Recorder::default()
+    }
+
+    /// A recorder that reports itself as not human-readable, like bincode
+    pub fn binary() -> Self {
+        Recorder {
+            events: Vec::new(),
+            binary: true,
+        }
+    }
+
+    fn push(&mut self, event: Event) -> Result<(), Error> {
+        self.events.push(event);
+        Ok(())
+    }
+}
+
+/// Serialize `value` with a human-readable recorder and return the recorded events
+pub fn record<T: Serialize + ?Sized>(value: &T) -> Vec<Event> {
+    let mut rec = Recorder::new();
+    value.serialize(&mut rec).unwrap();
+    rec.events
+}
+
+/// Serialize `value` with a non-human-readable recorder and return the recorded events
+pub fn record_binary<T: Serialize + ?Sized>(value: &T) -> Vec<Event> {
+    let mut rec = Recorder::binary();
+    value.serialize(&mut rec).unwrap();
+    rec.events
+}
+
+/// Every container length hint passed to the serializer, in order
+pub fn length_hints(events: Vec<Event>) -> Vec<Event> {
+    events
+        .into_iter()
+        .filter(|event| matches!(event, Event::Tuple(_) | Event::Seq(_)))
+        .collect()
+}
+
+impl ser::Serializer for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn is_human_readable(&self) -> bool {
+        !self.binary
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.push(Event::Bool(v))
+    }
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.push(Event::I64(v.into()))
+    }
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.push(Event::I64(v.into()))
+    }
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.push(Event::I64(v.into()))
+    }
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        self.push(Event::I64(v))
+    }
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.push(Event::U64(v.into()))
+    }
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.push(Event::U64(v.into()))
+    }
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.push(Event::U64(v.into()))
+    }
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        self.push(Event::U64(v))
+    }
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.push(Event::F64(v.into()))
+    }
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.push(Event::F64(v))
+    }
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.push(Event::Char(v))
+    }
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        self.push(Event::Str(v.to_owned()))
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        self.push(Event::Bytes(v.to_vec()))
+    }
+    fn serialize_none(self) -> Result<(), Error> {
+        self.push(Event::None)
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), Error> {
+        self.push(Event::Some)?;
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.push(Event::Unit)
+    }
+    fn serialize_unit_struct(self, name: &'static str) -> Result<(), Error> {
+        self.push(Event::UnitStruct(name))
+    }
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.push(Event::UnitVariant(name, variant))
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(Event::NewtypeStruct(name))?;
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(Event::NewtypeVariant(name, variant))?;
+        value.serialize(self)
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self, Error> {
+        self.push(Event::Seq(len))?;
+        Ok(self)
+    }
+    fn serialize_tuple(self, len: usize) -> Result<Self, Error> {
+        self.push(Event::Tuple(len))?;
+        Ok(self)
+    }
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<Self, Error> {
+        self.push(Event::TupleStruct(name, len))?;
+        Ok(self)
+    }
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self, Error> {
+        self.push(Event::TupleVariant(name, variant, len))?;
+        Ok(self)
+    }
+    fn serialize_map(self, len: Option<usize>) -> Result<Self, Error> {
+        self.push(Event::Map(len))?;
+        Ok(self)
+    }
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<Self, Error> {
+        self.push(Event::Struct(name, len))?;
+        Ok(self)
+    }
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self, Error> {
+        self.push(Event::StructVariant(name, variant, len))?;
+        Ok(self)
+    }
+}
+
+impl ser::SerializeSeq for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
+
+impl ser::SerializeTuple for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
+
+impl ser::SerializeMap for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        key.serialize(&mut **self)
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
+
+impl ser::SerializeStruct for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(Event::Field(key))?;
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
+
+impl ser::SerializeStructVariant for &mut Recorder {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(Event::Field(key))?;
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> Result<(), Error> {
+        self.push(Event::End)
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Counted {
+    #[serde(with = "serde_arrays::counted")]
+    arr: [u32; 4],
+}
+
+fn sample() -> Counted {
+    Counted { arr: [1, 2, 3, 4] }
+}
+
+fn from_json(j: &str) -> String {
+    serde_json::from_str::<Counted>(j).unwrap_err().to_string()
+}
+
+#[test]
+fn counted_json_round_trip() {
+    let j = serde_json::to_string(&sample()).unwrap();
+
+    assert_eq!(j, r#"{"arr":{"count":4,"items":[1,2,3,4]}}"#);
+    assert_eq!(sample(), serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn counted_bincode_round_trip() {
+    let b = bincode::serialize(&sample()).unwrap();
+
+    assert_eq!(b.len(), 8 + 4 * 4);
+    assert_eq!(sample(), bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn counted_accepts_items_first() {
+    let c: Counted =
+        serde_json::from_str(r#"{"arr":{"items":[1,2,3,4],"extra":true,"count":4}}"#).unwrap();
+
+    assert_eq!(c, sample());
+}
+
+#[test]
+fn counted_rejects_count_mismatch() {
+    let err = from_json(r#"{"arr":{"count":5,"items":[1,2,3,4]}}"#);
+    assert!(
+        err.starts_with("count 5 does not match an array of size 4"),
+        "{}",
+        err
+    );
+
+    let mut b = bincode::serialize(&sample()).unwrap();
+    b[0] = 3;
+    let err = bincode::deserialize::<Counted>(&b).unwrap_err().to_string();
+    assert_eq!(err, "count 3 does not match an array of size 4");
+}
+
+#[test]
+fn counted_rejects_items_mismatch() {
+    let err = from_json(r#"{"arr":{"count":4,"items":[1,2,3]}}"#);
+    assert!(
+        err.starts_with("invalid length 3, expected an array of size 4"),
+        "{}",
+        err
+    );
+
+    let err = from_json(r#"{"arr":{"count":4,"items":[1,2,3,4,5]}}"#);
+    assert!(
+        err.starts_with("invalid length 5, expected an array of size 4"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn counted_rejects_missing_fields() {
+    let err = from_json(r#"{"arr":{"items":[1,2,3,4]}}"#);
+    assert!(err.starts_with("missing field `count`"), "{}", err);
+
+    let err = from_json(r#"{"arr":{"count":4}}"#);
+    assert!(err.starts_with("missing field `items`"), "{}", err);
+}
+
+#[test]
+fn counted_rejects_duplicate_fields() {
+    let err = from_json(r#"{"arr":{"count":4,"count":4,"items":[1,2,3,4]}}"#);
+    assert!(err.starts_with("duplicate field `count`"), "{}", err);
+}
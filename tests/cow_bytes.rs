@@ -0,0 +1,102 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+use common::ramp;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Packet<'a> {
+    #[serde(borrow, with = "serde_arrays::cow_bytes")]
+    payload: Cow<'a, [u8; 64]>,
+}
+
+fn data() -> [u8; 64] {
+    ramp(3)
+}
+
+#[test]
+fn cow_bytes_bincode_borrows() {
+    let buf = bincode::serialize(&Packet {
+        payload: Cow::Owned(data()),
+    })
+    .unwrap();
+
+    let de: Packet = bincode::deserialize(&buf).unwrap();
+    match de.payload {
+        Cow::Borrowed(arr) => {
+            // The array is the tail of the buffer, after bincode's 8-byte length prefix
+            assert!(std::ptr::eq(arr.as_ptr(), buf[8..].as_ptr()));
+            assert_eq!(&data(), arr);
+        }
+        Cow::Owned(_) => panic!("expected the payload to be borrowed"),
+    }
+}
+
+#[test]
+fn cow_bytes_json_is_owned() {
+    let json = serde_json::to_string(&Packet {
+        payload: Cow::Owned(data()),
+    })
+    .unwrap();
+    assert!(json.starts_with(r#"{"payload":[0,3,6,"#));
+
+    let de: Packet = serde_json::from_str(&json).unwrap();
+    match de.payload {
+        Cow::Owned(arr) => assert_eq!(data(), arr),
+        Cow::Borrowed(_) => panic!("expected the payload to be owned"),
+    }
+}
+
+#[test]
+fn cow_bytes_transient_bytes_are_owned() {
+    use serde::de::value::{BytesDeserializer, Error};
+
+    let buf = data();
+    let de = BytesDeserializer::<Error>::new(&buf);
+    let cow: Cow<[u8; 64]> = serde_arrays::cow_bytes::deserialize(de).unwrap();
+    assert!(matches!(cow, Cow::Owned(arr) if arr == data()));
+}
+
+#[test]
+fn cow_bytes_serializes_like_bytes() {
+    #[derive(Serialize)]
+    struct Bytes {
+        #[serde(with = "serde_arrays::bytes")]
+        payload: [u8; 64],
+    }
+
+    let cow = bincode::serialize(&Packet {
+        payload: Cow::Borrowed(&data()),
+    })
+    .unwrap();
+    let bytes = bincode::serialize(&Bytes { payload: data() }).unwrap();
+    assert_eq!(bytes, cow);
+}
+
+#[test]
+fn cow_bytes_wrong_length() {
+    use serde::de::value::{BorrowedBytesDeserializer, BytesDeserializer, Error};
+
+    let buf = data();
+    let de = BorrowedBytesDeserializer::<Error>::new(&buf[..60]);
+    let err = serde_arrays::cow_bytes::deserialize::<_, 64>(de).unwrap_err();
+    assert_eq!(
+        "invalid length 60, expected a byte string or array of 64 bytes",
+        err.to_string()
+    );
+
+    let de = BytesDeserializer::<Error>::new(&buf[..60]);
+    let err = serde_arrays::cow_bytes::deserialize::<_, 64>(de).unwrap_err();
+    assert_eq!(
+        "invalid length 60, expected a byte string or array of 64 bytes",
+        err.to_string()
+    );
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Name {
+    #[serde(with = "serde_arrays::cstr")]
+    name: [u8; 8],
+}
+
+#[test]
+fn cstr_round_trip() {
+    let obj = Name {
+        name: *b"hi\0\0\0\0\0\0",
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"name":"hi"}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn cstr_empty_and_full() {
+    let obj = Name { name: [0; 8] };
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"name":""}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    // No room for a terminating NUL
+    let obj = Name { name: *b"exactly8" };
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"name":"exactly8"}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn cstr_ignores_bytes_after_nul() {
+    let obj = Name {
+        name: *b"hi\0junk!",
+    };
+
+    assert_eq!(r#"{"name":"hi"}"#, serde_json::to_string(&obj).unwrap());
+}
+
+#[test]
+fn cstr_too_long() {
+    let err = serde_json::from_str::<Name>(r#"{"name":"too long!"}"#).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 9, expected a string of at most 8 bytes"));
+
+    // Two-byte characters count as two bytes
+    let err = serde_json::from_str::<Name>(r#"{"name":"ééééé"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid length 10"));
+}
+
+#[test]
+fn cstr_rejects_embedded_nul() {
+    let err = serde_json::from_str::<Name>(r#"{"name":"a\u0000b"}"#).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("C string contains a NUL byte at index 1"));
+}
+
+#[test]
+fn cstr_invalid_utf8_is_error() {
+    let obj = Name {
+        name: *b"h\xffi\0\0\0\0\0",
+    };
+    let err = serde_json::to_string(&obj).unwrap_err();
+
+    assert!(err.to_string().starts_with("invalid UTF-8 in C string"));
+}
@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Legacy {
+    #[serde(with = "serde_arrays::csv_string")]
+    arr: [u8; 4],
+}
+
+#[test]
+fn csv_string_round_trip() {
+    let obj = Legacy {
+        arr: [1, 20, 255, 0],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":"1,20,255,0"}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn csv_string_ignores_whitespace_around_tokens() {
+    let de: Legacy = serde_json::from_str(r#"{"arr":" 1, 2 ,3 ,4"}"#).unwrap();
+    assert_eq!([1, 2, 3, 4], de.arr);
+}
+
+#[test]
+fn csv_string_empty_array() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Empty {
+        #[serde(with = "serde_arrays::csv_string")]
+        arr: [u8; 0],
+    }
+
+    let j = serde_json::to_string(&Empty { arr: [] }).unwrap();
+    assert_eq!(r#"{"arr":""}"#, j);
+    assert_eq!(Empty { arr: [] }, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn csv_string_malformed_token() {
+    let err = serde_json::from_str::<Legacy>(r#"{"arr":"1,2,x3,4"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid value \"x3\""));
+
+    let err = serde_json::from_str::<Legacy>(r#"{"arr":"1,2,,4"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid value \"\""));
+
+    let err = serde_json::from_str::<Legacy>(r#"{"arr":"1,2,300,4"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid value \"300\""));
+}
+
+#[test]
+fn csv_string_wrong_count() {
+    let err = serde_json::from_str::<Legacy>(r#"{"arr":"1,2,3"}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a string of 4 comma-separated values"));
+
+    let err = serde_json::from_str::<Legacy>(r#"{"arr":"1,2,3,4,5"}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 5, expected a string of 4 comma-separated values"));
+}
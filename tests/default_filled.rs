@@ -0,0 +1,58 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Lenient {
+    #[serde(with = "serde_arrays::default_filled")]
+    arr: [u32; 8],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Names {
+    #[serde(with = "serde_arrays::default_filled")]
+    arr: [String; 36],
+}
+
+#[test]
+fn default_filled_short() {
+    let obj: Lenient = serde_json::from_str(r#"{"arr":[7,8,9]}"#).unwrap();
+    assert_eq!([7, 8, 9, 0, 0, 0, 0, 0], obj.arr);
+}
+
+#[test]
+fn default_filled_empty() {
+    let obj: Lenient = serde_json::from_str(r#"{"arr":[]}"#).unwrap();
+    assert_eq!([0; 8], obj.arr);
+}
+
+#[test]
+fn default_filled_full_round_trip() {
+    let obj = Lenient {
+        arr: [1, 2, 3, 4, 5, 6, 7, 8],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[1,2,3,4,5,6,7,8]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn default_filled_large_array() {
+    let obj: Names = serde_json::from_str(r#"{"arr":["a","b"]}"#).unwrap();
+
+    assert_eq!("a", obj.arr[0]);
+    assert_eq!("b", obj.arr[1]);
+    assert!(obj.arr[2..].iter().all(String::is_empty));
+}
+
+#[test]
+#[should_panic(expected = "invalid length 9, expected an array of 0 to 8 elements")]
+fn default_filled_too_long() {
+    let _: Lenient = serde_json::from_str(r#"{"arr":[1,2,3,4,5,6,7,8,9]}"#).unwrap();
+}
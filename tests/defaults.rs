@@ -0,0 +1,112 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Skippable {
+    id: u32,
+    #[serde(
+        with = "serde_arrays",
+        default,
+        skip_serializing_if = "serde_arrays::is_all_default"
+    )]
+    arr: [u32; 4],
+}
+
+#[derive(Serialize, Debug, PartialEq, Eq)]
+struct SkippableLarge {
+    #[serde(
+        with = "serde_arrays",
+        skip_serializing_if = "serde_arrays::is_all_default"
+    )]
+    arr: [u32; 36],
+}
+
+#[test]
+fn all_default_array_is_omitted() {
+    let obj = Skippable { id: 1, arr: [0; 4] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("{\"id\":1}", &j);
+
+    let de: Skippable = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn non_default_array_is_written() {
+    let obj = Skippable {
+        id: 1,
+        arr: [0, 0, 3, 0],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("{\"id\":1,\"arr\":[0,0,3,0]}", &j);
+}
+
+#[test]
+fn large_all_default_array_is_omitted() {
+    let j = serde_json::to_string(&SkippableLarge { arr: [0; 36] }).unwrap();
+    assert_eq!("{}", &j);
+
+    let mut arr = [0; 36];
+    arr[35] = 1;
+    let j = serde_json::to_string(&SkippableLarge { arr }).unwrap();
+    assert!(j.ends_with(",0,1]}"));
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Defaulted {
+    id: u32,
+    #[serde(with = "serde_arrays", default = "serde_arrays::default_array")]
+    arr: [u32; 64],
+    #[serde(with = "serde_arrays", default = "serde_arrays::default_array")]
+    names: [String; 40],
+}
+
+#[test]
+fn absent_large_array_is_defaulted() {
+    let de: Defaulted = serde_json::from_str("{\"id\":7}").unwrap();
+
+    assert_eq!(7, de.id);
+    assert_eq!([0; 64], de.arr);
+    assert!(de.names.iter().all(String::is_empty));
+}
+
+#[test]
+fn present_large_array_is_not_defaulted() {
+    let json = format!("{{\"id\":7,\"arr\":{:?}}}", [5u32; 64]);
+    let de: Defaulted = serde_json::from_str(&json).unwrap();
+
+    assert_eq!([5; 64], de.arr);
+}
+
+#[test]
+fn is_all_default_predicate() {
+    assert!(serde_arrays::is_all_default(&[0u32; 16]));
+
+    for idx in 0..16 {
+        let mut arr = [0u32; 16];
+        arr[idx] = 1;
+        assert!(!serde_arrays::is_all_default(&arr), "index {}", idx);
+    }
+}
+
+#[test]
+fn is_all_default_non_copy_elements() {
+    let mut names: [String; 40] = serde_arrays::default_array();
+    assert!(serde_arrays::is_all_default(&names));
+
+    names[39].push('x');
+    assert!(!serde_arrays::is_all_default(&names));
+}
+
+#[test]
+fn is_all_default_empty_array() {
+    assert!(serde_arrays::is_all_default::<u32, 0>(&[]));
+}
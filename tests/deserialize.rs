@@ -6,7 +6,9 @@
 // copied, modified, or distributed except according to those terms.
 
 mod common;
+use common::drops::{live, Panicky};
 use common::*;
+use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
 
 #[test]
 fn deserialize_generic_array() {
@@ -46,6 +48,52 @@ fn deserialize_fixed_size_array_with_invalid_input() {
     assert_eq!(FixedArray { arr: [1; 36] }, obj);
 }
 
+/// JSON for a `FixedArray` with `len` elements
+fn fixed_array_json(len: usize) -> String {
+    format!(r#"{{"arr":{:?}}}"#, vec![1; len])
+}
+
+#[test]
+fn deserialize_too_short_reports_elements_found() {
+    let err = serde_json::from_str::<FixedArray>(&fixed_array_json(35)).unwrap_err();
+
+    assert!(err.to_string().starts_with(&format!(
+        "invalid length 35, expected {} at line 1",
+        expected_array::<u32>(36)
+    )));
+}
+
+#[test]
+fn deserialize_too_long_reports_elements_found() {
+    let err = serde_json::from_str::<FixedArray>(&fixed_array_json(40)).unwrap_err();
+
+    assert!(err.to_string().starts_with(&format!(
+        "invalid length 40, expected {} at line 1",
+        expected_array::<u32>(36)
+    )));
+}
+
+#[test]
+fn deserialize_far_too_long_reports_lower_bound() {
+    let err = serde_json::from_str::<FixedArray>(&fixed_array_json(5000)).unwrap_err();
+
+    assert!(err.to_string().starts_with(&format!(
+        "expected {}, found at least 1060 elements",
+        expected_array::<u32>(36)
+    )));
+}
+
+#[test]
+fn deserialize_too_long_and_malformed_reports_lower_bound() {
+    let json = format!(r#"{{"arr":{:?}"#, vec![1; 40]).replace("1, 1]", "1, }");
+    let err = serde_json::from_str::<FixedArray>(&json).unwrap_err();
+
+    assert!(err.to_string().starts_with(&format!(
+        "expected {}, found at least 39 elements",
+        expected_array::<u32>(36)
+    )));
+}
+
 #[test]
 fn deserialize_tuple_struct_with_generic_array() {
     let obj: GenericTupleStruct<16> =
@@ -53,3 +101,424 @@ fn deserialize_tuple_struct_with_generic_array() {
 
     assert_eq!(GenericTupleStruct::<16>([1; 16]), obj);
 }
+
+// The following tests exercise the unsafe array-building code with heap-owning elements, on both
+// the success and error paths. Run them under Miri to check for undefined behavior and leaks:
+//
+//     cargo +nightly miri test --test deserialize miri_
+
+#[derive(serde::Deserialize, Debug)]
+struct Strings {
+    #[serde(with = "serde_arrays")]
+    arr: [String; 4],
+}
+
+#[test]
+fn miri_deserialize_strings() {
+    let obj: Strings = serde_json::from_str(r#"{"arr":["a","bb","ccc","dddd"]}"#).unwrap();
+
+    assert_eq!(["a", "bb", "ccc", "dddd"], obj.arr);
+}
+
+#[test]
+fn miri_deserialize_strings_too_short() {
+    let res: Result<Strings, _> = serde_json::from_str(r#"{"arr":["a","bb","ccc"]}"#);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn miri_deserialize_strings_invalid_element() {
+    let res: Result<Strings, _> = serde_json::from_str(r#"{"arr":["a","bb",3,"dddd"]}"#);
+
+    assert!(res.is_err());
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Panickies {
+    #[serde(with = "serde_arrays")]
+    arr: [Panicky; 4],
+}
+
+#[test]
+fn miri_deserialize_panicking_element_drops_prior_elements() {
+    let res = std::panic::catch_unwind(|| {
+        serde_json::from_str::<Panickies>(r#"{"arr":["a","bb","panic","dddd"]}"#)
+    });
+
+    assert!(res.is_err());
+    assert_eq!(0, live());
+
+    let obj: Panickies = serde_json::from_str(r#"{"arr":["a","bb","ccc","dddd"]}"#).unwrap();
+    assert_eq!(4, live());
+    assert_eq!("ccc", obj.arr[2].0 .0);
+    drop(obj);
+    assert_eq!(0, live());
+}
+
+#[test]
+fn miri_deserialize_excess_elements_are_skipped_not_built() {
+    // Were the excess elements deserialized as `Panicky`, the "panic" among them would panic
+    let json = r#"{"arr":["a","bb","ccc","dddd","panic","f",}"#;
+    let err = serde_json::from_str::<Panickies>(json).unwrap_err();
+
+    assert!(
+        err.to_string().starts_with(&format!(
+            "expected {}, found at least 6 elements",
+            expected_array::<Panicky>(4)
+        )),
+        "{}",
+        err
+    );
+    assert_eq!(0, live());
+}
+
+// An array of options, `[Option<T>; N]`, is built element by element like any other array;
+// unlike `Option<[T; N]>`, each element may be `None` on its own
+
+#[derive(serde::Deserialize, Debug)]
+struct OptionalStrings {
+    #[serde(with = "serde_arrays")]
+    arr: [Option<String>; 4],
+}
+
+#[test]
+fn miri_deserialize_optional_strings() {
+    let obj: OptionalStrings = serde_json::from_str(r#"{"arr":["a",null,"ccc",null]}"#).unwrap();
+
+    assert_eq!(
+        [Some("a".to_string()), None, Some("ccc".to_string()), None],
+        obj.arr
+    );
+}
+
+#[test]
+fn miri_deserialize_optional_strings_invalid_last_element() {
+    // The `Some(String)`s already built must be dropped, which Miri's leak check confirms
+    let err = serde_json::from_str::<OptionalStrings>(r#"{"arr":["a",null,"ccc",4]}"#).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid type: integer `4`, expected a string"));
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct OptionalPanickies {
+    #[serde(with = "serde_arrays")]
+    arr: [Option<Panicky>; 4],
+}
+
+#[test]
+fn miri_deserialize_optional_elements_dropped_on_error() {
+    let res = serde_json::from_str::<OptionalPanickies>(r#"{"arr":["a",null,"ccc",4]}"#);
+
+    assert!(res.is_err());
+    assert_eq!(0, live());
+
+    let obj: OptionalPanickies = serde_json::from_str(r#"{"arr":["a",null,"ccc",null]}"#).unwrap();
+    assert_eq!(2, live());
+    assert!(obj.arr[1].is_none());
+    drop(obj);
+    assert_eq!(0, live());
+}
+
+#[derive(Debug, PartialEq)]
+struct Manual([u32; 40]);
+
+impl<'de> serde::Deserialize<'de> for Manual {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_tuple(40, serde_arrays::ArrayVisitor::new())
+            .map(Manual)
+    }
+}
+
+#[test]
+fn array_visitor_in_manual_impl() {
+    let de: Manual = serde_json::from_str(&format!("{:?}", vec![3; 40])).unwrap();
+    assert_eq!(Manual([3; 40]), de);
+
+    let err = serde_json::from_str::<Manual>(&format!("{:?}", vec![3; 39])).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 39, expected an array of size 40"));
+}
+
+#[derive(serde::Deserialize, Debug, PartialEq)]
+struct Quad(#[serde(with = "serde_arrays")] [u32; 4]);
+
+#[test]
+fn deserialize_seq_or_index_keyed_map() {
+    let seq = [
+        Token::NewtypeStruct { name: "Quad" },
+        Token::Seq { len: Some(4) },
+        Token::U32(1),
+        Token::U32(2),
+        Token::U32(3),
+        Token::U32(4),
+        Token::SeqEnd,
+    ];
+    assert_de_tokens(&Quad([1, 2, 3, 4]), &seq);
+
+    let map = [
+        Token::NewtypeStruct { name: "Quad" },
+        Token::Map { len: Some(4) },
+        Token::Str("2"),
+        Token::U32(3),
+        Token::Str("0"),
+        Token::U32(1),
+        Token::U64(3),
+        Token::U32(4),
+        Token::Str("1"),
+        Token::U32(2),
+        Token::MapEnd,
+    ];
+    assert_de_tokens(&Quad([1, 2, 3, 4]), &map);
+}
+
+#[test]
+fn deserialize_index_keyed_map_needs_indexed_map_in_json() {
+    let err = serde_json::from_str::<Quad>(r#"{"0":1,"1":2,"2":3,"3":4}"#).unwrap_err();
+
+    assert!(err.to_string().starts_with("invalid type: map"));
+}
+
+#[test]
+fn deserialize_index_keyed_map_missing_index() {
+    assert_de_tokens_error::<Quad>(
+        &[
+            Token::NewtypeStruct { name: "Quad" },
+            Token::Map { len: Some(3) },
+            Token::Str("0"),
+            Token::U32(1),
+            Token::Str("1"),
+            Token::U32(2),
+            Token::Str("3"),
+            Token::U32(4),
+            Token::MapEnd,
+        ],
+        "missing index 2",
+    );
+}
+
+#[test]
+fn deserialize_index_keyed_map_out_of_range() {
+    assert_de_tokens_error::<Quad>(
+        &[
+            Token::NewtypeStruct { name: "Quad" },
+            Token::Map { len: Some(2) },
+            Token::Str("0"),
+            Token::U32(1),
+            Token::Str("4"),
+        ],
+        "index 4 is out of range for an array of size 4 with indices starting at 0",
+    );
+}
+
+/// A sequence of `len` ones that reports its length up front and counts the elements read
+struct HintedSeq<'a> {
+    len: usize,
+    read: &'a mut usize,
+}
+
+impl<'de, 'a> serde::de::SeqAccess<'de> for HintedSeq<'a> {
+    type Error = serde::de::value::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        if *self.read == self.len {
+            return Ok(None);
+        }
+        *self.read += 1;
+        seed.deserialize(serde::de::value::U32Deserializer::new(1))
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - *self.read)
+    }
+}
+
+impl<'de, 'a> serde::Deserializer<'de> for HintedSeq<'a> {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[test]
+fn deserialize_size_hint_mismatch_fails_early() {
+    let mut read = 0;
+    let err = serde_arrays::deserialize::<_, u32, 36>(HintedSeq {
+        len: 40,
+        read: &mut read,
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        format!("invalid length 40, expected {}", expected_array::<u32>(36)),
+        err.to_string()
+    );
+    assert_eq!(0, read);
+
+    let mut read = 0;
+    let err = serde_arrays::deserialize::<_, u32, 36>(HintedSeq {
+        len: 35,
+        read: &mut read,
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        format!("invalid length 35, expected {}", expected_array::<u32>(36)),
+        err.to_string()
+    );
+    assert_eq!(0, read);
+}
+
+#[test]
+fn deserialize_size_hint_match() {
+    let mut read = 0;
+    let arr = serde_arrays::deserialize::<_, u32, 36>(HintedSeq {
+        len: 36,
+        read: &mut read,
+    })
+    .unwrap();
+
+    assert_eq!([1; 36], arr);
+    assert_eq!(36, read);
+}
+
+/// A sequence of strings from a self-describing format that, like many relaxed parsers for e.g.
+/// JSON5, ignores the tuple hint, drives every value through `deserialize_any`, and doesn't know
+/// its length up front
+struct UntypedSeq<'a>(std::slice::Iter<'a, &'a str>);
+
+impl<'de, 'a> serde::de::SeqAccess<'de> for UntypedSeq<'a> {
+    type Error = serde::de::value::Error;
+
+    fn next_element_seed<S>(&mut self, seed: S) -> Result<Option<S::Value>, Self::Error>
+    where
+        S: serde::de::DeserializeSeed<'de>,
+    {
+        self.0
+            .next()
+            .map(|s| seed.deserialize(serde::de::value::StrDeserializer::new(s)))
+            .transpose()
+    }
+}
+
+impl<'de, 'a> serde::Deserializer<'de> for UntypedSeq<'a> {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[test]
+fn miri_deserialize_through_deserialize_any() {
+    let input = ["a", "bb", "ccc"];
+
+    let arr = serde_arrays::deserialize::<_, String, 3>(UntypedSeq(input.iter())).unwrap();
+    assert_eq!(input, arr);
+
+    let arr = serde_arrays::deserialize::<_, String, 1>(UntypedSeq(input[..1].iter())).unwrap();
+    assert_eq!(["a"], arr);
+
+    let err = serde_arrays::deserialize::<_, String, 4>(UntypedSeq(input.iter())).unwrap_err();
+    assert_eq!(
+        format!("invalid length 3, expected {}", expected_array::<String>(4)),
+        err.to_string()
+    );
+
+    let err = serde_arrays::deserialize::<_, String, 2>(UntypedSeq(input.iter())).unwrap_err();
+    assert_eq!(
+        format!("invalid length 3, expected {}", expected_array::<String>(2)),
+        err.to_string()
+    );
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Borrowed<'a> {
+    #[serde(borrow, with = "serde_arrays")]
+    arr: [&'a str; 3],
+}
+
+#[test]
+fn miri_deserialize_borrowed_strs() {
+    let json = String::from(r#"{"arr":["one","two","three"]}"#);
+    let obj: Borrowed = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(["one", "two", "three"], obj.arr);
+    // Each element points into the input rather than at a copy of it
+    for s in &obj.arr {
+        let offset = json.find(s).unwrap();
+        assert!(std::ptr::eq(s.as_ptr(), json[offset..].as_ptr()));
+    }
+}
+
+#[test]
+fn miri_deserialize_borrowed_strs_error_path() {
+    let json = String::from(r#"{"arr":["one","two"]}"#);
+    let err = serde_json::from_str::<Borrowed>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 2, expected an array of size 3"));
+
+    // Strings with escapes cannot be borrowed, which is an ordinary error
+    let json = String::from(r#"{"arr":["one","t\"wo","three"]}"#);
+    assert!(serde_json::from_str::<Borrowed>(&json).is_err());
+}
+
+#[test]
+fn deserialize_from_in_memory_seq() {
+    use serde::de::value::{Error, SeqDeserializer};
+
+    let values: Vec<u32> = (0..36).collect();
+    let arr: [u32; 36] =
+        serde_arrays::deserialize(SeqDeserializer::<_, Error>::new(values.clone().into_iter()))
+            .unwrap();
+    assert_eq!(values[..], arr[..]);
+
+    let err = serde_arrays::deserialize::<_, u32, 36>(SeqDeserializer::<_, Error>::new(
+        values[..35].iter().copied(),
+    ))
+    .unwrap_err();
+    assert_eq!(
+        format!("invalid length 35, expected {}", expected_array::<u32>(36)),
+        err.to_string()
+    );
+
+    let err = serde_arrays::deserialize::<_, u32, 36>(SeqDeserializer::<_, Error>::new(
+        values.iter().copied().chain(Some(36)),
+    ))
+    .unwrap_err();
+    assert_eq!(
+        format!("invalid length 37, expected {}", expected_array::<u32>(36)),
+        err.to_string()
+    );
+}
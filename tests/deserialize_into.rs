@@ -0,0 +1,100 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use bincode::Options;
+use serde_arrays::deserialize_into;
+
+fn json(values: &[u32]) -> String {
+    serde_json::to_string(values).unwrap()
+}
+
+fn read_into<const N: usize>(place: &mut [u32; N], json: &str) -> Result<(), serde_json::Error> {
+    deserialize_into(place, &mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn deserialize_into_overwrites_every_element() {
+    let mut buf = [0; 40];
+    let values: Vec<u32> = (0..40).collect();
+
+    read_into(&mut buf, &json(&values)).unwrap();
+    assert_eq!(&values[..], &buf[..]);
+
+    // The same buffer can be reused for the next message
+    let values: Vec<u32> = (100..140).collect();
+    read_into(&mut buf, &json(&values)).unwrap();
+    assert_eq!(&values[..], &buf[..]);
+}
+
+#[test]
+fn deserialize_into_bincode() {
+    let mut buf = [0u64; 36];
+    let b = bincode::serialize(&vec![9u64; 36]).unwrap();
+
+    // Skip the `Vec` length prefix, which an array doesn't have
+    deserialize_into(
+        &mut buf,
+        &mut bincode::Deserializer::from_slice(&b[8..], bincode::options().with_fixint_encoding()),
+    )
+    .unwrap();
+    assert_eq!([9; 36], buf);
+}
+
+#[test]
+fn deserialize_into_too_short_overwrites_prefix() {
+    let mut buf = [7; 40];
+    let values: Vec<u32> = (0..25).collect();
+
+    let err = read_into(&mut buf, &json(&values)).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 25, expected an array of size 40"));
+
+    // Elements 0..25 were overwritten, the rest are untouched
+    assert_eq!(&values[..], &buf[..25]);
+    assert_eq!([7; 15], buf[25..]);
+}
+
+#[test]
+fn deserialize_into_invalid_element_overwrites_prefix() {
+    let mut buf = [7; 5];
+
+    let err = read_into(&mut buf, r#"[1, 2, "three", 4, 5]"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with(r#"invalid type: string "three", expected u32"#));
+
+    assert_eq!([1, 2, 7, 7, 7], buf);
+}
+
+#[test]
+fn deserialize_into_too_long_overwrites_all() {
+    let mut buf = [7; 5];
+
+    let err = read_into(&mut buf, "[1, 2, 3, 4, 5, 6, 7]").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 7, expected an array of size 5"));
+
+    assert_eq!([1, 2, 3, 4, 5], buf);
+}
+
+#[test]
+fn miri_deserialize_into_drops_replaced() {
+    let mut buf = [
+        String::from("old"),
+        String::from("old"),
+        String::from("old"),
+    ];
+
+    let res = deserialize_into(
+        &mut buf,
+        &mut serde_json::Deserializer::from_str(r#"["a", "b"]"#),
+    );
+    assert!(res.is_err());
+    assert_eq!(["a", "b", "old"], buf);
+}
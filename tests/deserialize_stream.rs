@@ -0,0 +1,104 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::drops::{live, Counted};
+use std::convert::Infallible;
+
+fn sum_json<const N: usize>(json: &str) -> Result<u64, serde_json::Error> {
+    let mut de = serde_json::Deserializer::from_str(json);
+    let mut sum = 0;
+    serde_arrays::deserialize_stream::<_, u64, _, _, N>(&mut de, |_, n| {
+        sum += n;
+        Ok::<_, Infallible>(())
+    })?;
+    Ok(sum)
+}
+
+#[test]
+fn stream_sums_elements() {
+    let j = serde_json::to_string(&(1..=40).collect::<Vec<u64>>()).unwrap();
+
+    assert_eq!(sum_json::<40>(&j).unwrap(), 820);
+}
+
+#[test]
+fn stream_passes_indices_in_order() {
+    let b = bincode::serialize(&[5u8; 32]).unwrap();
+    let mut seen = Vec::new();
+    serde_arrays::deserialize_stream::<_, u8, _, _, 32>(
+        &mut bincode::Deserializer::from_slice(&b, bincode::options()),
+        |idx, val| {
+            seen.push((idx, val));
+            Ok::<_, Infallible>(())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(seen, (0..32).map(|i| (i, 5)).collect::<Vec<_>>());
+}
+
+#[test]
+fn stream_rejects_too_few() {
+    let err = sum_json::<40>("[1, 2, 3]").unwrap_err().to_string();
+
+    assert!(
+        err.starts_with("invalid length 3, expected an array of size 40"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn stream_rejects_too_many() {
+    let err = sum_json::<2>("[1, 2, 3]").unwrap_err().to_string();
+
+    assert!(
+        err.starts_with("invalid length 3, expected an array of size 2"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn stream_stops_on_callback_error() {
+    let mut de = serde_json::Deserializer::from_str("[1, 2, 3, 4]");
+    let mut calls = 0;
+    let err = serde_arrays::deserialize_stream::<_, u8, _, _, 4>(&mut de, |_, n| {
+        calls += 1;
+        if n == 2 {
+            Err("two is right out")
+        } else {
+            Ok(())
+        }
+    })
+    .unwrap_err()
+    .to_string();
+
+    assert!(err.starts_with("at index 1: two is right out"), "{}", err);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn stream_drops_every_element_on_early_termination() {
+    let mut de = serde_json::Deserializer::from_str("[1, 2, 3, 4, 5]");
+    let mut kept = Vec::new();
+    let res = serde_arrays::deserialize_stream::<_, Counted<u8>, _, _, 40>(&mut de, |idx, c| {
+        if idx < 3 {
+            kept.push(c);
+            Ok(())
+        } else {
+            Err("done")
+        }
+    });
+
+    assert!(res.is_err());
+    assert_eq!(kept.len(), 3);
+    assert_eq!(live(), 3);
+    drop(kept);
+    assert_eq!(live(), 0);
+}
@@ -0,0 +1,135 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+enum Message {
+    Empty,
+    Bytes(#[serde(with = "serde_arrays")] [u8; 40]),
+    Words(#[serde(with = "serde_arrays")] [u32; 36]),
+    Pair(
+        #[serde(with = "serde_arrays")] [u16; 33],
+        #[serde(with = "serde_arrays")] [u16; 34],
+    ),
+    Named {
+        id: u8,
+        #[serde(with = "serde_arrays")]
+        data: [u8; 64],
+    },
+}
+
+fn messages() -> Vec<Message> {
+    vec![
+        Message::Empty,
+        Message::Bytes([1; 40]),
+        Message::Words([2; 36]),
+        Message::Pair([3; 33], [4; 34]),
+        Message::Named {
+            id: 5,
+            data: [6; 64],
+        },
+    ]
+}
+
+#[test]
+fn enum_variants_json() {
+    for msg in messages() {
+        let j = serde_json::to_string(&msg).unwrap();
+        assert_eq!(msg, serde_json::from_str(&j).unwrap());
+    }
+
+    let j = serde_json::to_string(&Message::Named {
+        id: 5,
+        data: [6; 64],
+    })
+    .unwrap();
+    assert!(j.starts_with(r#"{"Named":{"id":5,"data":[6,6,"#));
+}
+
+#[test]
+fn enum_variants_bincode() {
+    for msg in messages() {
+        let b = bincode::serialize(&msg).unwrap();
+        assert_eq!(msg, bincode::deserialize(&b).unwrap());
+    }
+
+    // Variant index, then the array with no length prefix
+    let b = bincode::serialize(&Message::Bytes([1; 40])).unwrap();
+    assert_eq!(4 + 40, b.len());
+}
+
+#[test]
+fn enum_variants_yaml() {
+    for msg in messages() {
+        let y = serde_yaml::to_string(&msg).unwrap();
+        assert_eq!(msg, serde_yaml::from_str(&y).unwrap());
+    }
+}
+
+#[test]
+#[should_panic(expected = "invalid length 39, expected an array of size 40")]
+fn enum_variant_wrong_length() {
+    let json = format!(r#"{{"Bytes":{:?}}}"#, vec![1; 39]);
+    serde_json::from_str::<Message>(&json).unwrap();
+}
+
+// Internally tagged and untagged enums buffer their content before deserializing the variant, so
+// the array is read back from Serde's own buffered content rather than from the format.
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(tag = "type")]
+enum Tagged {
+    Bytes {
+        #[serde(with = "serde_arrays")]
+        data: [u8; 40],
+    },
+    Words {
+        #[serde(with = "serde_arrays")]
+        data: [u32; 36],
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(untagged)]
+enum Untagged {
+    Bytes(#[serde(with = "serde_arrays")] [u8; 40]),
+    Words(#[serde(with = "serde_arrays")] [u32; 36]),
+}
+
+#[test]
+fn enum_internally_tagged() {
+    for msg in [
+        Tagged::Bytes { data: [1; 40] },
+        Tagged::Words { data: [2; 36] },
+    ] {
+        let j = serde_json::to_string(&msg).unwrap();
+        assert_eq!(msg, serde_json::from_str(&j).unwrap());
+    }
+
+    let err =
+        serde_json::from_str::<Tagged>(&format!(r#"{{"type":"Bytes","data":{:?}}}"#, vec![1; 41]))
+            .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 41, expected an array of size 40"));
+}
+
+#[test]
+fn enum_untagged() {
+    for msg in [Untagged::Bytes([1; 40]), Untagged::Words([2; 36])] {
+        let j = serde_json::to_string(&msg).unwrap();
+        assert_eq!(msg, serde_json::from_str(&j).unwrap());
+    }
+
+    // A sequence of 36 elements only fits the second variant
+    let j = serde_json::to_string(&vec![300; 36]).unwrap();
+    assert_eq!(
+        Untagged::Words([300; 36]),
+        serde_json::from_str(&j).unwrap()
+    );
+}
@@ -0,0 +1,50 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::recorder::{record, Event};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct FixedList {
+    #[serde(with = "serde_arrays::fixed_size_list")]
+    arr: [u32; 36],
+}
+
+#[test]
+fn serialize_as_sized_seq() {
+    let obj = FixedList { arr: [1; 36] };
+
+    let events = record(&obj);
+
+    assert_eq!(Event::Struct("FixedList", 1), events[0]);
+    assert_eq!(Event::Field("arr"), events[1]);
+    assert_eq!(Event::Seq(Some(36)), events[2]);
+    assert_eq!(36, events.iter().filter(|e| **e == Event::U64(1)).count());
+    assert!(!events.iter().any(|e| matches!(e, Event::Tuple(_))));
+}
+
+#[test]
+fn fixed_size_list_round_trip() {
+    let mut arr = [0; 36];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = i as u32;
+    }
+    let obj = FixedList { arr };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: FixedList = serde_json::from_str(&j).unwrap();
+
+    assert_eq!(obj, de);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 35, expected an array of size 36")]
+fn deserialize_wrong_size_list() {
+    let json = format!("{{\"arr\":{:?}}}", [1; 35]);
+    let _: FixedList = serde_json::from_str(&json).unwrap();
+}
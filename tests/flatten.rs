@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! `#[serde(flatten)]` buffers the struct's fields as Serde's internal content before handing them
+//! on, so arrays in and around flattened structs are deserialized from that buffer rather than
+//! directly from the format
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Header {
+    version: u8,
+    #[serde(with = "serde_arrays")]
+    checksum: [u8; 36],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Packet {
+    #[serde(flatten)]
+    header: Header,
+    #[serde(with = "serde_arrays")]
+    payload: [u32; 36],
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Nested {
+    #[serde(flatten)]
+    header: Header,
+    #[serde(with = "serde_arrays::nested")]
+    blocks: Vec<[u16; 33]>,
+}
+
+fn packet() -> Packet {
+    let mut payload = [0; 36];
+    for (i, elem) in payload.iter_mut().enumerate() {
+        *elem = i as u32 * 3;
+    }
+    Packet {
+        header: Header {
+            version: 2,
+            checksum: [0xAB; 36],
+        },
+        payload,
+    }
+}
+
+#[test]
+fn flatten_round_trip_json() {
+    let obj = packet();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"version":2,"checksum":[171,"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn flatten_round_trip_yaml() {
+    let obj = packet();
+
+    let y = serde_yaml::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_yaml::from_str(&y).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn flatten_nested_round_trip_json() {
+    let obj = Nested {
+        header: Header {
+            version: 1,
+            checksum: [7; 36],
+        },
+        blocks: vec![[1; 33], [2; 33]],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn flatten_length_errors_still_reported() {
+    let j = serde_json::to_string(&packet()).unwrap();
+
+    // Drop one element from the flattened struct's array
+    let short = j.replacen("[171,", "[", 1);
+    let err = serde_json::from_str::<Packet>(&short).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 35, expected an array of size 36"));
+
+    // And add one to the outer struct's array
+    let long = j.replacen("\"payload\":[", "\"payload\":[0,", 1);
+    let err = serde_json::from_str::<Packet>(&long).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 37, expected an array of size 36"));
+}
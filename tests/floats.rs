@@ -0,0 +1,104 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Samples {
+    #[serde(with = "serde_arrays::floats")]
+    values: [f64; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Samples32 {
+    #[serde(with = "serde_arrays::floats")]
+    values: [f32; 36],
+}
+
+fn assert_same(expected: &[f64], actual: &[f64]) {
+    assert_eq!(expected.len(), actual.len());
+    for (e, a) in expected.iter().zip(actual) {
+        assert_eq!(e.to_bits(), a.to_bits(), "{} != {}", e, a);
+    }
+}
+
+#[test]
+fn floats_json_round_trip_non_finite() {
+    let obj = Samples {
+        values: [f64::NAN, f64::INFINITY, -2.5, f64::NEG_INFINITY],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"values":["nan","inf",-2.5,"-inf"]}"#, j);
+
+    let de: Samples = serde_json::from_str(&j).unwrap();
+    assert_same(&obj.values, &de.values);
+}
+
+#[test]
+fn floats_json_accepts_integers() {
+    let de: Samples = serde_json::from_str(r#"{"values":[1,-2,3.5,"inf"]}"#).unwrap();
+    assert_same(&[1.0, -2.0, 3.5, f64::INFINITY], &de.values);
+}
+
+#[test]
+fn floats_f32_finite_values_are_unchanged() {
+    let mut values = [0.1f32; 36];
+    values[7] = f32::NAN;
+    values[35] = f32::NEG_INFINITY;
+
+    let j = serde_json::to_string(&Samples32 { values }).unwrap();
+    assert!(j.starts_with(r#"{"values":[0.1,0.1,"#));
+    assert!(j.ends_with(r#",0.1,"-inf"]}"#));
+
+    let de: Samples32 = serde_json::from_str(&j).unwrap();
+    assert_eq!(0.1, de.values[0]);
+    assert!(de.values[7].is_nan());
+    assert_eq!(f32::NEG_INFINITY, de.values[35]);
+}
+
+#[test]
+fn floats_binary_is_unchanged() {
+    let obj = Samples {
+        values: [f64::NAN, f64::INFINITY, -2.5, f64::NEG_INFINITY],
+    };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(4 * 8, b.len());
+
+    let de: Samples = bincode::deserialize(&b).unwrap();
+    assert_same(&obj.values, &de.values);
+}
+
+#[test]
+fn floats_unknown_string_is_error() {
+    let err = serde_json::from_str::<Samples>(r#"{"values":[1,"NaN",3,4]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with(r#"invalid value: string "NaN", expected a number, "nan", "inf", or "-inf""#));
+}
+
+#[test]
+fn floats_wrong_length_is_error() {
+    assert!(serde_json::from_str::<Samples>(r#"{"values":[1,2,3]}"#).is_err());
+}
+
+#[test]
+fn plain_serde_arrays_cannot_round_trip_non_finite() {
+    #[derive(Serialize, Deserialize, Debug)]
+    struct Plain {
+        #[serde(with = "serde_arrays")]
+        values: [f64; 4],
+    }
+
+    let j = serde_json::to_string(&Plain {
+        values: [f64::NAN, 0.0, 0.0, 0.0],
+    })
+    .unwrap();
+    assert_eq!(r#"{"values":[null,0.0,0.0,0.0]}"#, j);
+    assert!(serde_json::from_str::<Plain>(&j).is_err());
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Packet {
+    #[serde(with = "serde_arrays::framed")]
+    words: [u32; 4],
+}
+
+#[test]
+fn framed_json_round_trip() {
+    let obj = Packet {
+        words: [10, 20, 30, 40],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"words":[4,10,20,30,40]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn framed_bincode_round_trip() {
+    let obj = Packet {
+        words: [10, 20, 30, 40],
+    };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 4 * 4, b.len());
+    assert_eq!(&4u64.to_le_bytes(), &b[..8]);
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn framed_wrong_declared_length() {
+    let err = serde_json::from_str::<Packet>(r#"{"words":[5,10,20,30,40]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("declared length 5 does not match an array of size 4"));
+}
+
+#[test]
+fn framed_declared_length_disagrees_with_elements() {
+    let err = serde_json::from_str::<Packet>(r#"{"words":[4,10,20,30]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of size 4"));
+    assert!(err.to_string().contains("preceded by its length"));
+
+    let err = serde_json::from_str::<Packet>(r#"{"words":[4,10,20,30,40,50]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 5, expected an array of size 4"));
+}
+
+#[test]
+fn framed_empty_is_error() {
+    let err = serde_json::from_str::<Packet>(r#"{"words":[]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 0, expected an array of size 4"));
+}
+
+#[test]
+fn framed_large_array() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Large {
+        #[serde(with = "serde_arrays::framed")]
+        arr: [u8; 40],
+    }
+
+    let obj = Large { arr: [3; 40] };
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"arr":[40,3,3,"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
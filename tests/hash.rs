@@ -0,0 +1,50 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde_arrays::{stable_hash, StableHasher};
+use std::hash::{Hash, Hasher};
+
+#[test]
+fn stable_hash_is_reproducible() {
+    let arr = [3u64, 1, 4, 1, 5, 9, 2, 6];
+
+    assert_eq!(stable_hash(&arr), stable_hash(&arr.clone()));
+}
+
+#[test]
+fn stable_hash_across_hasher_instances() {
+    let arr = ["alpha", "beta", "gamma"];
+
+    let mut first = StableHasher::default();
+    let mut second = StableHasher::default();
+    for item in &arr {
+        item.hash(&mut first);
+        item.hash(&mut second);
+    }
+
+    assert_eq!(first.finish(), second.finish());
+    assert_eq!(first.finish(), stable_hash(&arr));
+}
+
+#[test]
+fn stable_hash_detects_changes() {
+    let mut arr = [0u32; 36];
+    let before = stable_hash(&arr);
+
+    arr[35] = 1;
+
+    assert_ne!(before, stable_hash(&arr));
+}
+
+#[test]
+fn stable_hash_known_values() {
+    // These values are part of the stability guarantee and must never change
+    assert_eq!(0xcbf2_9ce4_8422_2325, stable_hash::<u8, 0>(&[]));
+    assert_eq!(0xaf63_bd4c_8601_b7df, stable_hash(&[0u8]));
+    assert_eq!(stable_hash(&[1u32, 2]), stable_hash(&[1i32, 2]));
+    assert_eq!(stable_hash(&[7usize]), stable_hash(&[7u64]));
+}
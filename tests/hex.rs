@@ -0,0 +1,95 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Hash {
+    #[serde(with = "serde_arrays::hex")]
+    digest: [u8; 32],
+}
+
+fn hash() -> Hash {
+    let mut digest = [0; 32];
+    for (i, byte) in digest.iter_mut().enumerate() {
+        *byte = (i * 8 + 1) as u8;
+    }
+    Hash { digest }
+}
+
+#[test]
+fn hex_json_round_trip() {
+    let obj = hash();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        r#"{"digest":"0109111921293139414951596169717981899199a1a9b1b9c1c9d1d9e1e9f1f9"}"#,
+        j
+    );
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+    assert_eq!(
+        obj,
+        serde_json::from_str(&j.to_uppercase().replace("DIGEST", "digest")).unwrap()
+    );
+}
+
+#[test]
+fn hex_bincode_round_trip() {
+    let obj = hash();
+
+    let b = bincode::serialize(&obj).unwrap();
+    // Length prefix then the raw bytes, as for `serde_arrays::bytes`
+    assert_eq!(8 + 32, b.len());
+    assert_eq!(&obj.digest[..], &b[8..]);
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn hex_odd_length() {
+    let json = format!(r#"{{"digest":"{}"}}"#, "a".repeat(63));
+    let err = serde_json::from_str::<Hash>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid hex string: odd number of hex digits (63)"));
+}
+
+#[test]
+fn hex_invalid_digit() {
+    let json = format!(r#"{{"digest":"{}zz"}}"#, "a".repeat(62));
+    let err = serde_json::from_str::<Hash>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid hex string: invalid hex digit 'z'"));
+}
+
+#[test]
+fn hex_wrong_byte_count() {
+    let err = serde_json::from_str::<Hash>(r#"{"digest":"0011"}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid hex string: expected 32 bytes, found 2"));
+
+    let mut b = bincode::serialize(&hash()).unwrap();
+    b[0] = 31;
+    b.pop();
+    let err = bincode::deserialize::<Hash>(&b).unwrap_err();
+    assert_eq!(
+        "invalid length 31, expected a byte string or array of 32 bytes",
+        err.to_string()
+    );
+}
+
+#[test]
+fn hex_rejects_array_in_json() {
+    let json = serde_json::to_string(&[0u8; 32]).unwrap();
+    let err = serde_json::from_str::<Hash>(&format!(r#"{{"digest":{}}}"#, json)).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid type: sequence, expected a hex string of 32 bytes"));
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+use common::recorder::{record_binary, Event};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Table {
+    #[serde(with = "serde_arrays::hex_key_map")]
+    map: BTreeMap<[u8; 4], [u32; 36]>,
+}
+
+fn table() -> Table {
+    let mut map = BTreeMap::new();
+    map.insert([0xde, 0xad, 0xbe, 0xef], [1; 36]);
+    map.insert([0x00, 0x01, 0x0a, 0xff], [2; 36]);
+    Table { map }
+}
+
+#[test]
+fn hex_keys_round_trip() {
+    let obj = table();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"map":{"00010aff":[2,2,"#));
+    assert!(j.contains(r#"],"deadbeef":[1,1,"#));
+
+    let de: Table = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn hex_keys_accept_uppercase() {
+    let j = format!(r#"{{"map":{{"DEADBEEF":{:?}}}}}"#, [3; 36]);
+
+    let de: Table = serde_json::from_str(&j).unwrap();
+    assert_eq!(Some(&[3; 36]), de.map.get(&[0xde, 0xad, 0xbe, 0xef]));
+}
+
+#[test]
+fn binary_keys_are_arrays() {
+    let events = record_binary(&table());
+
+    assert_eq!(Event::Map(Some(2)), events[2]);
+    assert_eq!(Event::Tuple(4), events[3]);
+    assert_eq!(Event::U64(0x00), events[4]);
+    assert_eq!(Event::U64(0xff), events[7]);
+}
+
+fn parse_key(key: &str) -> String {
+    let j = format!(r#"{{"map":{{"{}":{:?}}}}}"#, key, [0; 36]);
+    serde_json::from_str::<Table>(&j).unwrap_err().to_string()
+}
+
+#[test]
+fn invalid_hex_keys() {
+    assert!(parse_key("deadbee").starts_with("invalid hex key: odd number of hex digits (7)"));
+    assert!(parse_key("deadbeefff").starts_with("invalid hex key: expected 4 bytes, found 5"));
+    assert!(parse_key("deadbexf").starts_with("invalid hex key: invalid hex digit 'x'"));
+}
+
+#[test]
+fn invalid_hex_key_is_not_echoed() {
+    assert!(!parse_key("secretsecret").contains("secret"));
+}
+
+#[test]
+#[should_panic(expected = "expected an array of size 36")]
+fn wrong_value_length() {
+    let _: Table = serde_json::from_str(r#"{"map":{"deadbeef":[1,2,3]}}"#).unwrap();
+}
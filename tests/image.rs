@@ -0,0 +1,87 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+struct Rgba(u8, u8, u8, u8);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Image {
+    #[serde(with = "serde_arrays::image")]
+    pixels: [[Rgba; 256]; 5],
+}
+
+fn image() -> Image {
+    let mut pixels = [[Rgba(0, 0, 0, 255); 256]; 5];
+    for (y, row) in pixels.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = Rgba(x as u8, y as u8, 0, 255);
+        }
+    }
+    Image { pixels }
+}
+
+fn rows_json(lengths: &[usize]) -> String {
+    let rows: Vec<Vec<[u8; 4]>> = lengths.iter().map(|&len| vec![[0; 4]; len]).collect();
+    format!(r#"{{"pixels":{}}}"#, serde_json::to_string(&rows).unwrap())
+}
+
+#[test]
+fn image_round_trip() {
+    let obj = image();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(5 * 256 * 4, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn image_short_row_names_row() {
+    let err = serde_json::from_str::<Image>(&rows_json(&[256, 256, 256, 200, 256])).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("row 3: invalid length 200, expected an array of size 256"));
+}
+
+#[test]
+fn image_long_row_names_row() {
+    let err = serde_json::from_str::<Image>(&rows_json(&[257, 256, 256, 256, 256])).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("row 0: invalid length 257, expected an array of size 256"));
+}
+
+#[test]
+fn image_invalid_pixel_names_row() {
+    let mut rows = vec![vec![[0; 4]; 256]; 5];
+    rows[4][10] = [1, 2, 3, 400];
+    let j = format!(r#"{{"pixels":{}}}"#, serde_json::to_string(&rows).unwrap());
+
+    let err = serde_json::from_str::<Image>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("row 4: invalid value: integer `400`"));
+}
+
+#[test]
+fn image_wrong_row_count() {
+    let err = serde_json::from_str::<Image>(&rows_json(&[256, 256, 256, 256])).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 4, expected an image of 5 rows of 256 pixels"));
+
+    let err = serde_json::from_str::<Image>(&rows_json(&[256; 6])).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 6, expected an image of 5 rows of 256 pixels"));
+}
@@ -0,0 +1,106 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct ZeroBased {
+    #[serde(with = "serde_arrays::indexed_map")]
+    arr: [u32; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct OneBased {
+    #[serde(with = "serde_arrays::indexed_map::IndexedMap::<1>")]
+    arr: [u32; 4],
+}
+
+#[test]
+fn zero_and_one_based_agree() {
+    let zero: ZeroBased = serde_json::from_str(r#"{"arr":{"0":5,"1":6,"2":7,"3":8}}"#).unwrap();
+    let one: OneBased = serde_json::from_str(r#"{"arr":{"1":5,"2":6,"3":7,"4":8}}"#).unwrap();
+
+    assert_eq!([5, 6, 7, 8], zero.arr);
+    assert_eq!(zero.arr, one.arr);
+}
+
+#[test]
+fn one_based_serialize() {
+    let j = serde_json::to_string(&OneBased { arr: [5, 6, 7, 8] }).unwrap();
+
+    assert_eq!(r#"{"arr":{"1":5,"2":6,"3":7,"4":8}}"#, j);
+}
+
+#[test]
+fn one_based_rejects_zero() {
+    let err = serde_json::from_str::<OneBased>(r#"{"arr":{"0":5,"1":6,"2":7,"3":8}}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err
+        .starts_with("index 0 is out of range for an array of size 4 with indices starting at 1"));
+}
+
+#[test]
+fn one_based_rejects_past_end() {
+    let err = serde_json::from_str::<OneBased>(r#"{"arr":{"1":5,"2":6,"3":7,"5":8}}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err
+        .starts_with("index 5 is out of range for an array of size 4 with indices starting at 1"));
+}
+
+#[test]
+fn zero_based_rejects_past_end() {
+    let err = serde_json::from_str::<ZeroBased>(r#"{"arr":{"1":5,"2":6,"3":7,"4":8}}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err
+        .starts_with("index 4 is out of range for an array of size 4 with indices starting at 0"));
+}
+
+#[test]
+fn zero_based_round_trip() {
+    let obj = ZeroBased { arr: [5, 6, 7, 8] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":{"0":5,"1":6,"2":7,"3":8}}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let y = serde_yaml::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_yaml::from_str(&y).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn zero_based_any_order() {
+    let obj: ZeroBased = serde_json::from_str(r#"{"arr":{"3":8,"1":6,"0":5,"2":7}}"#).unwrap();
+
+    assert_eq!([5, 6, 7, 8], obj.arr);
+}
+
+#[test]
+fn zero_based_rejects_missing_index() {
+    let err = serde_json::from_str::<ZeroBased>(r#"{"arr":{"0":5,"1":6,"3":8}}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("missing index 2"));
+}
+
+#[test]
+fn zero_based_rejects_duplicate_index() {
+    let err = serde_json::from_str::<ZeroBased>(r#"{"arr":{"0":5,"1":6,"1":7,"3":8}}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("duplicate index 1"));
+}
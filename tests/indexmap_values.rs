@@ -0,0 +1,63 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "indexmap")]
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Config {
+    #[serde(with = "serde_arrays::indexmap_values")]
+    tables: IndexMap<String, [u32; 36]>,
+}
+
+fn config() -> Config {
+    let mut tables = IndexMap::new();
+    for (i, name) in ["zulu", "alpha", "mike", "bravo"].iter().enumerate() {
+        tables.insert(name.to_string(), [i as u32; 36]);
+    }
+    Config { tables }
+}
+
+#[test]
+fn indexmap_values_round_trip() {
+    let obj = config();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Config = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Config = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn indexmap_values_preserves_order() {
+    let j = serde_json::to_string(&config()).unwrap();
+    let de: Config = serde_json::from_str(&j).unwrap();
+
+    assert_eq!(
+        vec!["zulu", "alpha", "mike", "bravo"],
+        de.tables.keys().collect::<Vec<_>>()
+    );
+    assert_eq!(j, serde_json::to_string(&de).unwrap());
+
+    let b = bincode::serialize(&config()).unwrap();
+    let de: Config = bincode::deserialize(&b).unwrap();
+    assert_eq!(b, bincode::serialize(&de).unwrap());
+}
+
+#[test]
+fn indexmap_values_error_names_key() {
+    let err = serde_json::from_str::<Config>(r#"{"tables":{"mike":[1,2]}}"#).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with(r#"for key "mike": invalid length 2, expected an array of size 36"#));
+}
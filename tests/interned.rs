@@ -0,0 +1,76 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "rc")]
+
+use serde::{Deserialize, Serialize};
+use std::{rc::Rc, sync::Arc};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Events {
+    #[serde(with = "serde_arrays::interned")]
+    sources: Vec<Rc<[u8; 16]>>,
+}
+
+#[test]
+fn interned_duplicates_share_a_pointer() {
+    let obj = Events {
+        sources: vec![Rc::new([7; 16]), Rc::new([7; 16]), Rc::new([7; 16])],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Events = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    assert!(Rc::ptr_eq(&de.sources[0], &de.sources[1]));
+    assert!(Rc::ptr_eq(&de.sources[0], &de.sources[2]));
+    // The three entries, and nothing else, hold the one allocation
+    assert_eq!(3, Rc::strong_count(&de.sources[0]));
+}
+
+#[test]
+fn interned_distinct_values_are_separate() {
+    let obj = Events {
+        sources: vec![Rc::new([1; 16]), Rc::new([2; 16]), Rc::new([1; 16])],
+    };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 3 * 16, b.len());
+
+    let de: Events = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+    assert!(Rc::ptr_eq(&de.sources[0], &de.sources[2]));
+    assert!(!Rc::ptr_eq(&de.sources[0], &de.sources[1]));
+    assert_eq!(1, Rc::strong_count(&de.sources[1]));
+}
+
+#[test]
+fn interned_arc() {
+    #[derive(Serialize, Deserialize)]
+    struct Shared {
+        #[serde(with = "serde_arrays::interned")]
+        ids: Vec<Arc<[u32; 36]>>,
+    }
+
+    let j = serde_json::to_string(&Shared {
+        ids: (0..4).map(|_| Arc::new([5; 36])).collect(),
+    })
+    .unwrap();
+    let de: Shared = serde_json::from_str(&j).unwrap();
+    assert_eq!(4, de.ids.len());
+    assert!(de.ids.iter().all(|id| Arc::ptr_eq(id, &de.ids[0])));
+}
+
+#[test]
+fn interned_malformed_array_names_index() {
+    let j = format!(r#"{{"sources":[{:?},[1,2]]}}"#, [1u8; 16]);
+
+    let err = serde_json::from_str::<Events>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 1: invalid length 2, expected an array of size 16"));
+}
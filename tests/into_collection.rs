@@ -0,0 +1,62 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use arrayvec::ArrayVec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct AsVec {
+    #[serde(with = "serde_arrays::into_collection::IntoCollection::<36>")]
+    arr: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct AsArrayVec {
+    #[serde(with = "serde_arrays::into_collection::IntoCollection::<36>")]
+    arr: ArrayVec<u32, 64>,
+}
+
+fn input(len: u32) -> String {
+    format!(r#"{{"arr":{:?}}}"#, (0..len).collect::<Vec<_>>())
+}
+
+#[test]
+fn collect_into_vec_and_arrayvec() {
+    let v: AsVec = serde_json::from_str(&input(36)).unwrap();
+    let av: AsArrayVec = serde_json::from_str(&input(36)).unwrap();
+
+    assert_eq!((0..36).collect::<Vec<_>>(), v.arr);
+    assert_eq!(v.arr.as_slice(), av.arr.as_slice());
+}
+
+#[test]
+fn collection_round_trip() {
+    let obj = AsArrayVec {
+        arr: (100..136).collect(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "invalid length 35, expected an array of size 36")]
+fn collection_too_short() {
+    let _: AsVec = serde_json::from_str(&input(35)).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 37, expected an array of size 36")]
+fn collection_too_long() {
+    let _: AsArrayVec = serde_json::from_str(&input(37)).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "expected a collection of 36 elements, found 3")]
+fn serialize_wrong_size_collection() {
+    serde_json::to_string(&AsVec { arr: vec![1, 2, 3] }).unwrap();
+}
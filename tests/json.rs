@@ -0,0 +1,44 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "serde_json")]
+
+#[test]
+fn json_bare_array_round_trip() {
+    let mut data = [0u32; 36];
+    for (i, item) in data.iter_mut().enumerate() {
+        *item = i as u32 * 3;
+    }
+
+    let j = serde_arrays::to_json_string(&data).unwrap();
+    assert_eq!(serde_json::to_string(&data.to_vec()).unwrap(), j);
+
+    let back: [u32; 36] = serde_arrays::from_json_str(&j).unwrap();
+    assert_eq!(data, back);
+}
+
+#[test]
+fn json_nested_array_to_string() {
+    let data = [[1u8, 2], [3, 4], [5, 6]];
+
+    // `[[u8; 2]; 3]` is both a nested array and an array of arrays, so pick one
+    let j = serde_arrays::to_json_string::<_, u8, 2>(&data).unwrap();
+    assert_eq!("[[1,2],[3,4],[5,6]]", j);
+}
+
+#[test]
+fn json_wrong_length_is_error() {
+    let res = serde_arrays::from_json_str::<u32, 36>("[1,2,3]");
+    assert!(res.is_err());
+}
+
+#[test]
+fn json_trailing_characters_are_error() {
+    let j = serde_arrays::to_json_string(&[1u32; 36]).unwrap();
+    let res = serde_arrays::from_json_str::<u32, 36>(&format!("{} 1", j));
+    assert!(res.is_err());
+}
@@ -0,0 +1,126 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// How wrong lengths are caught across formats, for the tuple (`serde_arrays`) and sequence
+// (`serde_arrays::seq`) representations; see the table in the `seq` module docs
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tuple<const N: usize> {
+    #[serde(with = "serde_arrays")]
+    arr: [u32; N],
+    next: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Seq<const N: usize> {
+    #[serde(with = "serde_arrays::seq")]
+    arr: [u32; N],
+    next: u32,
+}
+
+const NEXT: u32 = 0xFFFF_FFFF;
+
+fn tuple<const N: usize>() -> Tuple<N> {
+    Tuple {
+        arr: [1; N],
+        next: NEXT,
+    }
+}
+
+fn seq<const N: usize>() -> Seq<N> {
+    Seq {
+        arr: [1; N],
+        next: NEXT,
+    }
+}
+
+fn assert_invalid_length<E: std::fmt::Display>(err: E, found: usize) {
+    let msg = format!("invalid length {}, expected an array of size 4", found);
+    assert!(err.to_string().contains(&msg), "{}", err);
+}
+
+#[test]
+fn json_tuple_and_seq_check_length() {
+    for found in [3, 5] {
+        let json = if found == 3 {
+            serde_json::to_string(&tuple::<3>()).unwrap()
+        } else {
+            serde_json::to_string(&tuple::<5>()).unwrap()
+        };
+        assert_invalid_length(serde_json::from_str::<Tuple<4>>(&json).unwrap_err(), found);
+        assert_invalid_length(serde_json::from_str::<Seq<4>>(&json).unwrap_err(), found);
+    }
+}
+
+#[test]
+fn messagepack_tuple_and_seq_check_length() {
+    // MessagePack frames tuples and sequences alike as arrays with a length header
+    for found in [3, 5] {
+        let (t, s) = if found == 3 {
+            (
+                rmp_serde::to_vec(&tuple::<3>()),
+                rmp_serde::to_vec(&seq::<3>()),
+            )
+        } else {
+            (
+                rmp_serde::to_vec(&tuple::<5>()),
+                rmp_serde::to_vec(&seq::<5>()),
+            )
+        };
+        let (t, s) = (t.unwrap(), s.unwrap());
+        assert_eq!(t, s);
+
+        assert_invalid_length(rmp_serde::from_slice::<Tuple<4>>(&t).unwrap_err(), found);
+        assert_invalid_length(rmp_serde::from_slice::<Seq<4>>(&s).unwrap_err(), found);
+    }
+}
+
+#[test]
+fn bincode_seq_checks_length() {
+    let short = bincode::serialize(&seq::<3>()).unwrap();
+    assert_invalid_length(bincode::deserialize::<Seq<4>>(&short).unwrap_err(), 3);
+
+    let long = bincode::serialize(&seq::<5>()).unwrap();
+    assert_invalid_length(bincode::deserialize::<Seq<4>>(&long).unwrap_err(), 5);
+}
+
+#[test]
+fn bincode_tuple_trusts_the_type() {
+    // Tuples carry no length, so bincode reads exactly 4 elements whatever was written: too few
+    // runs out of input, while too many are misread as whatever follows
+    let short = bincode::serialize(&tuple::<3>()).unwrap();
+    assert!(bincode::deserialize::<Tuple<4>>(&short).is_err());
+
+    let long = bincode::serialize(&tuple::<5>()).unwrap();
+    let misread = bincode::deserialize::<Tuple<4>>(&long).unwrap();
+    assert_eq!([1; 4], misread.arr);
+    assert_eq!(1, misread.next);
+}
+
+#[test]
+fn truncated_input_is_an_error() {
+    let t = bincode::serialize(&tuple::<4>()).unwrap();
+    let s = bincode::serialize(&seq::<4>()).unwrap();
+    for len in 0..t.len() {
+        assert!(bincode::deserialize::<Tuple<4>>(&t[..len]).is_err());
+    }
+    for len in 0..s.len() {
+        assert!(bincode::deserialize::<Seq<4>>(&s[..len]).is_err());
+    }
+
+    let m = rmp_serde::to_vec(&tuple::<4>()).unwrap();
+    for len in 0..m.len() {
+        assert!(rmp_serde::from_slice::<Tuple<4>>(&m[..len]).is_err());
+    }
+
+    let json = serde_json::to_string(&tuple::<4>()).unwrap();
+    for len in 0..json.len() {
+        assert!(serde_json::from_str::<Tuple<4>>(&json[..len]).is_err());
+    }
+}
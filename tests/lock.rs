@@ -0,0 +1,99 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, RwLock};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Shared {
+    #[serde(with = "serde_arrays::lock")]
+    mutex: Mutex<[u32; 16]>,
+    #[serde(with = "serde_arrays::lock")]
+    rw: RwLock<[u32; 16]>,
+}
+
+fn shared() -> Shared {
+    let mut arr = [0; 16];
+    for (i, elem) in arr.iter_mut().enumerate() {
+        *elem = i as u32 * 10;
+    }
+    Shared {
+        mutex: Mutex::new(arr),
+        rw: RwLock::new([7; 16]),
+    }
+}
+
+#[test]
+fn lock_round_trip() {
+    let obj = shared();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        format!(
+            r#"{{"mutex":{},"rw":{}}}"#,
+            serde_json::to_string(&*obj.mutex.lock().unwrap()).unwrap(),
+            serde_json::to_string(&*obj.rw.read().unwrap()).unwrap(),
+        ),
+        j
+    );
+    let de: Shared = serde_json::from_str(&j).unwrap();
+    assert_eq!(*obj.mutex.lock().unwrap(), *de.mutex.lock().unwrap());
+    assert_eq!(*obj.rw.read().unwrap(), *de.rw.read().unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(2 * 16 * 4, b.len());
+    let de: Shared = bincode::deserialize(&b).unwrap();
+    assert_eq!(*obj.mutex.lock().unwrap(), *de.mutex.lock().unwrap());
+    assert_eq!(*obj.rw.read().unwrap(), *de.rw.read().unwrap());
+}
+
+#[test]
+fn lock_wrong_length() {
+    let err = serde_json::from_str::<Shared>(r#"{"mutex":[1,2,3],"rw":[]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of size 16"));
+}
+
+/// Poison a lock by panicking in another thread while `hold` holds it
+fn poison<T: Send + Sync + 'static>(obj: std::sync::Arc<T>, hold: fn(&T)) {
+    let _ = std::thread::spawn(move || hold(&obj)).join();
+}
+
+#[test]
+fn lock_poisoned_mutex_is_an_error() {
+    let obj = std::sync::Arc::new(shared());
+    poison(obj.clone(), |obj| {
+        let _guard = obj.mutex.lock().unwrap();
+        panic!("poison the mutex");
+    });
+    assert!(obj.mutex.is_poisoned());
+
+    let err = serde_json::to_string(&*obj).unwrap_err();
+    assert_eq!(
+        "cannot serialize an array behind a poisoned lock",
+        err.to_string()
+    );
+}
+
+#[test]
+fn lock_poisoned_rwlock_is_an_error() {
+    let obj = std::sync::Arc::new(shared());
+    poison(obj.clone(), |obj| {
+        let _guard = obj.rw.write().unwrap();
+        panic!("poison the lock");
+    });
+    assert!(obj.rw.is_poisoned());
+
+    let err = serde_json::to_string(&*obj).unwrap_err();
+    assert_eq!(
+        "cannot serialize an array behind a poisoned lock",
+        err.to_string()
+    );
+}
@@ -0,0 +1,83 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Tables {
+    #[serde(with = "serde_arrays::map_values")]
+    tables: HashMap<String, [u32; 36]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Numbered {
+    #[serde(with = "serde_arrays::map_values")]
+    tables: HashMap<u16, [u8; 3]>,
+}
+
+#[test]
+fn map_values_round_trip() {
+    let mut tables = HashMap::new();
+    tables.insert("ones".to_string(), [1; 36]);
+    tables.insert("twos".to_string(), [2; 36]);
+    let obj = Tables { tables };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Tables = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Tables = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn map_values_matches_plain_map() {
+    let mut tables = HashMap::new();
+    tables.insert(7, [1, 2, 3]);
+    let obj = Numbered { tables };
+
+    // Serde handles arrays this small itself, so the output should be identical
+    let mut plain = BTreeMap::new();
+    plain.insert(7u16, [1u8, 2, 3]);
+
+    assert_eq!(
+        serde_json::to_string(&serde_json::json!({ "tables": plain })).unwrap(),
+        serde_json::to_string(&obj).unwrap()
+    );
+}
+
+#[test]
+fn map_values_empty() {
+    let obj = Tables {
+        tables: HashMap::new(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"tables":{}}"#, j);
+
+    let de: Tables = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+#[should_panic(expected = "for key 9: invalid length 2, expected an array of size 3")]
+fn map_values_error_names_key() {
+    let _: Numbered = serde_json::from_str(r#"{"tables":{"8":[1,2,3],"9":[1,2]}}"#).unwrap();
+}
+
+#[test]
+fn map_values_invalid_element_names_key() {
+    let err = serde_json::from_str::<Tables>(r#"{"tables":{"bad":["x"]}}"#).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with(r#"for key "bad": invalid type: string "x", expected u32"#));
+}
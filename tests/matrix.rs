@@ -0,0 +1,120 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use serde_arrays::Matrix;
+
+fn matrix() -> Matrix<u32, 3, 4> {
+    Matrix([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])
+}
+
+#[test]
+fn matrix_round_trip() {
+    let obj = matrix();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("[[1,2,3,4],[5,6,7,8],[9,10,11,12]]", j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(12 * 4, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn matrix_large_round_trip() {
+    let mut obj = Matrix::<u16, 40, 50>::default();
+    obj[(39, 49)] = 7;
+    obj[(0, 1)] = 3;
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn matrix_accessors() {
+    let mut obj = matrix();
+
+    assert_eq!(3, obj.rows());
+    assert_eq!(4, obj.cols());
+    assert_eq!(3, Matrix::<u32, 3, 4>::ROWS);
+    assert_eq!(4, Matrix::<u32, 3, 4>::COLS);
+    assert_eq!(Some(&7), obj.get(1, 2));
+    assert_eq!(None, obj.get(3, 0));
+    assert_eq!(None, obj.get(0, 4));
+    assert_eq!(12, obj[(2, 3)]);
+
+    *obj.get_mut(0, 0).unwrap() = 100;
+    obj[(2, 3)] = 120;
+    assert_eq!(
+        [[100, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 120]],
+        obj.into_inner()
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected a matrix of 3 rows of 4 elements")]
+fn matrix_too_few_rows() {
+    serde_json::from_str::<Matrix<u32, 3, 4>>("[[1,2,3,4],[5,6,7,8]]").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 4, expected a matrix of 3 rows of 4 elements")]
+fn matrix_too_many_rows() {
+    serde_json::from_str::<Matrix<u32, 3, 4>>("[[1,2,3,4],[5,6,7,8],[1,2,3,4],[5,6,7,8]]").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 3, expected an array of size 4")]
+fn matrix_short_row() {
+    serde_json::from_str::<Matrix<u32, 3, 4>>("[[1,2,3,4],[5,6,7],[9,10,11,12]]").unwrap();
+}
+
+#[test]
+fn miri_matrix_drops_built_rows_on_error() {
+    let res = serde_json::from_str::<Matrix<String, 3, 2>>(r#"[["a","b"],["c","d"],["e"]]"#);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn serialize_transposed_writes_columns() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Square {
+        #[serde(
+            serialize_with = "serde_arrays::matrix::serialize_transposed",
+            deserialize_with = "serde_arrays::matrix::deserialize_transposed"
+        )]
+        cells: [[u32; 2]; 2],
+    }
+
+    let obj = Square {
+        cells: [[1, 2], [3, 4]],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"cells":[[1,3],[2,4]]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn deserialize_transposed_larger_square() {
+    #[derive(Deserialize)]
+    struct Square {
+        #[serde(deserialize_with = "serde_arrays::matrix::deserialize_transposed")]
+        cells: [[u8; 3]; 3],
+    }
+
+    let de: Square = serde_json::from_str(r#"{"cells":[[1,4,7],[2,5,8],[3,6,9]]}"#).unwrap();
+    assert_eq!([[1, 2, 3], [4, 5, 6], [7, 8, 9]], de.cells);
+}
@@ -0,0 +1,76 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Clustered {
+    #[serde(with = "serde_arrays::mean_centered")]
+    arr: [i32; 36],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Spread {
+    #[serde(with = "serde_arrays::mean_centered")]
+    arr: [u64; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Floats {
+    #[serde(with = "serde_arrays::mean_centered")]
+    arr: [f64; 5],
+}
+
+#[test]
+fn mean_centered_clustered_integers() {
+    let mut arr = [0; 36];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = -5_000 + (i as i32 % 3) - 1;
+    }
+    let obj = Clustered { arr };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"arr":[-5000,[-1,0,1,-1,0,1,"#));
+
+    let de: Clustered = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn mean_centered_spread_integers() {
+    let obj = Spread {
+        arr: [0, u64::MAX, 1, u64::MAX - 1],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Spread = serde_json::from_str(&j).unwrap();
+
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn mean_centered_floats() {
+    let obj = Floats {
+        arr: [0.5, -1000.25, 3.75, 1e6, 42.0],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Floats = serde_json::from_str(&j).unwrap();
+
+    for (expected, actual) in obj.arr.iter().zip(&de.arr) {
+        assert!((expected - actual).abs() <= expected.abs() * f64::EPSILON * 4.0);
+    }
+}
+
+#[test]
+fn mean_centered_out_of_range() {
+    let err = serde_json::from_str::<Spread>(r#"{"arr":[10,[0,1,-11,2]]}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("mean 10 with deviation -11 at position 2 is out of range"));
+}
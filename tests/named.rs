@@ -0,0 +1,68 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use serde_arrays::named::ArrayName;
+
+struct Palette;
+impl ArrayName for Palette {
+    const NAME: &'static str = "a 40-color palette";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Theme {
+    #[serde(with = "serde_arrays::named::Named::<Palette>")]
+    colors: [u32; 40],
+}
+
+#[test]
+fn named_round_trip() {
+    let obj = Theme { colors: [0xFF; 40] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        serde_json::to_string(&serde_json::json!({ "colors": vec![0xFF; 40] })).unwrap(),
+        j
+    );
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected a 40-color palette (array of size 40)")]
+fn named_too_short() {
+    serde_json::from_str::<Theme>(r#"{"colors":[1,2]}"#).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 41, expected a 40-color palette (array of size 40)")]
+fn named_too_long() {
+    let json = format!(r#"{{"colors":{:?}}}"#, vec![1; 41]);
+    serde_json::from_str::<Theme>(&json).unwrap();
+}
+
+#[test]
+fn named_wrong_type() {
+    let err = serde_json::from_str::<Theme>(r#"{"colors":"red"}"#).unwrap_err();
+
+    assert!(err.to_string().starts_with(
+        r#"invalid type: string "red", expected a 40-color palette (array of size 40)"#
+    ));
+}
+
+#[test]
+fn deserialize_named_runtime_name() {
+    let name = format!("{} #{}", "layer", 3);
+    let mut de = serde_json::Deserializer::from_str("[1, 2, 3]");
+
+    let err = serde_arrays::deserialize_named::<_, u8, 4>(&name, &mut de).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected layer #3 (array of size 4)"));
+}
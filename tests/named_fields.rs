@@ -0,0 +1,80 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use serde_arrays::named_fields::FieldNames;
+
+struct Rgba;
+impl FieldNames<4> for Rgba {
+    const NAMES: &'static [&'static str; 4] = &["r", "g", "b", "a"];
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Style {
+    #[serde(with = "serde_arrays::named_fields::NamedFields::<Rgba>")]
+    color: [u8; 4],
+}
+
+#[test]
+fn named_fields_rgba_round_trip() {
+    let obj = Style {
+        color: [255, 128, 0, 64],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"color":{"r":255,"g":128,"b":0,"a":64}}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn named_fields_any_order_and_unknown_fields() {
+    let de: Style =
+        serde_json::from_str(r#"{"color":{"a":4,"hex":"ignored","b":3,"g":2,"r":1}}"#).unwrap();
+    assert_eq!([1, 2, 3, 4], de.color);
+}
+
+#[test]
+fn named_fields_missing_field() {
+    let err = serde_json::from_str::<Style>(r#"{"color":{"r":1,"g":2,"a":4}}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `b`"));
+}
+
+#[test]
+fn named_fields_duplicate_field() {
+    let err =
+        serde_json::from_str::<Style>(r#"{"color":{"r":1,"g":2,"g":2,"b":3,"a":4}}"#).unwrap_err();
+    assert!(err.to_string().starts_with("duplicate field `g`"));
+}
+
+#[test]
+fn named_fields_rejects_array() {
+    let err = serde_json::from_str::<Style>(r#"{"color":[1,2,3,4]}"#).unwrap_err();
+    assert!(err.to_string().starts_with(
+        "invalid type: sequence, expected an object with the fields `r`, `g`, `b`, `a`"
+    ));
+}
+
+#[test]
+fn named_fields_drops_built_elements_on_error() {
+    struct Names;
+    impl FieldNames<3> for Names {
+        const NAMES: &'static [&'static str; 3] = &["first", "second", "third"];
+    }
+
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct Strings {
+        #[serde(with = "serde_arrays::named_fields::NamedFields::<Names>")]
+        arr: [String; 3],
+    }
+
+    let err = serde_json::from_str::<Strings>(r#"{"arr":{"first":"a","third":"c"}}"#).unwrap_err();
+    assert!(err.to_string().starts_with("missing field `second`"));
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "ndarray")]
+
+use ndarray::{array, Array2};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Points {
+    #[serde(with = "serde_arrays::ndarray::Cols::<3>")]
+    xyz: Array2<u32>,
+}
+
+#[test]
+fn ndarray_round_trip() {
+    let obj = Points {
+        xyz: array![[1, 2, 3], [4, 5, 6]],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"xyz":[[1,2,3],[4,5,6]]}"#, j);
+    let de: Points = serde_json::from_str(&j).unwrap();
+    assert_eq!((2, 3), de.xyz.dim());
+    assert_eq!(obj, de);
+
+    // Rows are written as tuples, so bincode needs only the number of rows
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 2 * 3 * 4, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn ndarray_matches_vec_of_arrays() {
+    #[derive(Serialize)]
+    struct Rows {
+        #[serde(with = "serde_arrays")]
+        xyz: Vec<[u32; 3]>,
+    }
+
+    let obj = Points {
+        xyz: array![[1, 2, 3], [4, 5, 6]],
+    };
+    let rows = Rows {
+        xyz: vec![[1, 2, 3], [4, 5, 6]],
+    };
+    assert_eq!(
+        bincode::serialize(&rows).unwrap(),
+        bincode::serialize(&obj).unwrap()
+    );
+}
+
+#[test]
+fn ndarray_empty() {
+    let de: Points = serde_json::from_str(r#"{"xyz":[]}"#).unwrap();
+    assert_eq!((0, 3), de.xyz.dim());
+}
+
+#[test]
+fn ndarray_rejects_ragged_rows() {
+    let err = serde_json::from_str::<Points>(r#"{"xyz":[[1,2,3],[4,5]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 1: invalid length 2, expected an array of size 3"));
+
+    let err =
+        serde_json::from_str::<Points>(r#"{"xyz":[[1,2,3],[4,5,6],[7,8,9,10]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 2: invalid length 4, expected an array of size 3"));
+}
+
+#[test]
+fn ndarray_serialize_wrong_column_count() {
+    let obj = Points {
+        xyz: array![[1, 2], [3, 4]],
+    };
+
+    let err = serde_json::to_string(&obj).unwrap_err();
+    assert_eq!("expected a matrix of 3 columns, found 2", err.to_string());
+}
+
+#[test]
+fn ndarray_serialize_non_standard_layout() {
+    // A transposed array isn't laid out row by row in memory, but is still written row by row
+    let obj = Points {
+        xyz: array![[1, 4], [2, 5], [3, 6]].reversed_axes(),
+    };
+    assert!(!obj.xyz.is_standard_layout());
+    assert_eq!(
+        r#"{"xyz":[[1,2,3],[4,5,6]]}"#,
+        serde_json::to_string(&obj).unwrap()
+    );
+}
@@ -0,0 +1,827 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::drops::{live, Counted};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Matrix {
+    #[serde(with = "serde_arrays::nested")]
+    arr: [[u32; 3]; 2],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct LargeMatrix {
+    #[serde(with = "serde_arrays::nested")]
+    arr: [[u32; 36]; 40],
+}
+
+#[test]
+fn nested_round_trip() {
+    let obj = Matrix {
+        arr: [[1, 2, 3], [4, 5, 6]],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[[1,2,3],[4,5,6]]}"#, j);
+
+    let de: Matrix = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn nested_large_round_trip() {
+    let mut arr = [[0; 36]; 40];
+    for (i, row) in arr.iter_mut().enumerate() {
+        for (j, item) in row.iter_mut().enumerate() {
+            *item = (i * 36 + j) as u32;
+        }
+    }
+    let obj = LargeMatrix { arr };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: LargeMatrix = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(36 * 40 * 4, b.len());
+    let de: LargeMatrix = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected an array of size 3")]
+fn nested_short_inner_array() {
+    let _: Matrix = serde_json::from_str(r#"{"arr":[[1,2,3],[4,5]]}"#).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 1, expected an array of 2 arrays of size 3")]
+fn nested_short_outer_array() {
+    let _: Matrix = serde_json::from_str(r#"{"arr":[[1,2,3]]}"#).unwrap();
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Grid {
+    #[serde(with = "serde_arrays::nested")]
+    arr: [[[u32; 2]; 3]; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct LargeGrid {
+    #[serde(with = "serde_arrays::nested")]
+    arr: [[[u8; 40]; 3]; 2],
+}
+
+#[test]
+fn nested_triple_round_trip() {
+    let mut arr = [[[0; 2]; 3]; 4];
+    for (i, plane) in arr.iter_mut().enumerate() {
+        for (j, row) in plane.iter_mut().enumerate() {
+            for (k, item) in row.iter_mut().enumerate() {
+                *item = (i * 100 + j * 10 + k) as u32;
+            }
+        }
+    }
+    let obj = Grid { arr };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        concat!(
+            r#"{"arr":[[[0,1],[10,11],[20,21]],[[100,101],[110,111],[120,121]],"#,
+            r#"[[200,201],[210,211],[220,221]],[[300,301],[310,311],[320,321]]]}"#
+        ),
+        j
+    );
+
+    let de: Grid = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn nested_triple_large_inner_round_trip() {
+    let obj = LargeGrid {
+        arr: [[[1; 40], [2; 40], [3; 40]], [[4; 40], [5; 40], [6; 40]]],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: LargeGrid = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(40 * 3 * 2, b.len());
+    let de: LargeGrid = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 3, expected an array of 4 arrays of 3 arrays of size 2")]
+fn nested_triple_short_outer_array() {
+    let _: Grid = serde_json::from_str(
+        r#"{"arr":[[[0,0],[0,0],[0,0]],[[0,0],[0,0],[0,0]],[[0,0],[0,0],[0,0]]]}"#,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 1, expected an array of 3 arrays of size 2")]
+fn nested_triple_short_middle_array() {
+    let _: Grid = serde_json::from_str(r#"{"arr":[[[0,0]]]}"#).unwrap();
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct VecArray {
+    #[serde(with = "serde_arrays::nested")]
+    arr: Vec<[u32; 96]>,
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_round_trip() {
+    let obj = VecArray {
+        arr: vec![[1; 96], [2; 96], [3; 96]],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"arr":[[1,1,"#));
+    assert!(j.ends_with(",3,3]]}"));
+
+    let de: VecArray = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_matches_serialize_array_in_vec() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct SmallVecArray {
+        #[serde(with = "serde_arrays::nested")]
+        arr: Vec<[u32; 3]>,
+    }
+
+    let json = "{\"arr\":[[1,1,1],[1,1,1]]}";
+    let de: SmallVecArray = serde_json::from_str(json).unwrap();
+
+    assert_eq!(vec![[1; 3]; 2], de.arr);
+    assert_eq!(json, serde_json::to_string(&de).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_malformed_inner_array_names_index() {
+    #[derive(Deserialize, Debug)]
+    struct SmallVecArray {
+        #[serde(with = "serde_arrays::nested")]
+        #[allow(dead_code)]
+        arr: Vec<[u32; 3]>,
+    }
+
+    let json = r#"{"arr":[[1,2,3],[4,5,6],[7,8],[10,11,12]]}"#;
+    let err = serde_json::from_str::<SmallVecArray>(json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 2: invalid length 2, expected an array of size 3"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_blames_first_row_differing_from_the_rest() {
+    #[derive(Deserialize, Debug)]
+    struct Rows {
+        #[serde(with = "serde_arrays::nested")]
+        #[allow(dead_code)]
+        rows: Vec<[u32; 3]>,
+    }
+
+    // Row 0 sets the expected width; row 1 is the first to differ, and row 3 differs too
+    let json = r#"{"rows":[[1,2,3],[4,5,6,7],[8,9,10],[11,12]]}"#;
+    let err = serde_json::from_str::<Rows>(json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 1: invalid length 4, expected an array of size 3"));
+
+    let json = r#"{"rows":[[1,2,3],[4,5]]}"#;
+    let err = serde_json::from_str::<Rows>(json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 1: invalid length 2, expected an array of size 3"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_empty() {
+    let obj = VecArray { arr: Vec::new() };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[]}"#, j);
+
+    let de: VecArray = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "invalid length 95, expected an array of size 96")]
+fn nested_vec_short_inner_array() {
+    let json = format!(r#"{{"arr":[{:?},{:?}]}}"#, [1; 96], [2; 95]);
+
+    let _: VecArray = serde_json::from_str(&json).unwrap();
+}
+
+// The following tests check that every element constructed before a nested deserialize fails is
+// dropped exactly once, in both the inner and outer arrays.
+
+impl serde_arrays::nested::Element for Counted<u32> {}
+
+#[derive(Deserialize, Debug)]
+struct CountedMatrix {
+    #[serde(with = "serde_arrays::nested")]
+    arr: [[Counted<u32>; 3]; 3],
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Deserialize, Debug)]
+struct CountedVec {
+    #[serde(with = "serde_arrays::nested")]
+    arr: Vec<[Counted<u32>; 3]>,
+}
+
+#[test]
+fn nested_drops_all_on_success() {
+    let obj: CountedMatrix = serde_json::from_str(r#"{"arr":[[1,2,3],[4,5,6],[7,8,9]]}"#).unwrap();
+    assert_eq!(9, live());
+    assert_eq!(9, obj.arr[2][2].0);
+
+    drop(obj);
+    assert_eq!(0, live());
+}
+
+#[test]
+fn nested_short_inner_array_drops_constructed() {
+    let err =
+        serde_json::from_str::<CountedMatrix>(r#"{"arr":[[1,2,3],[4,5],[7,8,9]]}"#).unwrap_err();
+
+    assert!(err.to_string().contains("invalid length 2"));
+    assert_eq!(0, live());
+}
+
+#[test]
+fn nested_short_outer_array_drops_constructed() {
+    let err = serde_json::from_str::<CountedMatrix>(r#"{"arr":[[1,2,3],[4,5,6]]}"#).unwrap_err();
+
+    assert!(err.to_string().contains("invalid length 2"));
+    assert_eq!(0, live());
+}
+
+#[test]
+fn nested_long_outer_array_drops_constructed() {
+    let err =
+        serde_json::from_str::<CountedMatrix>(r#"{"arr":[[1,2,3],[4,5,6],[7,8,9],[10,11,12]]}"#)
+            .unwrap_err();
+
+    assert!(err.to_string().contains("invalid length 4"));
+    assert_eq!(0, live());
+}
+
+#[test]
+fn nested_invalid_inner_element_drops_constructed() {
+    let err = serde_json::from_str::<CountedMatrix>(r#"{"arr":[[1,2,3],[4,"x",6],[7,8,9]]}"#)
+        .unwrap_err();
+
+    assert!(err.to_string().contains("invalid type"));
+    assert_eq!(0, live());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_short_inner_array_drops_constructed() {
+    let err = serde_json::from_str::<CountedVec>(r#"{"arr":[[1,2,3],[4,5,6],[7]]}"#).unwrap_err();
+
+    assert!(err.to_string().contains("invalid length 1"));
+    assert_eq!(0, live());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_drops_all_on_success() {
+    let obj: CountedVec = serde_json::from_str(r#"{"arr":[[1,2,3],[4,5,6]]}"#).unwrap();
+    assert_eq!(6, live());
+    assert_eq!(2, obj.arr.len());
+
+    drop(obj);
+    assert_eq!(0, live());
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Frames {
+    #[serde(with = "serde_arrays::nested")]
+    frames: std::collections::VecDeque<[f32; 128]>,
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_deque_round_trip() {
+    let mut obj = Frames {
+        frames: std::collections::VecDeque::with_capacity(3),
+    };
+    // Wrap around the ring buffer so the front isn't at the start of its storage
+    obj.frames.push_back([0.0; 128]);
+    obj.frames.push_back([1.0; 128]);
+    obj.frames.pop_front();
+    obj.frames.push_back([2.0; 128]);
+    obj.frames.push_front([0.5; 128]);
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Frames = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let front: Vec<f32> = de.frames.iter().map(|frame| frame[0]).collect();
+    assert_eq!(vec![0.5, 1.0, 2.0], front);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_deque_empty() {
+    let obj = Frames {
+        frames: std::collections::VecDeque::new(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"frames":[]}"#, j);
+
+    let de: Frames = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Transforms {
+    #[serde(with = "serde_arrays::nested")]
+    transforms: Vec<[[f32; 4]; 4]>,
+}
+
+#[cfg(feature = "alloc")]
+fn transform(scale: f32) -> [[f32; 4]; 4] {
+    let mut m = [[0.0; 4]; 4];
+    for (r, row) in m.iter_mut().enumerate() {
+        for (c, cell) in row.iter_mut().enumerate() {
+            *cell = scale * (r * 4 + c) as f32;
+        }
+    }
+    m
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_of_matrices_round_trip() {
+    let obj = Transforms {
+        transforms: vec![transform(1.0), transform(-0.5)],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"transforms":[[[0.0,1.0,2.0,3.0],[4.0,5.0,6.0,7.0],"#));
+    let de: Transforms = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 2 * 16 * 4, b.len());
+    let de: Transforms = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_of_matrices_error_names_index() {
+    let json = r#"{"transforms":[
+        [[1,0,0,0],[0,1,0,0],[0,0,1,0],[0,0,0,1]],
+        [[1,0,0,0],[0,1,0],[0,0,1,0],[0,0,0,1]]
+    ]}"#;
+
+    let err = serde_json::from_str::<Transforms>(json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 1: invalid length 3, expected an array of size 4"));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Bytes {
+    #[serde(with = "serde_arrays::nested")]
+    arr: [[u8; 3]; 5],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct BytesRows {
+    #[serde(with = "serde_arrays::nested::Rows")]
+    arr: [[u8; 3]; 5],
+}
+
+#[test]
+fn nested_rows_matches_nested() {
+    let mut arr = [[0; 3]; 5];
+    for (i, row) in arr.iter_mut().enumerate() {
+        for (j, item) in row.iter_mut().enumerate() {
+            *item = (i * 3 + j) as u8;
+        }
+    }
+
+    let j = serde_json::to_string(&Bytes { arr }).unwrap();
+    assert_eq!(j, serde_json::to_string(&BytesRows { arr }).unwrap());
+    let de: BytesRows = serde_json::from_str(&j).unwrap();
+    assert_eq!(arr, de.arr);
+
+    let b = bincode::serialize(&Bytes { arr }).unwrap();
+    assert_eq!(b, bincode::serialize(&BytesRows { arr }).unwrap());
+    let de: BytesRows = bincode::deserialize(&b).unwrap();
+    assert_eq!(arr, de.arr);
+}
+
+/// An element type that does not implement `serde_arrays::nested::Element`
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Image {
+    #[serde(with = "serde_arrays::nested::Rows")]
+    pixels: [[Rgb; 40]; 2],
+}
+
+#[test]
+fn nested_rows_custom_elements() {
+    let obj = Image {
+        pixels: [
+            [Rgb { r: 1, g: 2, b: 3 }; 40],
+            [Rgb { r: 4, g: 5, b: 6 }; 40],
+        ],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"pixels":[[{"r":1,"g":2,"b":3},"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "invalid length 1, expected an array of 5 arrays of size 3")]
+fn nested_rows_short_outer() {
+    let _: BytesRows = serde_json::from_str(r#"{"arr":[[1,2,3]]}"#).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected an array of size 3")]
+fn nested_rows_short_inner() {
+    let _: BytesRows =
+        serde_json::from_str(r#"{"arr":[[1,2,3],[1,2],[1,2,3],[1,2,3],[1,2,3]]}"#).unwrap();
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Columns {
+    #[serde(with = "serde_arrays::nested", default)]
+    data: Option<Vec<[f32; 8]>>,
+    #[serde(with = "serde_arrays::nested", default)]
+    grid: Option<[Option<[u8; 40]>; 2]>,
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_option_none() {
+    let obj = Columns {
+        data: None,
+        grid: None,
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"data":null,"grid":null}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+    assert_eq!(obj, serde_json::from_str("{}").unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_option_some_empty() {
+    let obj = Columns {
+        data: Some(Vec::new()),
+        grid: Some([None, None]),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"data":[],"grid":[null,null]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_option_some() {
+    let obj = Columns {
+        data: Some(vec![[0.5; 8], [1.5; 8], [2.5; 8]]),
+        grid: Some([Some([7; 40]), None]),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"data":[[0.5,0.5,0.5,0.5,0.5,0.5,0.5,0.5],[1.5,"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+
+    let y = serde_yaml::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_yaml::from_str(&y).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_option_invalid_is_an_error() {
+    let err = serde_json::from_str::<Columns>(r#"{"data":[[1,2,3]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 0: invalid length 3, expected an array of size 8"));
+
+    let err = serde_json::from_str::<Columns>(r#"{"data":7}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid type: integer `7`, expected a sequence of arrays of size 8"));
+}
+
+fn nested_to_json<A: serde_arrays::nested::NestedArray>(data: A) -> String {
+    let mut out = Vec::new();
+    serde_arrays::nested::serialize(&data, &mut serde_json::Serializer::new(&mut out)).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn nested_serialize_borrowed_array() {
+    let owned = [[1u32, 2, 3], [4, 5, 6]];
+    let borrowed: &[[u32; 3]; 2] = &owned;
+
+    assert_eq!("[[1,2,3],[4,5,6]]", nested_to_json(borrowed));
+    assert_eq!(nested_to_json(owned), nested_to_json(borrowed));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_serialize_slices() {
+    let rows = [[1u32, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+    assert_eq!("[[4,5,6],[7,8,9]]", nested_to_json(&rows[1..]));
+    assert_eq!("[]", nested_to_json(&rows[..0]));
+
+    let data = vec![[[1u8; 2]; 2]; 2];
+    assert_eq!(nested_to_json(&data[..]), nested_to_json(data.clone()));
+}
+
+#[test]
+fn nested_serialize_borrowed_field() {
+    #[derive(Serialize)]
+    struct View<'a> {
+        #[serde(serialize_with = "serde_arrays::nested::serialize")]
+        matrix: &'a [[u32; 3]; 2],
+        #[serde(serialize_with = "serde_arrays::nested::serialize")]
+        rows: &'a [[u32; 3]],
+    }
+
+    let owned = Matrix {
+        arr: [[1, 2, 3], [4, 5, 6]],
+    };
+    let view = View {
+        matrix: &owned.arr,
+        rows: &owned.arr[1..],
+    };
+
+    assert_eq!(
+        r#"{"matrix":[[1,2,3],[4,5,6]],"rows":[[4,5,6]]}"#,
+        serde_json::to_string(&view).unwrap()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Volume {
+    #[serde(with = "serde_arrays::nested")]
+    voxels: Box<[[[u8; 4]; 4]; 100_000]>,
+}
+
+#[cfg(feature = "alloc")]
+/// Build a `Volume` without ever putting the array itself on the stack
+fn volume() -> Volume {
+    use std::convert::TryInto;
+
+    let voxels: Vec<[[u8; 4]; 4]> = (0..100_000).map(|i| [[(i % 251) as u8; 4]; 4]).collect();
+    Volume {
+        voxels: voxels.into_boxed_slice().try_into().unwrap(),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_boxed_large_outer_dimension_on_small_stack() {
+    let obj = volume();
+    let j = serde_json::to_string(&obj).unwrap();
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(100_000 * 16, b.len());
+
+    // The 1.6 MB array would never fit on this thread's stack were it built there
+    let de = std::thread::Builder::new()
+        .stack_size(256 * 1024)
+        .spawn(move || {
+            let from_json: Volume = serde_json::from_str(&j).unwrap();
+            let from_bincode: Volume = bincode::deserialize(&b).unwrap();
+            assert_eq!(from_json, from_bincode);
+            from_json
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    assert_eq!(obj, de);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_boxed_errors() {
+    #[derive(Deserialize, Debug)]
+    struct Small {
+        #[serde(with = "serde_arrays::nested")]
+        #[allow(dead_code)]
+        arr: Box<[[u8; 2]; 3]>,
+    }
+
+    let err = serde_json::from_str::<Small>(r#"{"arr":[[1,2],[3,4]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 2, expected an array of 3 arrays of size 2"));
+
+    let err = serde_json::from_str::<Small>(r#"{"arr":[[1,2],[3,4],[5,6],[7,8]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 4, expected an array of 3 arrays of size 2"));
+
+    let err = serde_json::from_str::<Small>(r#"{"arr":[[1,2],[3,4],[5]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 1, expected an array of size 2"));
+}
+
+#[cfg(feature = "alloc")]
+/// A sequence of rows reporting whatever size hint it's given, however wrong
+struct HintedRows {
+    rows: std::vec::IntoIter<Vec<u32>>,
+    hint: Option<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> serde::Deserializer<'de> for HintedRows {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> serde::de::SeqAccess<'de> for HintedRows {
+    type Error = serde::de::value::Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: serde::de::DeserializeSeed<'de>,
+    {
+        use serde::de::IntoDeserializer;
+
+        match self.rows.next() {
+            Some(row) => seed.deserialize(row.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.hint
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn hinted_rows(count: usize, hint: Option<usize>) -> HintedRows {
+    let rows: Vec<Vec<u32>> = (0..count as u32).map(|i| vec![i, i + 1, i + 2]).collect();
+    HintedRows {
+        rows: rows.into_iter(),
+        hint,
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_reserves_from_size_hint() {
+    for hint in [Some(10_000), None, Some(3)].iter() {
+        let data: Vec<[u32; 3]> =
+            serde_arrays::nested::deserialize(hinted_rows(10_000, *hint)).unwrap();
+
+        assert_eq!(10_000, data.len());
+        assert!(data.capacity() >= 10_000);
+        for (i, row) in data.iter().enumerate() {
+            let i = i as u32;
+            assert_eq!([i, i + 1, i + 2], *row);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_vec_hostile_size_hint_is_capped() {
+    let data: Vec<[u32; 3]> =
+        serde_arrays::nested::deserialize(hinted_rows(2, Some(usize::MAX))).unwrap();
+
+    assert_eq!(vec![[0, 1, 2], [1, 2, 3]], data);
+    assert!(data.capacity() <= 1024 * 1024 / 12);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_boxed_checks_size_hint_like_boxed() {
+    let err = serde_arrays::nested::deserialize::<_, Box<[[u32; 3]; 4]>>(hinted_rows(4, Some(5)))
+        .unwrap_err();
+    let boxed_err =
+        serde_arrays::boxed::deserialize::<_, [u32; 3], 4>(hinted_rows(4, Some(5))).unwrap_err();
+
+    assert_eq!(
+        "invalid length 5, expected an array of 4 arrays of size 3",
+        err.to_string()
+    );
+    assert!(boxed_err
+        .to_string()
+        .starts_with("invalid length 5, expected"));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct ColumnMajor {
+    #[serde(
+        serialize_with = "serde_arrays::nested::serialize_row_major",
+        deserialize_with = "serde_arrays::nested::deserialize_row_major"
+    )]
+    cols: [[String; 2]; 3],
+}
+
+fn column_major() -> ColumnMajor {
+    let cell = |r: usize, c: usize| format!("r{}c{}", r, c);
+    ColumnMajor {
+        cols: [
+            [cell(0, 0), cell(1, 0)],
+            [cell(0, 1), cell(1, 1)],
+            [cell(0, 2), cell(1, 2)],
+        ],
+    }
+}
+
+#[test]
+fn nested_column_major_serializes_row_major() {
+    let j = serde_json::to_string(&column_major()).unwrap();
+
+    assert_eq!(
+        r#"{"cols":[["r0c0","r0c1","r0c2"],["r1c0","r1c1","r1c2"]]}"#,
+        j
+    );
+    assert_eq!(column_major(), serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&column_major()).unwrap();
+    assert_eq!(column_major(), bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn miri_nested_column_major_bad_row_is_error() {
+    // The strings already read must be dropped, which Miri's leak check confirms
+    let err =
+        serde_json::from_str::<ColumnMajor>(r#"{"cols":[["a","b","c"],["d","e"]]}"#).unwrap_err();
+
+    assert!(
+        err.to_string()
+            .starts_with("invalid length 2, expected an array of size 3"),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,110 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+use common::recorder::{length_hints, record, record_binary, Event};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Grid {
+    #[serde(with = "serde_arrays::nested_seq")]
+    arr: [[[u8; 2]; 3]; 4],
+    #[serde(with = "serde_arrays::nested_seq")]
+    vec: Vec<[[u8; 5]; 6]>,
+    #[serde(with = "serde_arrays::nested_seq")]
+    opt: Option<[[u8; 36]; 2]>,
+}
+
+fn grid() -> Grid {
+    Grid {
+        arr: [[[1; 2]; 3]; 4],
+        vec: vec![[[2; 5]; 6]; 1],
+        opt: Some([[3; 36]; 2]),
+    }
+}
+
+#[test]
+fn nested_seq_every_level_is_a_seq() {
+    let mut expected = vec![Event::Seq(Some(4))];
+    for _ in 0..4 {
+        expected.push(Event::Seq(Some(3)));
+        expected.extend(vec![Event::Seq(Some(2)); 3]);
+    }
+    expected.push(Event::Seq(Some(1)));
+    expected.push(Event::Seq(Some(6)));
+    expected.extend(vec![Event::Seq(Some(5)); 6]);
+    expected.push(Event::Seq(Some(2)));
+    expected.extend(vec![Event::Seq(Some(36)); 2]);
+
+    assert_eq!(expected, length_hints(record(&grid())));
+    assert_eq!(expected, length_hints(record_binary(&grid())));
+}
+
+#[test]
+fn nested_keeps_tuples_for_arrays() {
+    #[derive(Serialize)]
+    struct Frames {
+        #[serde(with = "serde_arrays::nested")]
+        vec: Vec<[[u8; 5]; 6]>,
+    }
+
+    let mut expected = vec![Event::Seq(Some(2))];
+    for _ in 0..2 {
+        expected.push(Event::Tuple(6));
+        expected.extend(vec![Event::Tuple(5); 6]);
+    }
+
+    let frames = Frames {
+        vec: vec![[[0; 5]; 6]; 2],
+    };
+    assert_eq!(expected, length_hints(record(&frames)));
+}
+
+#[test]
+fn nested_seq_json_matches_nested() {
+    #[derive(Serialize)]
+    struct Tuples {
+        #[serde(with = "serde_arrays::nested")]
+        arr: [[[u8; 2]; 3]; 4],
+        #[serde(with = "serde_arrays::nested")]
+        vec: Vec<[[u8; 5]; 6]>,
+        #[serde(with = "serde_arrays::nested")]
+        opt: Option<[[u8; 36]; 2]>,
+    }
+
+    let seqs = grid();
+    let tuples = Tuples {
+        arr: seqs.arr,
+        vec: seqs.vec.clone(),
+        opt: seqs.opt,
+    };
+
+    let j = serde_json::to_string(&seqs).unwrap();
+    assert_eq!(serde_json::to_string(&tuples).unwrap(), j);
+    assert_eq!(seqs, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn nested_seq_bincode_round_trip() {
+    let data = grid();
+
+    let bytes = bincode::serialize(&data).unwrap();
+    assert_eq!(data, bincode::deserialize(&bytes).unwrap());
+}
+
+#[test]
+fn nested_seq_short_inner_array() {
+    let err = serde_json::from_str::<Grid>(
+        r#"{"arr":[[[1,1],[1,1],[1,1]],[[1,1],[1,1],[1,1]],[[1,1],[1,1],[1,1]],[[1,1],[1,1],[1]]],"vec":[],"opt":null}"#,
+    )
+    .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 1, expected an array"));
+}
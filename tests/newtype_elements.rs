@@ -0,0 +1,78 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+
+use common::expected_array;
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Element(u32);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Frame(#[serde(with = "serde_arrays")] [Element; 3]);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct LargeFrame {
+    #[serde(with = "serde_arrays")]
+    elements: [Element; 36],
+}
+
+#[test]
+fn newtype_elements_tokens() {
+    let frame = Frame([Element(1), Element(2), Element(3)]);
+
+    assert_tokens(
+        &frame,
+        &[
+            Token::NewtypeStruct { name: "Frame" },
+            Token::Tuple { len: 3 },
+            Token::NewtypeStruct { name: "Element" },
+            Token::U32(1),
+            Token::NewtypeStruct { name: "Element" },
+            Token::U32(2),
+            Token::NewtypeStruct { name: "Element" },
+            Token::U32(3),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[test]
+fn newtype_elements_too_few() {
+    assert_de_tokens_error::<Frame>(
+        &[
+            Token::NewtypeStruct { name: "Frame" },
+            Token::Tuple { len: 2 },
+            Token::NewtypeStruct { name: "Element" },
+            Token::U32(1),
+            Token::NewtypeStruct { name: "Element" },
+            Token::U32(2),
+            Token::TupleEnd,
+        ],
+        &format!(
+            "invalid length 2, expected {}",
+            expected_array::<Element>(3)
+        ),
+    );
+}
+
+#[test]
+fn newtype_elements_json_round_trip() {
+    let mut elements = [0; 36].map(Element);
+    for (i, element) in elements.iter_mut().enumerate() {
+        element.0 = i as u32;
+    }
+    let frame = LargeFrame { elements };
+
+    let j = serde_json::to_string(&frame).unwrap();
+    assert!(j.starts_with(r#"{"elements":[0,1,2,"#));
+
+    let de: LargeFrame = serde_json::from_str(&j).unwrap();
+    assert_eq!(frame, de);
+}
@@ -0,0 +1,27 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Build with e.g. `cargo build --target thumbv7em-none-eabihf` to check that `serde_arrays`
+//! works without `std` or `alloc`.
+
+#![no_std]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Frame {
+    #[serde(with = "serde_arrays")]
+    pub payload: [u8; 64],
+    #[serde(with = "serde_arrays::nested")]
+    pub calibration: [[i16; 4]; 4],
+    #[serde(with = "serde_arrays::fixed_size_list")]
+    pub samples: [u32; 40],
+}
+
+pub fn checksum(frame: &Frame) -> u64 {
+    serde_arrays::stable_hash(&frame.payload)
+}
@@ -0,0 +1,67 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Reading {
+    #[serde(with = "serde_arrays::null_as_default")]
+    samples: [u32; 3],
+}
+
+#[test]
+fn null_as_default_replaces_nulls() {
+    let reading: Reading = serde_json::from_str(r#"{"samples":[1,null,3]}"#).unwrap();
+    assert_eq!([1, 0, 3], reading.samples);
+
+    let reading: Reading = serde_json::from_str(r#"{"samples":[null,null,null]}"#).unwrap();
+    assert_eq!([0, 0, 0], reading.samples);
+}
+
+#[test]
+fn null_as_default_round_trip() {
+    let reading = Reading { samples: [4, 0, 6] };
+
+    let json = serde_json::to_string(&reading).unwrap();
+    assert_eq!(r#"{"samples":[4,0,6]}"#, json);
+    assert_eq!(reading, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn null_as_default_large_array() {
+    #[derive(Deserialize)]
+    struct Large {
+        #[serde(with = "serde_arrays::null_as_default")]
+        names: [String; 40],
+    }
+
+    let mut json = vec![r#""a""#; 40];
+    json[39] = "null";
+    let large: Large =
+        serde_json::from_str(&format!(r#"{{"names":[{}]}}"#, json.join(","))).unwrap();
+    assert_eq!("a", large.names[0]);
+    assert_eq!("", large.names[39]);
+}
+
+#[test]
+fn null_as_default_still_checks_length() {
+    let err = serde_json::from_str::<Reading>(r#"{"samples":[1,null]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 2, expected an array of size 3"));
+
+    let err = serde_json::from_str::<Reading>(r#"{"samples":[1,null,3,null]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 4, expected an array of size 3"));
+}
+
+#[test]
+fn null_as_default_rejects_invalid_elements() {
+    let err = serde_json::from_str::<Reading>(r#"{"samples":[1,"two",3]}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid type: string \"two\""));
+}
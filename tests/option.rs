@@ -0,0 +1,67 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Config {
+    #[serde(with = "serde_arrays::option")]
+    maybe: Option<[u32; 48]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Defaulted {
+    #[serde(with = "serde_arrays::option", default)]
+    maybe: Option<[u32; 3]>,
+}
+
+#[test]
+fn option_round_trip() {
+    for obj in [
+        Config {
+            maybe: Some([1; 48]),
+        },
+        Config { maybe: None },
+    ] {
+        let j = serde_json::to_string(&obj).unwrap();
+        let de: Config = serde_json::from_str(&j).unwrap();
+        assert_eq!(obj, de);
+
+        let b = bincode::serialize(&obj).unwrap();
+        let de: Config = bincode::deserialize(&b).unwrap();
+        assert_eq!(obj, de);
+    }
+}
+
+#[test]
+fn option_json() {
+    let obj = Defaulted {
+        maybe: Some([1, 2, 3]),
+    };
+    assert_eq!(r#"{"maybe":[1,2,3]}"#, serde_json::to_string(&obj).unwrap());
+
+    let obj = Defaulted { maybe: None };
+    assert_eq!(r#"{"maybe":null}"#, serde_json::to_string(&obj).unwrap());
+}
+
+#[test]
+fn option_missing_field_with_default() {
+    let de: Defaulted = serde_json::from_str("{}").unwrap();
+    assert_eq!(Defaulted { maybe: None }, de);
+}
+
+#[test]
+#[should_panic(expected = "missing field `maybe`")]
+fn option_missing_field_without_default() {
+    let _: Config = serde_json::from_str("{}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected an array of size 3")]
+fn option_wrong_length_is_an_error() {
+    let _: Defaulted = serde_json::from_str(r#"{"maybe":[1,2]}"#).unwrap();
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Extended {
+    #[serde(with = "serde_arrays::optional_tail::OptionalTail::<2>")]
+    arr: [u32; 6],
+}
+
+fn parse(arr: &[u32]) -> Result<Extended, serde_json::Error> {
+    serde_json::from_str(&format!(r#"{{"arr":{:?}}}"#, arr))
+}
+
+#[test]
+fn optional_tail_too_short() {
+    let err = parse(&[1, 2, 3]).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of 4 to 6 elements"));
+}
+
+#[test]
+fn optional_tail_only_required() {
+    assert_eq!([1, 2, 3, 4, 0, 0], parse(&[1, 2, 3, 4]).unwrap().arr);
+}
+
+#[test]
+fn optional_tail_partial_tail() {
+    assert_eq!([1, 2, 3, 4, 5, 0], parse(&[1, 2, 3, 4, 5]).unwrap().arr);
+}
+
+#[test]
+fn optional_tail_full() {
+    assert_eq!([1, 2, 3, 4, 5, 6], parse(&[1, 2, 3, 4, 5, 6]).unwrap().arr);
+}
+
+#[test]
+fn optional_tail_too_long() {
+    let err = parse(&[1, 2, 3, 4, 5, 6, 7]).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 7, expected an array of 4 to 6 elements"));
+}
+
+#[test]
+fn optional_tail_serializes_everything() {
+    let obj = Extended {
+        arr: [1, 2, 3, 4, 0, 0],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[1,2,3,4,0,0]}"#, j);
+}
+
+#[test]
+fn optional_tail_larger_than_array() {
+    #[derive(Deserialize)]
+    struct AllOptional {
+        #[serde(with = "serde_arrays::optional_tail::OptionalTail::<8>")]
+        arr: [u32; 4],
+    }
+
+    let de: AllOptional = serde_json::from_str(r#"{"arr":[]}"#).unwrap();
+    assert_eq!([0; 4], de.arr);
+}
+
+#[test]
+fn optional_tail_reads_older_shorter_data_through_bincode() {
+    #[derive(Serialize)]
+    struct Old {
+        arr: Vec<u32>,
+        next: u32,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct New {
+        #[serde(with = "serde_arrays::optional_tail::OptionalTail::<2>")]
+        arr: [u32; 6],
+        next: u32,
+    }
+
+    let b = bincode::serialize(&Old {
+        arr: vec![1, 2, 3, 4],
+        next: 99,
+    })
+    .unwrap();
+
+    assert_eq!(
+        New {
+            arr: [1, 2, 3, 4, 0, 0],
+            next: 99,
+        },
+        bincode::deserialize(&b).unwrap()
+    );
+}
+
+#[test]
+fn optional_tail_bincode_round_trip() {
+    let obj = Extended {
+        arr: [1, 2, 3, 4, 5, 6],
+    };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Padded {
+    #[serde(with = "serde_arrays::padded::Padded::<0xFF>")]
+    arr: [u8; 8],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Wide {
+    #[serde(with = "serde_arrays::padded::Padded::<7>")]
+    arr: [u32; 36],
+}
+
+#[test]
+fn padded_short() {
+    let obj: Padded = serde_json::from_str(r#"{"arr":[1,2,3]}"#).unwrap();
+    assert_eq!([1, 2, 3, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], obj.arr);
+}
+
+#[test]
+fn padded_empty() {
+    let obj: Padded = serde_json::from_str(r#"{"arr":[]}"#).unwrap();
+    assert_eq!([0xFF; 8], obj.arr);
+}
+
+#[test]
+fn padded_full_round_trip() {
+    let obj = Padded {
+        arr: [1, 2, 3, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[1,2,3,255,255,255,255,255]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn padded_converts_pad_value() {
+    let obj: Wide = serde_json::from_str(r#"{"arr":[1000,2000]}"#).unwrap();
+
+    assert_eq!([1000, 2000], obj.arr[..2]);
+    assert!(obj.arr[2..].iter().all(|&x| x == 7));
+}
+
+#[test]
+#[should_panic(expected = "invalid length 9, expected an array of 0 to 8 elements")]
+fn padded_too_long() {
+    let _: Padded = serde_json::from_str(r#"{"arr":[1,2,3,4,5,6,7,8,9]}"#).unwrap();
+}
+
+#[test]
+fn padded_short_through_bincode() {
+    let b = bincode::serialize(&(vec![1u8, 2, 3], 42u8)).unwrap();
+    let (obj, next): (Padded, u8) = bincode::deserialize(&b).unwrap();
+
+    assert_eq!([1, 2, 3, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], obj.arr);
+    assert_eq!(42, next);
+}
@@ -0,0 +1,82 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Cipher {
+    #[serde(with = "serde_arrays::pair")]
+    iv_and_key: ([u8; 16], [u8; 32]),
+}
+
+fn cipher() -> Cipher {
+    let mut key = [0; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+    Cipher {
+        iv_and_key: ([0xAA; 16], key),
+    }
+}
+
+fn json(iv: usize, key: usize) -> String {
+    format!(r#"{{"iv_and_key":[{:?},{:?}]}}"#, vec![1; iv], vec![2; key])
+}
+
+#[test]
+fn pair_round_trip() {
+    let obj = cipher();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"iv_and_key":[[170,170,"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(16 + 32, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+
+    let y = serde_yaml::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_yaml::from_str(&y).unwrap());
+}
+
+#[test]
+fn pair_first_wrong_length() {
+    let err = serde_json::from_str::<Cipher>(&json(15, 32)).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 15, expected an array of size 16"));
+
+    let err = serde_json::from_str::<Cipher>(&json(17, 32)).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 17, expected an array of size 16"));
+}
+
+#[test]
+fn pair_second_wrong_length() {
+    let err = serde_json::from_str::<Cipher>(&json(16, 31)).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 31, expected an array of size 32"));
+
+    let err = serde_json::from_str::<Cipher>(&json(16, 33)).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 33, expected an array of size 32"));
+}
+
+#[test]
+fn pair_wrong_number_of_arrays() {
+    let err = serde_json::from_str::<Cipher>(r#"{"iv_and_key":[[1]]}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid length 1"));
+
+    let j = format!(r#"{{"iv_and_key":[{:?},{:?},[]]}}"#, [1; 16], [2; 32]);
+    let err = serde_json::from_str::<Cipher>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a pair of arrays of sizes 16 and 32"));
+}
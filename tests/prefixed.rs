@@ -0,0 +1,68 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+
+use common::{
+    expected_array,
+    recorder::{record_binary, Event},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Prefixed {
+    #[serde(with = "serde_arrays::prefixed")]
+    arr: [u32; 36],
+}
+
+#[test]
+fn prefixed_writes_length() {
+    let obj = Prefixed { arr: [3; 36] };
+
+    let events = record_binary(&obj);
+    assert!(events.contains(&Event::Seq(Some(36))));
+    assert!(!events.iter().any(|event| matches!(event, Event::Tuple(_))));
+
+    // bincode writes a sequence's length as a u64 ahead of its elements
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 36 * 4, b.len());
+    assert_eq!(36u64.to_le_bytes(), b[..8]);
+}
+
+#[test]
+fn prefixed_round_trip() {
+    let mut arr = [0; 36];
+    for (i, elem) in arr.iter_mut().enumerate() {
+        *elem = i as u32;
+    }
+    let obj = Prefixed { arr };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn prefixed_rejects_wrong_prefix() {
+    let mut b = bincode::serialize(&Prefixed { arr: [3; 36] }).unwrap();
+
+    // The elements are all still there, but the prefix no longer matches `N`
+    for prefix in [35u64, 37] {
+        b[..8].copy_from_slice(&prefix.to_le_bytes());
+        let err = bincode::deserialize::<Prefixed>(&b).unwrap_err();
+        assert_eq!(
+            format!(
+                "invalid length {}, expected {}",
+                prefix,
+                expected_array::<u32>(36)
+            ),
+            err.to_string()
+        );
+    }
+}
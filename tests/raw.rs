@@ -0,0 +1,77 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde_arrays::raw::{read_at, RawError};
+
+/// A record with a 3-byte header, followed by four `u32`s each padded to 8 bytes
+fn padded_record() -> Vec<u8> {
+    let mut record = vec![0xde, 0xad, 0xbe];
+    for value in &[10u32, 20, 0xffff_0000, 40] {
+        record.extend_from_slice(&value.to_ne_bytes());
+        record.extend_from_slice(&[0xee; 4]);
+    }
+    record
+}
+
+#[test]
+fn read_padded_u32s() {
+    let record = padded_record();
+
+    let arr: [u32; 4] = read_at(&record, 3, 8).unwrap();
+
+    assert_eq!([10, 20, 0xffff_0000, 40], arr);
+}
+
+#[test]
+fn read_packed_i16s() {
+    let mut record = Vec::new();
+    for value in &[-1i16, 2, -3] {
+        record.extend_from_slice(&value.to_ne_bytes());
+    }
+
+    let arr: [i16; 3] = read_at(&record, 0, 2).unwrap();
+
+    assert_eq!([-1, 2, -3], arr);
+}
+
+#[test]
+fn read_last_element_without_trailing_padding() {
+    // The final element's padding isn't needed to read it
+    let mut record = padded_record();
+    record.truncate(record.len() - 4);
+
+    let arr: [u32; 4] = read_at(&record, 3, 8).unwrap();
+
+    assert_eq!(40, arr[3]);
+}
+
+#[test]
+fn read_out_of_bounds() {
+    let record = padded_record();
+
+    assert_eq!(
+        Err(RawError::OutOfBounds { end: 36, len: 35 }),
+        read_at::<u32, 4>(&record, 8, 8)
+    );
+    assert_eq!(
+        Err(RawError::OutOfBounds {
+            end: usize::MAX,
+            len: 35
+        }),
+        read_at::<u32, 4>(&record, 0, usize::MAX)
+    );
+}
+
+#[test]
+fn read_overlapping_stride() {
+    let record = padded_record();
+
+    assert_eq!(
+        Err(RawError::InvalidStride { stride: 2, size: 4 }),
+        read_at::<u32, 4>(&record, 3, 2)
+    );
+}
@@ -0,0 +1,71 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "rc")]
+
+use serde::{Deserialize, Serialize};
+use std::{rc::Rc, sync::Arc};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Local {
+    #[serde(with = "serde_arrays::rc")]
+    table: Rc<[u32; 256]>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Shared {
+    #[serde(with = "serde_arrays::rc")]
+    table: Arc<[u32; 256]>,
+}
+
+fn table() -> [u32; 256] {
+    let mut table = [0; 256];
+    for (i, x) in table.iter_mut().enumerate() {
+        *x = i as u32 * 3;
+    }
+    table
+}
+
+#[test]
+fn rc_round_trip() {
+    let obj = Local {
+        table: Rc::new(table()),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn arc_round_trip() {
+    let obj = Shared {
+        table: Arc::new(table()),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Shared = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+
+    // The deserialized array can be shared across threads like any other
+    let shared = Arc::clone(&de.table);
+    let sum = std::thread::spawn(move || shared.iter().sum::<u32>())
+        .join()
+        .unwrap();
+    assert_eq!(table().iter().sum::<u32>(), sum);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 2, expected an array of size 256")]
+fn arc_too_short() {
+    serde_json::from_str::<Shared>(r#"{"table":[1,2]}"#).unwrap();
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Page {
+    #[serde(with = "serde_arrays::rle")]
+    bytes: [u8; 4096],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Small {
+    #[serde(with = "serde_arrays::rle")]
+    arr: [String; 4],
+}
+
+#[test]
+fn rle_constant_array_is_one_run() {
+    let page = Page { bytes: [0; 4096] };
+
+    let j = serde_json::to_string(&page).unwrap();
+    assert_eq!(r#"{"bytes":[[0,4096]]}"#, j);
+    assert_eq!(page, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn rle_runs_round_trip() {
+    let mut page = Page { bytes: [0; 4096] };
+    page.bytes[0] = 1;
+    page.bytes[4000..].copy_from_slice(&[9; 96]);
+
+    let j = serde_json::to_string(&page).unwrap();
+    assert_eq!(r#"{"bytes":[[1,1],[0,3999],[9,96]]}"#, j);
+    assert_eq!(page, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&page).unwrap();
+    assert_eq!(page, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn rle_non_copy_elements() {
+    let obj = Small {
+        arr: [
+            "a".to_string(),
+            "a".to_string(),
+            "b".to_string(),
+            "a".to_string(),
+        ],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[["a",2],["b",1],["a",1]]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn rle_empty_array() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Empty {
+        #[serde(with = "serde_arrays::rle")]
+        arr: [u8; 0],
+    }
+
+    let j = serde_json::to_string(&Empty { arr: [] }).unwrap();
+    assert_eq!(r#"{"arr":[]}"#, j);
+    assert_eq!(Empty { arr: [] }, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn rle_counts_too_few() {
+    let err = serde_json::from_str::<Small>(r#"{"arr":[["a",2],["b",1]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a sequence of runs adding up to 4 elements"));
+}
+
+#[test]
+fn rle_counts_too_many() {
+    let err = serde_json::from_str::<Small>(r#"{"arr":[["a",2],["b",3]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("runs add up to more than 4 elements"));
+
+    let err = serde_json::from_str::<Small>(r#"{"arr":[["a",4],["b",1]]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("runs add up to more than 4 elements"));
+}
+
+#[test]
+fn rle_zero_count_is_error() {
+    let err = serde_json::from_str::<Small>(r#"{"arr":[["a",2],["b",0],["c",2]]}"#).unwrap_err();
+    assert!(err.to_string().starts_with("run 1 has a count of zero"));
+
+    let err = serde_json::from_str::<Small>(r#"{"arr":[["a",4],["b",0]]}"#).unwrap_err();
+    assert!(err.to_string().starts_with("run 1 has a count of zero"));
+}
+
+#[test]
+fn rle_malformed_run_names_it() {
+    let err = serde_json::from_str::<Small>(r#"{"arr":[["a",2],["b"]]}"#).unwrap_err();
+    assert!(err.to_string().starts_with("in run 1: "));
+}
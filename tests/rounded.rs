@@ -0,0 +1,78 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+mod common;
+use common::recorder::{record_binary, Event};
+use serde::{Deserialize, Serialize};
+
+const VALUES: [f64; 5] = [0.1, 1.23456, 2.0, -4.56789, 1234.56789];
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TwoPlaces {
+    #[serde(with = "serde_arrays::rounded::Rounded::<2>")]
+    arr: [f64; 5],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct FourPlaces {
+    #[serde(with = "serde_arrays::rounded::Rounded::<4>")]
+    arr: [f64; 5],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct SinglePrecision {
+    #[serde(with = "serde_arrays::rounded::Rounded::<2>")]
+    arr: [f32; 40],
+}
+
+#[test]
+fn rounded_two_places() {
+    let j = serde_json::to_string(&TwoPlaces { arr: VALUES }).unwrap();
+
+    assert_eq!(r#"{"arr":[0.1,1.23,2.0,-4.57,1234.57]}"#, j);
+}
+
+#[test]
+fn rounded_four_places() {
+    let j = serde_json::to_string(&FourPlaces { arr: VALUES }).unwrap();
+
+    assert_eq!(r#"{"arr":[0.1,1.2346,2.0,-4.5679,1234.5679]}"#, j);
+}
+
+#[test]
+fn rounded_f32() {
+    let j = serde_json::to_string(&SinglePrecision { arr: [0.1; 40] }).unwrap();
+
+    assert!(j.starts_with(r#"{"arr":[0.1,0.1,"#));
+
+    let de: SinglePrecision = serde_json::from_str(&j).unwrap();
+    assert_eq!([0.1; 40], de.arr);
+}
+
+#[test]
+fn rounded_deserializes_unchanged() {
+    let de: TwoPlaces =
+        serde_json::from_str(r#"{"arr":[0.1,1.23456,2.0,-4.56789,1234.56789]}"#).unwrap();
+
+    assert_eq!(VALUES, de.arr);
+}
+
+#[test]
+fn rounded_binary_passthrough() {
+    let events = record_binary(&TwoPlaces { arr: VALUES });
+
+    let floats: Vec<_> = events
+        .into_iter()
+        .filter_map(|e| match e {
+            Event::F64(v) => Some(v),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(VALUES.to_vec(), floats);
+}
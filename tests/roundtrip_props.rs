@@ -0,0 +1,111 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Property tests round-tripping random arrays
+//!
+//! To cover a new array shape, add a struct using the module under test and a strategy producing
+//! arbitrary values of it (see [`array`]), then add a `proptest!` case calling [`assert_round_trip`].
+//! Random contents, unlike `[1; N]`, catch elements being written or read in the wrong order.
+
+use proptest::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{convert::TryInto, fmt::Debug};
+
+/// Strategy for `[T; N]` arrays of elements generated by `element`
+fn array_of<S: Strategy, const N: usize>(element: S) -> impl Strategy<Value = [S::Value; N]> {
+    proptest::collection::vec(element, N).prop_map(|v| match v.try_into() {
+        Ok(arr) => arr,
+        Err(_) => unreachable!("exactly N elements were generated"),
+    })
+}
+
+/// Strategy for `[T; N]` arrays of arbitrary elements
+fn array<T: Arbitrary, const N: usize>() -> impl Strategy<Value = [T; N]> {
+    array_of(any::<T>())
+}
+
+/// Assert that `value` survives a round trip through both JSON and bincode
+fn assert_round_trip<T>(value: &T) -> Result<(), TestCaseError>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let j = serde_json::to_string(value).unwrap();
+    prop_assert_eq!(value, &serde_json::from_str::<T>(&j).unwrap());
+
+    let b = bincode::serialize(value).unwrap();
+    prop_assert_eq!(value, &bincode::deserialize::<T>(&b).unwrap());
+
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Array<T: Serialize + DeserializeOwned, const N: usize>(
+    #[serde(with = "serde_arrays", bound = "")] [T; N],
+);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Nested<const N: usize, const M: usize>(
+    #[serde(with = "serde_arrays::nested")] [[u16; N]; M],
+);
+
+#[cfg(feature = "alloc")]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct NestedVec<const N: usize>(#[serde(with = "serde_arrays::nested")] Vec<[i32; N]>);
+
+proptest! {
+    #[test]
+    fn round_trip_empty(arr in array::<u32, 0>()) {
+        assert_round_trip(&Array(arr))?;
+    }
+
+    #[test]
+    fn round_trip_one(arr in array::<i64, 1>()) {
+        assert_round_trip(&Array(arr))?;
+    }
+
+    #[test]
+    fn round_trip_small(arr in array::<u8, 7>()) {
+        assert_round_trip(&Array(arr))?;
+    }
+
+    #[test]
+    fn round_trip_past_serde_limit(arr in array::<u32, 33>()) {
+        assert_round_trip(&Array(arr))?;
+    }
+
+    #[test]
+    fn round_trip_large(arr in array::<u64, 300>()) {
+        assert_round_trip(&Array(arr))?;
+    }
+
+    #[test]
+    fn round_trip_strings(arr in array::<String, 40>()) {
+        assert_round_trip(&Array(arr))?;
+    }
+
+    #[test]
+    fn round_trip_nested(rows in array_of::<_, 40>(array::<u16, 5>())) {
+        assert_round_trip(&Nested(rows))?;
+    }
+
+    #[test]
+    fn round_trip_nested_large(rows in array_of::<_, 37>(array::<u16, 40>())) {
+        assert_round_trip(&Nested(rows))?;
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip_nested_vec(rows in proptest::collection::vec(array::<i32, 36>(), 0..8)) {
+        assert_round_trip(&NestedVec(rows))?;
+    }
+
+    #[test]
+    fn serialized_in_order(arr in array::<u32, 50>()) {
+        let j = serde_json::to_string(&Array(arr)).unwrap();
+        prop_assert_eq!(serde_json::to_string(&arr.to_vec()).unwrap(), j);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "schemars")]
+
+use schemars::{schema_for, JsonSchema, SchemaGenerator};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct Small {
+    #[serde(with = "serde_arrays")]
+    #[schemars(with = "serde_arrays::schemars::Array<u32, 4>")]
+    arr: [u32; 4],
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct Large {
+    #[serde(with = "serde_arrays")]
+    #[schemars(with = "serde_arrays::schemars::Array<String, 100>")]
+    arr: [String; 100],
+}
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct Path {
+    #[schemars(schema_with = "serde_arrays::schemars::schema::<Point, 64>")]
+    points: [Point; 64],
+}
+
+#[test]
+fn schema_for_small_array() {
+    let schema = schema_for!(Small);
+    let arr = schema.pointer("/properties/arr").unwrap();
+
+    assert_eq!(arr["type"], "array");
+    assert_eq!(arr["minItems"], 4);
+    assert_eq!(arr["maxItems"], 4);
+    assert_eq!(arr["items"]["type"], "integer");
+}
+
+#[test]
+fn schema_matches_schemars_own_arrays() {
+    let mut generator = SchemaGenerator::default();
+    let ours = serde_arrays::schemars::schema::<u32, 4>(&mut generator);
+    let theirs = generator.subschema_for::<[u32; 4]>();
+
+    assert_eq!(ours, theirs);
+}
+
+#[test]
+fn schema_for_large_array() {
+    let schema = schema_for!(Large);
+    let arr = schema.pointer("/properties/arr").unwrap();
+
+    assert_eq!(
+        arr,
+        &json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "minItems": 100,
+            "maxItems": 100,
+        })
+    );
+}
+
+#[test]
+fn schema_with_references_element_definitions() {
+    let schema = schema_for!(Path);
+    let points = schema.pointer("/properties/points").unwrap();
+
+    assert_eq!(points["items"]["$ref"], "#/$defs/Point");
+    assert_eq!(points["minItems"], 64);
+    assert_eq!(points["maxItems"], 64);
+    assert!(schema.pointer("/$defs/Point").is_some());
+}
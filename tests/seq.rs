@@ -0,0 +1,65 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::recorder::{record, Event};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct AsSeq {
+    #[serde(with = "serde_arrays::seq")]
+    arr: [u32; 40],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct AsTuple {
+    #[serde(with = "serde_arrays")]
+    arr: [u32; 40],
+}
+
+#[test]
+fn seq_json_matches_tuple() {
+    let json = format!(r#"{{"arr":{:?}}}"#, [3; 40]).replace(", ", ",");
+
+    let seq: AsSeq = serde_json::from_str(&json).unwrap();
+    let tuple: AsTuple = serde_json::from_str(&json).unwrap();
+    assert_eq!(seq.arr, tuple.arr);
+
+    assert_eq!(json, serde_json::to_string(&seq).unwrap());
+    assert_eq!(json, serde_json::to_string(&tuple).unwrap());
+}
+
+#[test]
+fn seq_declares_its_length() {
+    let events = record(&AsSeq { arr: [0; 40] });
+    assert_eq!(Event::Seq(Some(40)), events[2]);
+
+    let events = record(&AsTuple { arr: [0; 40] });
+    assert_eq!(Event::Tuple(40), events[2]);
+}
+
+#[test]
+fn seq_bincode_differs_from_tuple() {
+    let seq = bincode::serialize(&AsSeq { arr: [3; 40] }).unwrap();
+    let tuple = bincode::serialize(&AsTuple { arr: [3; 40] }).unwrap();
+
+    // bincode writes a sequence's length, but leaves it out for a tuple of known size
+    assert_eq!(40 * 4, tuple.len());
+    assert_eq!(8 + 40 * 4, seq.len());
+    assert_eq!(40u64.to_le_bytes(), seq[..8]);
+    assert_eq!(tuple[..], seq[8..]);
+
+    let de: AsSeq = bincode::deserialize(&seq).unwrap();
+    assert_eq!([3; 40], de.arr);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 39, expected an array of size 40")]
+fn seq_wrong_length() {
+    let json = format!(r#"{{"arr":{:?}}}"#, [3; 39]);
+    let _: AsSeq = serde_json::from_str(&json).unwrap();
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Bytes(#[serde(with = "serde_arrays::serde_bytes")] [u8; 8]);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Elements(#[serde(with = "serde_arrays")] [u8; 8]);
+
+const DATA: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+fn to_cbor<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut buf = Vec::new();
+    ciborium::into_writer(value, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn serde_bytes_cbor_uses_byte_string() {
+    let bytes = to_cbor(&Bytes(DATA));
+    let elements = to_cbor(&Elements(DATA));
+
+    // CBOR major type 2 is a byte string, major type 4 an array; both are of length 8
+    assert_eq!(0x48, bytes[0]);
+    assert_eq!(0x88, elements[0]);
+    assert_eq!(&DATA[..], &bytes[1..]);
+    assert_eq!(&DATA[..], &elements[1..]);
+}
+
+#[test]
+fn serde_bytes_matches_byte_array() {
+    let theirs = serde_bytes::ByteArray::new(DATA);
+
+    assert_eq!(to_cbor(&theirs), to_cbor(&Bytes(DATA)));
+    assert_eq!(
+        bincode::serialize(&theirs).unwrap(),
+        bincode::serialize(&Bytes(DATA)).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_string(&theirs).unwrap(),
+        serde_json::to_string(&Bytes(DATA)).unwrap()
+    );
+
+    let de: serde_bytes::ByteArray<8> = ciborium::from_reader(&to_cbor(&Bytes(DATA))[..]).unwrap();
+    assert_eq!(DATA, de.into_array());
+    let de: Bytes = ciborium::from_reader(&to_cbor(&theirs)[..]).unwrap();
+    assert_eq!(Bytes(DATA), de);
+}
+
+#[test]
+fn serde_bytes_round_trip() {
+    let de: Bytes = ciborium::from_reader(&to_cbor(&Bytes(DATA))[..]).unwrap();
+    assert_eq!(Bytes(DATA), de);
+
+    let b = bincode::serialize(&Bytes(DATA)).unwrap();
+    assert_eq!(Bytes(DATA), bincode::deserialize(&b).unwrap());
+
+    let json = serde_json::to_string(&Bytes(DATA)).unwrap();
+    assert_eq!("[1,2,3,4,5,6,7,8]", json);
+    assert_eq!(Bytes(DATA), serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn serde_bytes_wrong_length() {
+    let cbor = to_cbor(&serde_bytes::ByteArray::new([0u8; 7]));
+    let err = ciborium::from_reader::<Bytes, _>(&cbor[..]).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("invalid length 7, expected a byte string or array of 8 bytes"));
+
+    let err = serde_json::from_str::<Bytes>("[1,2,3,4,5,6,7,8,9]").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 9, expected a byte string or array of 8 bytes"));
+}
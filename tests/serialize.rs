@@ -37,3 +37,141 @@ fn serialize_tuple_struct_with_generic_array() {
 
     assert_eq!("[1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1]", &j);
 }
+
+#[test]
+fn serialize_bincode_has_no_overhead() {
+    #[derive(serde::Serialize)]
+    struct Telemetry {
+        #[serde(with = "serde_arrays")]
+        arr: [u16; 4],
+    }
+
+    let b = bincode::serialize(&Telemetry {
+        arr: [1, 0x0203, 4, 0xFFFF],
+    })
+    .unwrap();
+    assert_eq!(vec![1, 0, 3, 2, 4, 0, 0xFF, 0xFF], b);
+
+    let b = bincode::serialize(&GenericArray::<40> { arr: [7; 40] }).unwrap();
+    assert_eq!(40 * 4, b.len());
+}
+
+#[test]
+fn serialize_tuple_regardless_of_human_readable() {
+    use common::recorder::{record, record_binary, Event};
+
+    let obj = FixedArray { arr: [1; 36] };
+
+    assert_eq!(Event::Tuple(36), record(&obj)[2]);
+    assert_eq!(record(&obj), record_binary(&obj));
+}
+
+/// Serialize anything `Serializable`, as generic serialization layers do
+fn to_json<A, T, const N: usize>(data: A) -> String
+where
+    A: serde_arrays::Serializable<T, N>,
+    T: serde::Serialize,
+{
+    let mut buf = Vec::new();
+    data.serialize(&mut serde_json::Serializer::new(&mut buf))
+        .unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn serialize_through_references() {
+    let arr = [1u32; 36];
+    let expected = serde_json::to_string(&FixedArray { arr }).unwrap();
+    assert_eq!(
+        format!("{{\"arr\":{}}}", to_json::<_, u32, 36>(&arr)),
+        expected
+    );
+    assert_eq!(to_json::<_, u32, 36>(&&arr), to_json::<_, u32, 36>(arr));
+
+    // Arrays of arrays, and arrays of references to arrays, serialize as nested arrays
+    let nested = [[1u8, 2], [3, 4], [5, 6]];
+    assert_eq!("[[1,2],[3,4],[5,6]]", to_json::<_, u8, 2>(&nested));
+
+    let refs = [&nested[0], &nested[2]];
+    assert_eq!("[[1,2],[5,6]]", to_json::<_, u8, 2>(refs));
+    assert_eq!("[[1,2],[5,6]]", to_json::<_, u8, 2>(&refs));
+
+    #[cfg(feature = "alloc")]
+    {
+        let v = vec![[1u8, 2]];
+        assert_eq!("[[1,2]]", to_json::<_, u8, 2>(&v));
+    }
+}
+
+struct Key([u8; 32]);
+
+impl AsRef<[u8; 32]> for Key {
+    fn as_ref(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+#[derive(serde::Serialize)]
+struct Session {
+    #[serde(serialize_with = "serde_arrays::serialize_ref")]
+    key: Key,
+}
+
+#[derive(serde::Serialize)]
+struct Bare {
+    #[serde(with = "serde_arrays")]
+    key: [u8; 32],
+}
+
+#[test]
+fn serialize_as_ref_newtype() {
+    let mut key = [0; 32];
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = i as u8;
+    }
+
+    let session = Session { key: Key(key) };
+    let bare = Bare { key };
+    assert_eq!(
+        serde_json::to_string(&bare).unwrap(),
+        serde_json::to_string(&session).unwrap()
+    );
+    assert_eq!(
+        bincode::serialize(&bare).unwrap(),
+        bincode::serialize(&session).unwrap()
+    );
+    assert_eq!(32, bincode::serialize(&session).unwrap().len());
+}
+
+#[test]
+fn serialize_ref_directly() {
+    let boxed: Box<[u32; 36]> = Box::new([1; 36]);
+
+    let mut json = Vec::new();
+    serde_arrays::serialize_ref(&boxed, &mut serde_json::Serializer::new(&mut json)).unwrap();
+    assert_eq!(
+        serde_json::to_string(&FixedArray { arr: [1; 36] }).unwrap(),
+        format!(r#"{{"arr":{}}}"#, String::from_utf8(json).unwrap())
+    );
+}
+
+#[test]
+fn serializable_len_is_outer_length() {
+    use serde_arrays::Serializable;
+
+    let arr = [0u32; 36];
+    assert_eq!(36, Serializable::<u32, 36>::len(&arr));
+
+    let nested = [[0u32; 3]; 2];
+    assert_eq!(2, Serializable::<u32, 3>::len(&nested));
+
+    #[cfg(feature = "alloc")]
+    {
+        let vec = vec![[0u32; 3]; 5];
+        assert_eq!(5, Serializable::<u32, 3>::len(&vec));
+
+        // References report whatever they refer to
+        assert_eq!(5, Serializable::<u32, 3>::len(&&vec));
+        assert!(Serializable::<u32, 3>::is_empty(&Vec::<[u32; 3]>::new()));
+    }
+}
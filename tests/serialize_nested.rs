@@ -28,6 +28,7 @@ fn serialize_generic_nested_array() {
     assert_eq!(json, &j_generic);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn serialize_array_in_vec() {
     let vecced = VecArray {
@@ -39,3 +40,180 @@ fn serialize_array_in_vec() {
     let json = "{\"arr\":[[1,1,1],[1,1,1]]}";
     assert_eq!(json, &j_vecced);
 }
+
+#[test]
+fn nested_array_length_hints() {
+    use common::recorder::{length_hints, record, record_binary, Event};
+
+    let generic = GenericNestedArray { arr: [[1; 3]; 5] };
+
+    let mut expected = vec![Event::Tuple(5)];
+    expected.extend(vec![Event::Tuple(3); 5]);
+    assert_eq!(expected, length_hints(record(&generic)));
+    assert_eq!(expected, length_hints(record_binary(&generic)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec_array_length_hints() {
+    use common::recorder::{length_hints, record, Event};
+
+    let vecced = VecArray {
+        arr: vec![[1; 36]; 4],
+    };
+
+    let mut expected = vec![Event::Seq(Some(4))];
+    expected.extend(vec![Event::Tuple(36); 4]);
+    assert_eq!(expected, length_hints(record(&vecced)));
+
+    let empty = VecArray::<36> { arr: Vec::new() };
+    assert_eq!(vec![Event::Seq(Some(0))], length_hints(record(&empty)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn nested_module_length_hints() {
+    use common::recorder::{length_hints, record, Event};
+
+    #[derive(serde::Serialize)]
+    struct Grid {
+        #[serde(with = "serde_arrays::nested")]
+        arr: [[[u8; 2]; 3]; 4],
+        #[serde(with = "serde_arrays::nested")]
+        vec: Vec<[[u8; 5]; 6]>,
+        #[serde(with = "serde_arrays::nested::Rows")]
+        rows: [[u8; 7]; 8],
+    }
+
+    let events = length_hints(record(&Grid {
+        arr: [[[0; 2]; 3]; 4],
+        vec: vec![[[0; 5]; 6]; 1],
+        rows: [[0; 7]; 8],
+    }));
+
+    let mut expected = vec![Event::Tuple(4)];
+    for _ in 0..4 {
+        expected.push(Event::Tuple(3));
+        expected.extend(vec![Event::Tuple(2); 3]);
+    }
+    expected.push(Event::Seq(Some(1)));
+    expected.push(Event::Tuple(6));
+    expected.extend(vec![Event::Tuple(5); 6]);
+    expected.push(Event::Tuple(8));
+    expected.extend(vec![Event::Tuple(7); 8]);
+    assert_eq!(expected, events);
+}
+
+#[test]
+fn nested_array_round_trip() {
+    let mut nested = NestedArray { arr: [[0; 36]; 2] };
+    nested.arr[1][35] = 7;
+
+    let json = serde_json::to_string(&nested).unwrap();
+    assert_eq!(nested, serde_json::from_str(&json).unwrap());
+
+    let b = bincode::serialize(&nested).unwrap();
+    assert_eq!(2 * 36 * 4, b.len());
+    assert_eq!(nested, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn generic_nested_array_round_trip() {
+    let mut generic = GenericNestedArray { arr: [[1; 36]; 40] };
+    generic.arr[39][0] = 2;
+
+    let json = serde_json::to_string(&generic).unwrap();
+    assert_eq!(generic, serde_json::from_str(&json).unwrap());
+
+    let b = bincode::serialize(&generic).unwrap();
+    assert_eq!(generic, bincode::deserialize(&b).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn array_in_vec_round_trip() {
+    let vecced = VecArray {
+        arr: vec![[3; 36], [4; 36], [5; 36]],
+    };
+
+    let json = serde_json::to_string(&vecced).unwrap();
+    assert_eq!(vecced, serde_json::from_str(&json).unwrap());
+
+    let b = bincode::serialize(&vecced).unwrap();
+    assert_eq!(vecced, bincode::deserialize(&b).unwrap());
+
+    let empty = VecArray::<36> { arr: Vec::new() };
+    let json = serde_json::to_string(&empty).unwrap();
+    assert_eq!("{\"arr\":[]}", json);
+    assert_eq!(empty, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn nested_array_wrong_inner_length() {
+    let err = serde_json::from_str::<NestedArray<3>>("{\"arr\":[[1,1,1],[1,1]]}").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 2, expected an array of size 3"));
+}
+
+#[test]
+fn nested_serialize_preserves_element_order() {
+    let generic = GenericNestedArray {
+        arr: [[1, 2, 3], [4, 5, 6]],
+    };
+    let json = serde_json::to_string(&generic).unwrap();
+    assert_eq!(r#"{"arr":[[1,2,3],[4,5,6]]}"#, json);
+    assert_eq!(generic, serde_json::from_str(&json).unwrap());
+
+    #[cfg(feature = "alloc")]
+    {
+        let vecced = VecArray {
+            arr: vec![[1, 2, 3], [4, 5, 6]],
+        };
+        let json = serde_json::to_string(&vecced).unwrap();
+        assert_eq!(r#"{"arr":[[1,2,3],[4,5,6]]}"#, json);
+        assert_eq!(vecced, serde_json::from_str(&json).unwrap());
+    }
+
+    let b = bincode::serialize(&generic).unwrap();
+    let expected: Vec<u8> = (1..=6u32).flat_map(u32::to_le_bytes).collect();
+    assert_eq!(expected, b);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn root_serialize_preserves_element_order() {
+    #[derive(serde::Serialize)]
+    struct Root {
+        #[serde(with = "serde_arrays")]
+        vec: Vec<[u32; 3]>,
+    }
+
+    let json = serde_json::to_string(&Root {
+        vec: vec![[7, 8, 9], [10, 11, 12]],
+    })
+    .unwrap();
+    assert_eq!(r#"{"vec":[[7,8,9],[10,11,12]]}"#, json);
+}
+
+#[test]
+fn nested_module_preserves_element_order() {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Cube {
+        #[serde(with = "serde_arrays::nested")]
+        arr: [[[u8; 2]; 2]; 2],
+        #[serde(with = "serde_arrays::nested::Rows")]
+        rows: [[u8; 3]; 2],
+    }
+
+    let cube = Cube {
+        arr: [[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+        rows: [[1, 2, 3], [4, 5, 6]],
+    };
+    let json = serde_json::to_string(&cube).unwrap();
+    assert_eq!(
+        r#"{"arr":[[[1,2],[3,4]],[[5,6],[7,8]]],"rows":[[1,2,3],[4,5,6]]}"#,
+        json
+    );
+    assert_eq!(cube, serde_json::from_str(&json).unwrap());
+}
@@ -0,0 +1,51 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::recorder::{record, Event};
+use serde::Serialize;
+
+/// Slice-backed data that should serialize as an array of size `N`
+struct Window<'a, const N: usize>(&'a [u32]);
+
+impl<const N: usize> Serialize for Window<'_, N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_arrays::serialize_slice::<_, _, N>(self.0, serializer)
+    }
+}
+
+#[test]
+fn serialize_slice_matches_array() {
+    let data: Vec<u32> = (0..100).collect();
+
+    assert_eq!(
+        serde_json::to_string(&data[10..50]).unwrap(),
+        serde_json::to_string(&Window::<40>(&data[10..50])).unwrap()
+    );
+
+    assert_eq!(Event::Tuple(40), record(&Window::<40>(&data[10..50]))[0]);
+    assert_eq!(
+        40 * 4,
+        bincode::serialize(&Window::<40>(&data[10..50]))
+            .unwrap()
+            .len()
+    );
+}
+
+#[test]
+fn serialize_slice_wrong_length() {
+    let data: Vec<u32> = (0..100).collect();
+
+    let err = serde_json::to_string(&Window::<40>(&data[..39])).unwrap_err();
+    assert_eq!("expected a slice of 40 elements, found 39", err.to_string());
+
+    let err = bincode::serialize(&Window::<40>(&data[..41])).unwrap_err();
+    assert_eq!("expected a slice of 40 elements, found 41", err.to_string());
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::convert::TryInto;
+
+/// Counts allocations made by the current thread, so concurrently running tests don't interfere
+struct CountingAlloc;
+
+std::thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Number of allocations `f` makes on this thread
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+const ROWS: usize = 100_000;
+
+/// Rows counting up from 0, built on the heap as 400 KB is a lot for a test thread's stack
+fn rows() -> Box<[[u8; 4]; ROWS]> {
+    let rows: Vec<_> = (0..ROWS as u32).map(u32::to_le_bytes).collect();
+    rows.into_boxed_slice().try_into().unwrap()
+}
+
+#[test]
+fn serialize_large_nested_array_without_allocating() {
+    use bincode::Options;
+
+    let rows = rows();
+    assert_eq!(1, allocations(|| drop(vec![0u8; 16])));
+
+    // bincode into a buffer allocated up front
+    let mut buf = Vec::with_capacity(4 * ROWS);
+    let count = allocations(|| {
+        let opts = bincode::options().with_fixint_encoding();
+        let mut ser = bincode::Serializer::new(&mut buf, opts);
+        serde_arrays::nested::serialize(&*rows, &mut ser).unwrap();
+    });
+    assert_eq!(0, count);
+    assert_eq!(4 * ROWS, buf.len());
+    assert_eq!(&[1, 0, 0, 0], &buf[4..8]);
+    assert_eq!(&(ROWS as u32 - 1).to_le_bytes(), &buf[buf.len() - 4..]);
+
+    // JSON straight into a writer
+    let count = allocations(|| {
+        let mut ser = serde_json::Serializer::new(std::io::sink());
+        serde_arrays::nested::serialize(&*rows, &mut ser).unwrap();
+    });
+    assert_eq!(0, count);
+}
+
+#[test]
+fn serialize_large_root_array_without_allocating() {
+    #[derive(Serialize)]
+    struct Flat<'a> {
+        #[serde(with = "serde_arrays")]
+        values: &'a [u32; ROWS],
+    }
+
+    let values: Box<[u32; ROWS]> = (0..ROWS as u32)
+        .collect::<Vec<_>>()
+        .into_boxed_slice()
+        .try_into()
+        .unwrap();
+    let flat = Flat { values: &values };
+
+    let count = allocations(|| serde_json::to_writer(std::io::sink(), &flat).unwrap());
+    assert_eq!(0, count);
+
+    let count = allocations(|| {
+        bincode::serialize_into(std::io::sink(), &flat).unwrap();
+    });
+    assert_eq!(0, count);
+}
@@ -0,0 +1,82 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Ids {
+    #[serde(with = "serde_arrays::set")]
+    hashed: HashSet<[u8; 16]>,
+    #[serde(with = "serde_arrays::set")]
+    sorted: BTreeSet<[u8; 16]>,
+}
+
+fn ids() -> Ids {
+    Ids {
+        hashed: (0..5).map(|i| [i; 16]).collect(),
+        sorted: (0..5).rev().map(|i| [i * 2; 16]).collect(),
+    }
+}
+
+#[test]
+fn set_json_round_trip() {
+    let obj = ids();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn set_bincode_round_trip() {
+    let obj = ids();
+
+    let b = bincode::serialize(&obj).unwrap();
+    // A length prefix per set, but none per member
+    assert_eq!(8 + 5 * 16 + 8 + 5 * 16, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn set_btree_is_written_in_order() {
+    #[derive(Serialize)]
+    struct Sorted {
+        #[serde(with = "serde_arrays::set")]
+        sorted: BTreeSet<[u8; 2]>,
+    }
+
+    let sorted = vec![[2, 0], [0, 1], [1, 9]].into_iter().collect();
+    let j = serde_json::to_string(&Sorted { sorted }).unwrap();
+    assert_eq!(r#"{"sorted":[[0,1],[1,9],[2,0]]}"#, j);
+}
+
+#[test]
+fn set_duplicates_are_deduplicated() {
+    let one = format!("{:?}", [1u8; 16]);
+    let two = format!("{:?}", [2u8; 16]);
+    let j = format!(
+        r#"{{"hashed":[{},{},{}],"sorted":[{},{},{}]}}"#,
+        one, two, one, two, two, one
+    );
+
+    let obj: Ids = serde_json::from_str(&j).unwrap();
+    assert_eq!(2, obj.hashed.len());
+    assert_eq!(
+        vec![[1; 16], [2; 16]],
+        obj.sorted.into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn set_malformed_member_names_index() {
+    let j = format!(r#"{{"hashed":[{:?},[1,2,3]],"sorted":[]}}"#, [1u8; 16]);
+
+    let err = serde_json::from_str::<Ids>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("at index 1: invalid length 3, expected an array of size 16"));
+}
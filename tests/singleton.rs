@@ -0,0 +1,100 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct One {
+    #[serde(with = "serde_arrays::singleton")]
+    arr: [u32; 1],
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Strict {
+    #[serde(with = "serde_arrays")]
+    arr: [u32; 1],
+}
+
+#[test]
+fn singleton_accepts_array_or_scalar() {
+    let expected = One { arr: [5] };
+    assert_eq!(expected, serde_json::from_str(r#"{"arr":[5]}"#).unwrap());
+    assert_eq!(expected, serde_json::from_str(r#"{"arr":5}"#).unwrap());
+
+    assert_eq!(expected, serde_yaml::from_str("arr: [5]").unwrap());
+    assert_eq!(expected, serde_yaml::from_str("arr: 5").unwrap());
+}
+
+#[test]
+fn singleton_is_still_written_as_array() {
+    let j = serde_json::to_string(&One { arr: [5] }).unwrap();
+    assert_eq!(r#"{"arr":[5]}"#, j);
+}
+
+#[test]
+fn singleton_wrong_array_length_is_error() {
+    let err = serde_json::from_str::<One>(r#"{"arr":[5,6]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 2, expected an array of size 1"));
+}
+
+#[test]
+fn singleton_wrong_scalar_type_is_error() {
+    let err = serde_json::from_str::<One>(r#"{"arr":"five"}"#).unwrap_err();
+    assert!(err.to_string().starts_with("invalid type: string"));
+}
+
+#[test]
+fn singleton_string_and_option_elements() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Named {
+        #[serde(with = "serde_arrays::singleton")]
+        names: [String; 1],
+        #[serde(with = "serde_arrays::singleton")]
+        maybe: [Option<u8>; 1],
+    }
+
+    let named: Named = serde_json::from_str(r#"{"names":"x","maybe":null}"#).unwrap();
+    assert_eq!(
+        Named {
+            names: ["x".to_string()],
+            maybe: [None],
+        },
+        named
+    );
+}
+
+#[test]
+fn singleton_binary_formats_are_unchanged() {
+    let obj = One { arr: [5] };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(4, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+
+    let m = rmp_serde::to_vec(&obj).unwrap();
+    assert_eq!(obj, rmp_serde::from_slice(&m).unwrap());
+}
+
+#[test]
+fn singleton_index_keyed_map() {
+    let expected = One { arr: [5] };
+    assert_eq!(
+        expected,
+        serde_json::from_str(r#"{"arr":{"0":5}}"#).unwrap()
+    );
+}
+
+#[test]
+fn default_path_requires_an_array() {
+    assert_eq!(
+        Strict { arr: [5] },
+        serde_json::from_str(r#"{"arr":[5]}"#).unwrap()
+    );
+    assert!(serde_json::from_str::<Strict>(r#"{"arr":5}"#).is_err());
+}
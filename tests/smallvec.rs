@@ -0,0 +1,64 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "smallvec")]
+
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Path {
+    #[serde(with = "serde_arrays::smallvec")]
+    arr: SmallVec<[u16; 32]>,
+}
+
+#[test]
+fn smallvec_inline_round_trip() {
+    let obj = Path {
+        arr: (0..32).collect(),
+    };
+    assert!(!obj.arr.spilled());
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Path = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+    assert!(!de.arr.spilled());
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Path = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+    assert!(!de.arr.spilled());
+}
+
+#[test]
+fn smallvec_spilled_round_trip() {
+    let obj = Path {
+        arr: (0..100).collect(),
+    };
+    assert!(obj.arr.spilled());
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: Path = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+    assert!(de.arr.spilled());
+
+    let b = bincode::serialize(&obj).unwrap();
+    let de: Path = bincode::deserialize(&b).unwrap();
+    assert_eq!(obj, de);
+    assert!(de.arr.spilled());
+}
+
+#[test]
+fn smallvec_empty() {
+    let obj = Path {
+        arr: SmallVec::new(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
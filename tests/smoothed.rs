@@ -0,0 +1,65 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug)]
+struct Window3 {
+    #[serde(with = "serde_arrays::smoothed::Smoothed::<3>")]
+    arr: [f64; 6],
+}
+
+#[derive(Deserialize, Debug)]
+struct Window4 {
+    #[serde(with = "serde_arrays::smoothed::Smoothed::<4>")]
+    arr: [i32; 6],
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Unsmoothed {
+    #[serde(with = "serde_arrays::smoothed::Smoothed::<1>")]
+    arr: [i32; 36],
+}
+
+const INPUT: &str = r#"{"arr":[3,6,9,3,0,12]}"#;
+
+#[test]
+fn smoothed_window_3() {
+    let de: Window3 = serde_json::from_str(INPUT).unwrap();
+
+    // The ends only average the two positions that exist
+    assert_eq!([4.5, 6.0, 6.0, 4.0, 5.0, 6.0], de.arr);
+}
+
+#[test]
+fn smoothed_even_window_rounds_integers() {
+    let de: Window4 = serde_json::from_str(INPUT).unwrap();
+
+    // Windows: [3,6,9], [3,6,9,3], [6,9,3,0], [9,3,0,12], [3,0,12], [0,12]
+    assert_eq!([6, 5, 5, 6, 5, 6], de.arr);
+}
+
+#[test]
+fn smoothed_window_1_is_unchanged() {
+    let mut arr = [0; 36];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = (i as i32 % 5) * 7;
+    }
+
+    let j = serde_json::to_string(&Unsmoothed { arr }).unwrap();
+    let de: Unsmoothed = serde_json::from_str(&j).unwrap();
+
+    assert_eq!(arr, de.arr);
+}
+
+#[test]
+#[should_panic(expected = "expected an array of size 6")]
+fn smoothed_requires_exact_length() {
+    let _: Window3 = serde_json::from_str(r#"{"arr":[3,6,9]}"#).unwrap();
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Registers {
+    #[serde(with = "serde_arrays::sparse")]
+    regs: [u32; 40],
+}
+
+#[test]
+fn sparse_writes_only_non_default_elements() {
+    let mut obj = Registers { regs: [0; 40] };
+    obj.regs[2] = 99;
+    obj.regs[37] = 42;
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"regs":{"2":99,"37":42}}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn sparse_all_default_is_empty_map() {
+    let obj = Registers { regs: [0; 40] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"regs":{}}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn sparse_fills_missing_indices_with_default() {
+    let obj: Registers = serde_json::from_str(r#"{"regs":{"39":7,"0":1}}"#).unwrap();
+
+    let mut expected = [0; 40];
+    expected[0] = 1;
+    expected[39] = 7;
+    assert_eq!(expected, obj.regs);
+}
+
+#[test]
+fn sparse_out_of_range_index_is_error() {
+    let err = serde_json::from_str::<Registers>(r#"{"regs":{"3":1,"40":2}}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("index 40 is out of range for an array of size 40"));
+}
+
+#[test]
+fn sparse_duplicate_index_is_error() {
+    let err = serde_json::from_str::<Registers>(r#"{"regs":{"3":1,"3":2}}"#).unwrap_err();
+    assert!(err.to_string().starts_with("duplicate index 3"));
+}
+
+#[test]
+fn sparse_with_integer_keys() {
+    let mut obj = Registers { regs: [0; 40] };
+    obj.regs[5] = 12;
+    obj.regs[33] = 34;
+
+    let y = serde_yaml::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_yaml::from_str(&y).unwrap());
+
+    let m = rmp_serde::to_vec(&obj).unwrap();
+    assert_eq!(obj, rmp_serde::from_slice(&m).unwrap());
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Names {
+    #[serde(with = "serde_arrays::sparse")]
+    names: [String; 3],
+}
+
+#[test]
+fn sparse_non_copy_elements() {
+    let obj = Names {
+        names: [String::new(), "b".to_owned(), String::new()],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"names":{"1":"b"}}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
@@ -0,0 +1,87 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// These exercise the unsafe array-building code with heap-owning elements; run them under Miri to
+// check for undefined behavior and leaks:
+//
+//     cargo +nightly miri test --test split
+
+use serde::{Deserialize, Serialize};
+use serde_arrays::{concat_array, split_array};
+
+#[test]
+fn split_and_concat_small() {
+    let (first, rest): ([u8; 1], [u8; 3]) = split_array([1, 2, 3, 4]);
+    assert_eq!([1], first);
+    assert_eq!([2, 3, 4], rest);
+
+    let whole: [u8; 4] = concat_array(first, rest);
+    assert_eq!([1, 2, 3, 4], whole);
+}
+
+#[test]
+fn split_and_concat_empty_halves() {
+    let (none, all): ([u8; 0], [u8; 4]) = split_array([1, 2, 3, 4]);
+    assert_eq!([0u8; 0], none);
+    assert_eq!([1, 2, 3, 4], all);
+
+    let whole: [u8; 4] = concat_array(all, none);
+    assert_eq!([1, 2, 3, 4], whole);
+}
+
+#[test]
+fn split_and_concat_non_copy() {
+    let strings = [
+        "a".to_string(),
+        "b".to_string(),
+        "c".to_string(),
+        "d".to_string(),
+        "e".to_string(),
+    ];
+
+    let (first, last): ([String; 2], [String; 3]) = split_array(strings);
+    assert_eq!(["a", "b"], first);
+    assert_eq!(["c", "d", "e"], last);
+
+    let whole: [String; 5] = concat_array(last, first);
+    assert_eq!(["c", "d", "e", "a", "b"], whole);
+}
+
+#[test]
+#[should_panic(expected = "cannot split an array of size 4 into 1 and 2")]
+fn split_wrong_sizes_panics() {
+    let _: ([u8; 1], [u8; 2]) = split_array([1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "cannot concatenate arrays of size 1 and 3 into 5")]
+fn concat_wrong_sizes_panics() {
+    let _: [u8; 5] = concat_array([1], [2, 3, 4]);
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Keypair {
+    #[serde(with = "serde_arrays")]
+    bytes: [u8; 64],
+}
+
+#[test]
+fn split_deserialized_array() {
+    let mut bytes = [0; 64];
+    bytes[32..].copy_from_slice(&[9; 32]);
+    let j = serde_json::to_string(&Keypair { bytes }).unwrap();
+
+    let keypair: Keypair = serde_json::from_str(&j).unwrap();
+    let (secret, public): ([u8; 32], [u8; 32]) = split_array(keypair.bytes);
+    assert_eq!([0; 32], secret);
+    assert_eq!([9; 32], public);
+
+    let joined = Keypair {
+        bytes: concat_array(secret, public),
+    };
+    assert_eq!(j, serde_json::to_string(&joined).unwrap());
+}
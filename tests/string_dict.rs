@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Labels<const N: usize> {
+    #[serde(with = "serde_arrays::string_dict")]
+    arr: [String; N],
+}
+
+fn dict_len(json: &str) -> usize {
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    value["arr"]["dict"].as_array().unwrap().len()
+}
+
+#[test]
+fn string_dict_all_distinct() {
+    let obj = Labels {
+        arr: ["a".to_string(), "b".to_string(), "c".to_string()],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        "{\"arr\":{\"dict\":[\"a\",\"b\",\"c\"],\"idx\":[0,1,2]}}",
+        &j
+    );
+    assert_eq!(3, dict_len(&j));
+
+    let de: Labels<3> = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn string_dict_all_same() {
+    let json = format!("[{}]", vec!["\"same\""; 40].join(","));
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let obj = Labels::<40> {
+        arr: serde_arrays::deserialize(&mut de).unwrap(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(1, dict_len(&j));
+
+    let de: Labels<40> = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn string_dict_fields_in_any_order() {
+    let de: Labels<3> =
+        serde_json::from_str("{\"arr\":{\"idx\":[1,0,1],\"dict\":[\"x\",\"y\"]}}").unwrap();
+
+    assert_eq!(["y", "x", "y"], de.arr);
+}
+
+#[test]
+#[should_panic(expected = "index 2 at position 1 is out of range for a dictionary of 2 strings")]
+fn string_dict_index_out_of_range() {
+    let _: Labels<3> =
+        serde_json::from_str("{\"arr\":{\"dict\":[\"x\",\"y\"],\"idx\":[1,2,0]}}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "missing field `idx`")]
+fn string_dict_missing_field() {
+    let _: Labels<3> = serde_json::from_str("{\"arr\":{\"dict\":[\"x\",\"y\"]}}").unwrap();
+}
@@ -0,0 +1,107 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+enum Event {
+    Idle,
+    Click(Point),
+    Key(char),
+    Scroll(f64),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct EventLog {
+    #[serde(with = "serde_arrays::tagged")]
+    events: [Event; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum WithStructVariant {
+    A { x: u32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Unsupported {
+    #[serde(with = "serde_arrays::tagged")]
+    arr: [WithStructVariant; 1],
+}
+
+#[test]
+fn tagged_round_trip() {
+    let obj = EventLog {
+        events: [
+            Event::Click(Point { x: 3, y: -4 }),
+            Event::Idle,
+            Event::Key('q'),
+            Event::Scroll(1.5),
+        ],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(
+        "{\"events\":[[1,{\"x\":3,\"y\":-4}],[0,null],[2,\"q\"],[3,1.5]]}",
+        &j
+    );
+
+    let de: EventLog = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn tagged_large_array_round_trip() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Large {
+        #[serde(with = "serde_arrays::tagged")]
+        events: [Event; 40],
+    }
+
+    let mut pairs = vec!["[0,null]"; 40];
+    pairs[7] = "[2,\"z\"]";
+    let json = format!("{{\"events\":[{}]}}", pairs.join(","));
+
+    let de: Large = serde_json::from_str(&json).unwrap();
+    assert_eq!(Event::Idle, de.events[6]);
+    assert_eq!(Event::Key('z'), de.events[7]);
+
+    assert_eq!(json, serde_json::to_string(&de).unwrap());
+}
+
+#[test]
+#[should_panic(expected = "invalid value: integer `7`")]
+fn tagged_unknown_discriminant() {
+    let _: EventLog =
+        serde_json::from_str("{\"events\":[[0,null],[0,null],[7,null],[0,null]]}").unwrap();
+}
+
+#[test]
+fn tagged_pair_with_trailing_elements() {
+    let err = serde_json::from_str::<EventLog>(
+        "{\"events\":[[0,null],[2,\"q\",\"junk\"],[0,null],[0,null]]}",
+    )
+    .unwrap_err();
+
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a [discriminant, payload] pair"));
+}
+
+#[test]
+#[should_panic(expected = "only support unit and newtype enum variants")]
+fn tagged_struct_variant_unsupported() {
+    let _ = serde_json::to_string(&Unsupported {
+        arr: [WithStructVariant::A { x: 1 }],
+    })
+    .unwrap();
+}
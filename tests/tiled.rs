@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Tiled {
+    #[serde(with = "serde_arrays::tiled")]
+    arr: [u32; 6],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Empty {
+    #[serde(with = "serde_arrays::tiled")]
+    arr: [u32; 0],
+}
+
+#[test]
+fn tiled_repeats_pattern() {
+    let t: Tiled = serde_json::from_str(r#"{"arr":[1,2]}"#).unwrap();
+    assert_eq!(t.arr, [1, 2, 1, 2, 1, 2]);
+
+    let t: Tiled = serde_json::from_str(r#"{"arr":[7]}"#).unwrap();
+    assert_eq!(t.arr, [7; 6]);
+
+    let t: Tiled = serde_json::from_str(r#"{"arr":[1,2,3]}"#).unwrap();
+    assert_eq!(t.arr, [1, 2, 3, 1, 2, 3]);
+}
+
+#[test]
+fn tiled_accepts_full_array() {
+    let t = Tiled {
+        arr: [9, 8, 7, 6, 5, 4],
+    };
+
+    let j = serde_json::to_string(&t).unwrap();
+    assert_eq!(j, r#"{"arr":[9,8,7,6,5,4]}"#);
+    assert_eq!(t, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&t).unwrap();
+    assert_eq!(t, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn tiled_pattern_through_bincode() {
+    let b = bincode::serialize(&(vec![4u32, 5],)).unwrap();
+    let t: Tiled = bincode::deserialize(&b).unwrap();
+
+    assert_eq!(t.arr, [4, 5, 4, 5, 4, 5]);
+}
+
+#[test]
+fn tiled_rejects_non_divisible_pattern() {
+    let err = serde_json::from_str::<Tiled>(r#"{"arr":[1,2,3,4]}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.starts_with("invalid length 4, expected a non-empty sequence whose length divides 6"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn tiled_rejects_empty_pattern() {
+    let err = serde_json::from_str::<Tiled>(r#"{"arr":[]}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("invalid length 0, expected"), "{}", err);
+
+    let e: Empty = serde_json::from_str(r#"{"arr":[]}"#).unwrap();
+    assert_eq!(e.arr, [0u32; 0]);
+}
+
+#[test]
+fn tiled_rejects_too_long() {
+    let err = serde_json::from_str::<Tiled>(r#"{"arr":[1,2,3,4,5,6,7]}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("invalid length 7, expected"), "{}", err);
+}
+
+#[test]
+fn tiled_clones_strings() {
+    #[derive(Deserialize)]
+    struct Names {
+        #[serde(with = "serde_arrays::tiled")]
+        arr: [String; 40],
+    }
+
+    let n: Names = serde_json::from_str(r#"{"arr":["a","b","c","d"]}"#).unwrap();
+    assert_eq!(n.arr[37], "b");
+    assert_eq!(n.arr[39], "d");
+}
@@ -0,0 +1,133 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Plain {
+    #[serde(with = "serde_arrays")]
+    arr: [Duration; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Compact {
+    #[serde(with = "serde_arrays::time")]
+    arr: [Duration; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Series {
+    #[serde(with = "serde_arrays")]
+    arr: [Duration; 64],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Stamps {
+    #[serde(with = "serde_arrays")]
+    arr: [SystemTime; 40],
+}
+
+fn durations() -> [Duration; 4] {
+    [
+        Duration::from_secs(0),
+        Duration::from_nanos(1),
+        Duration::new(3, 141_592_653),
+        Duration::from_secs(86_400),
+    ]
+}
+
+#[test]
+fn durations_default_round_trip() {
+    let plain = Plain { arr: durations() };
+
+    let j = serde_json::to_string(&plain).unwrap();
+    assert!(j.starts_with(r#"{"arr":[{"secs":0,"nanos":0},{"secs":0,"nanos":1},"#));
+    assert_eq!(plain, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&plain).unwrap();
+    assert_eq!(plain, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn durations_compact_round_trip() {
+    let compact = Compact { arr: durations() };
+
+    let j = serde_json::to_string(&compact).unwrap();
+    assert_eq!(j, r#"{"arr":[0,1,3141592653,86400000000000]}"#);
+    assert_eq!(compact, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn durations_compact_is_unchanged_for_binary_formats() {
+    let compact = Compact { arr: durations() };
+    let plain = Plain { arr: durations() };
+
+    let b = bincode::serialize(&compact).unwrap();
+    assert_eq!(b, bincode::serialize(&plain).unwrap());
+    assert_eq!(compact, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn durations_compact_rejects_overlong_duration() {
+    let mut arr = durations();
+    arr[2] = Duration::from_secs(u64::MAX);
+    let err = serde_json::to_string(&Compact { arr })
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        err.starts_with("at index 2: duration of 18446744073709551615s is too long"),
+        "{}",
+        err
+    );
+
+    // The largest duration that does fit is fine
+    let mut arr = durations();
+    arr[3] = Duration::from_nanos(u64::MAX);
+    let j = serde_json::to_string(&Compact { arr }).unwrap();
+    assert_eq!(Compact { arr }, serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn durations_compact_rejects_negative_nanos() {
+    let err = serde_json::from_str::<Compact>(r#"{"arr":[0,1,-2,3]}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("invalid value: integer `-2`"), "{}", err);
+}
+
+#[test]
+fn durations_beyond_32_elements() {
+    let mut arr = [Duration::default(); 64];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = Duration::from_millis(i as u64 * 250);
+    }
+    let series = Series { arr };
+
+    let j = serde_json::to_string(&series).unwrap();
+    assert_eq!(series, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&series).unwrap();
+    assert_eq!(series, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn system_times_round_trip() {
+    let mut arr = [UNIX_EPOCH; 40];
+    for (i, item) in arr.iter_mut().enumerate() {
+        *item = UNIX_EPOCH + Duration::new(1_600_000_000 + i as u64, i as u32 * 1000);
+    }
+    let stamps = Stamps { arr };
+
+    let j = serde_json::to_string(&stamps).unwrap();
+    assert_eq!(stamps, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&stamps).unwrap();
+    assert_eq!(stamps, bincode::deserialize(&b).unwrap());
+}
@@ -0,0 +1,79 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+
+use common::expected_array;
+use serde::{Deserialize, Serialize};
+use serde_arrays::transparent::TransparentElement;
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fixed16(i16);
+
+impl TransparentElement for Fixed16 {
+    type Inner = i16;
+
+    fn as_inner(&self) -> &i16 {
+        &self.0
+    }
+
+    fn from_inner(inner: i16) -> Self {
+        Fixed16(inner)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Buffer(#[serde(with = "serde_arrays::transparent")] [Fixed16; 8]);
+
+fn sample() -> Buffer {
+    Buffer([-300, -2, -1, 0, 1, 2, 300, i16::MAX].map(Fixed16))
+}
+
+#[test]
+fn transparent_tokens_are_flat() {
+    let mut tokens = vec![
+        Token::NewtypeStruct { name: "Buffer" },
+        Token::Tuple { len: 8 },
+    ];
+    tokens.extend(sample().0.iter().map(|x| Token::I16(x.0)));
+    tokens.push(Token::TupleEnd);
+
+    assert_tokens(&sample(), &tokens);
+}
+
+#[test]
+fn transparent_json_round_trip() {
+    let j = serde_json::to_string(&sample()).unwrap();
+
+    assert_eq!(j, "[-300,-2,-1,0,1,2,300,32767]");
+    assert_eq!(sample(), serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn transparent_bincode_round_trip() {
+    let b = bincode::serialize(&sample()).unwrap();
+
+    assert_eq!(b.len(), 8 * 2);
+    assert_eq!(sample(), bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn transparent_wrong_length() {
+    assert_de_tokens_error::<Buffer>(
+        &[
+            Token::NewtypeStruct { name: "Buffer" },
+            Token::Tuple { len: 1 },
+            Token::I16(1),
+            Token::TupleEnd,
+        ],
+        &format!(
+            "invalid length 1, expected {}",
+            expected_array::<Fixed16>(8)
+        ),
+    );
+}
@@ -0,0 +1,78 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Truncated {
+    #[serde(with = "serde_arrays::truncating")]
+    arr: [u32; 36],
+}
+
+#[derive(Deserialize, Debug)]
+struct Strings {
+    #[serde(with = "serde_arrays::truncating")]
+    arr: [String; 2],
+}
+
+fn input(len: u32) -> String {
+    format!(r#"{{"arr":{:?}}}"#, (0..len).collect::<Vec<_>>())
+}
+
+#[test]
+fn truncating_ignores_extra_elements() {
+    let obj: Truncated = serde_json::from_str(&input(40)).unwrap();
+
+    let mut expected = [0; 36];
+    for (i, item) in expected.iter_mut().enumerate() {
+        *item = i as u32;
+    }
+    assert_eq!(expected, obj.arr);
+}
+
+#[test]
+fn truncating_exact_round_trip() {
+    let obj: Truncated = serde_json::from_str(&input(36)).unwrap();
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(input(36).replace(", ", ","), j);
+}
+
+#[test]
+fn truncating_ignores_extra_elements_of_any_type() {
+    let obj: Strings = serde_json::from_str(r#"{"arr":["a","b",3,{"c":[4]},null]}"#).unwrap();
+
+    assert_eq!(["a", "b"], obj.arr);
+}
+
+#[test]
+#[should_panic(expected = "invalid length 35, expected an array of at least 36 elements")]
+fn truncating_too_short() {
+    let _: Truncated = serde_json::from_str(&input(35)).unwrap();
+}
+
+#[test]
+fn truncating_malformed_extra_elements_is_an_error() {
+    assert!(serde_json::from_str::<Strings>(r#"{"arr":["a","b","c",}"#).is_err());
+}
+
+#[test]
+fn truncating_bincode_round_trip() {
+    let obj = Truncated {
+        arr: serde_arrays::try_from_fn(|i| Ok::<_, ()>(i as u32)).unwrap(),
+    };
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn truncating_excess_through_bincode_is_error_not_misread() {
+    let b = bincode::serialize(&((0..40).collect::<Vec<u32>>(), 99u32)).unwrap();
+
+    assert!(bincode::deserialize::<(Truncated, u32)>(&b).is_err());
+}
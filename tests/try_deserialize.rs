@@ -0,0 +1,89 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde_arrays::{try_deserialize, ArrayLengthError};
+
+fn from_json<const N: usize>(json: &str) -> Result<[String; N], ArrayLengthError> {
+    let mut de = serde_json::Deserializer::from_str(json);
+    let res = try_deserialize(&mut de).unwrap();
+    de.end().unwrap();
+    res
+}
+
+#[test]
+fn try_deserialize_exact() {
+    assert_eq!(
+        Ok(["a".to_string(), "b".to_string()]),
+        from_json::<2>(r#"["a","b"]"#)
+    );
+}
+
+#[test]
+fn try_deserialize_too_short() {
+    let err = from_json::<4>(r#"["a","b"]"#).unwrap_err();
+
+    assert_eq!(
+        ArrayLengthError {
+            expected: 4,
+            found: 2
+        },
+        err
+    );
+    assert!(err.is_too_short());
+    assert!(!err.is_too_long());
+}
+
+#[test]
+fn try_deserialize_too_long() {
+    let err = from_json::<2>(r#"["a","b","c","d","e"]"#).unwrap_err();
+
+    assert_eq!(
+        ArrayLengthError {
+            expected: 2,
+            found: 5
+        },
+        err
+    );
+    assert!(err.is_too_long());
+    assert!(!err.is_too_short());
+}
+
+#[test]
+fn try_deserialize_other_errors_pass_through() {
+    let mut de = serde_json::Deserializer::from_str(r#"["a",2]"#);
+    let err = try_deserialize::<_, String, 2>(&mut de).unwrap_err();
+    assert!(err.to_string().starts_with("invalid type: integer `2`"));
+
+    let mut de = serde_json::Deserializer::from_str(r#""ab""#);
+    let err = try_deserialize::<_, String, 2>(&mut de).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid type: string \"ab\", expected an array of size 2"));
+}
+
+#[test]
+fn array_length_error_message_matches_serde() {
+    let err = from_json::<36>("[]").unwrap_err();
+    assert_eq!(
+        "invalid length 0, expected an array of size 36",
+        err.to_string()
+    );
+
+    let mut de = serde_json::Deserializer::from_str("[]");
+    let serde_err = serde_arrays::deserialize::<_, u32, 36>(&mut de).unwrap_err();
+    assert!(serde_err.to_string().starts_with(&err.to_string()));
+}
+
+#[test]
+fn try_deserialize_bincode() {
+    use bincode::Options;
+
+    let b = bincode::serialize(&[1u16; 8]).unwrap();
+    let opts = bincode::options().with_fixint_encoding();
+    let mut de = bincode::Deserializer::from_slice(&b, opts);
+    assert_eq!(Ok([1; 8]), try_deserialize::<_, u16, 8>(&mut de).unwrap());
+}
@@ -0,0 +1,69 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// These exercise the unsafe array-building code with heap-owning elements; run them under Miri to
+// check for undefined behavior and leaks:
+//
+//     cargo +nightly miri test --test try_from_fn
+
+mod common;
+use common::drops::{live, Counted};
+use serde_arrays::try_from_fn;
+use std::cell::{Cell, RefCell};
+
+#[test]
+fn try_from_fn_builds_in_index_order() {
+    let calls = RefCell::new(Vec::new());
+    let arr: [String; 40] = try_from_fn(|idx| {
+        calls.borrow_mut().push(idx);
+        Ok::<_, ()>(idx.to_string())
+    })
+    .unwrap();
+
+    assert_eq!("0", arr[0]);
+    assert_eq!("39", arr[39]);
+    assert_eq!((0..40).collect::<Vec<_>>(), calls.into_inner());
+}
+
+#[test]
+fn try_from_fn_empty_never_calls() {
+    let arr: [u8; 0] = try_from_fn(|_| -> Result<u8, ()> { panic!("never called") }).unwrap();
+    assert_eq!([0u8; 0], arr);
+}
+
+#[test]
+fn try_from_fn_error_at_index_2_drops_earlier_elements() {
+    let calls = Cell::new(0);
+
+    let result: Result<[Counted<String>; 5], String> = try_from_fn(|idx| {
+        calls.set(calls.get() + 1);
+        if idx == 2 {
+            Err(format!("failed at {}", idx))
+        } else {
+            Ok(Counted::new(idx.to_string()))
+        }
+    });
+
+    assert_eq!("failed at 2", result.err().unwrap());
+    assert_eq!(3, calls.get());
+    assert_eq!(0, live());
+}
+
+#[test]
+fn try_from_fn_panic_drops_earlier_elements() {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        try_from_fn::<_, (), _, 5>(|idx| {
+            if idx == 2 {
+                panic!("boom");
+            }
+            Ok(Counted::new(idx.to_string()))
+        })
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(0, live());
+}
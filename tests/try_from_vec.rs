@@ -0,0 +1,63 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// These exercise the unsafe array-building code with heap-owning elements; run them under Miri to
+// check for undefined behavior and leaks:
+//
+//     cargo +nightly miri test --test try_from_vec
+
+#![cfg(feature = "alloc")]
+
+use serde_arrays::try_from_vec;
+
+fn strings(len: usize) -> Vec<String> {
+    (0..len).map(|i| i.to_string()).collect()
+}
+
+#[test]
+fn try_from_vec_exact() {
+    let arr: [String; 4] = try_from_vec(strings(4)).unwrap();
+    assert_eq!(["0", "1", "2", "3"], arr);
+
+    let arr: [u32; 40] = try_from_vec(vec![7; 40]).unwrap();
+    assert_eq!([7; 40], arr);
+
+    let arr: [u32; 0] = try_from_vec(Vec::new()).unwrap();
+    assert_eq!([0u32; 0], arr);
+}
+
+#[test]
+fn try_from_vec_short() {
+    let vec = strings(3);
+    let ptr = vec.as_ptr();
+
+    let err = try_from_vec::<_, 4>(vec).unwrap_err();
+    assert_eq!(strings(3), err);
+    // The very same vector is handed back
+    assert!(std::ptr::eq(ptr, err.as_ptr()));
+}
+
+#[test]
+fn try_from_vec_long() {
+    let err = try_from_vec::<_, 4>(strings(5)).unwrap_err();
+    assert_eq!(strings(5), err);
+
+    let err = try_from_vec::<u32, 0>(vec![1]).unwrap_err();
+    assert_eq!(vec![1], err);
+}
+
+#[test]
+fn try_from_vec_matches_std() {
+    use std::convert::TryFrom;
+
+    for len in 0..6 {
+        assert_eq!(
+            <[String; 4]>::try_from(strings(len)),
+            try_from_vec::<_, 4>(strings(len))
+        );
+    }
+}
@@ -0,0 +1,56 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug)]
+#[allow(dead_code)]
+struct Mixed {
+    #[serde(with = "serde_arrays")]
+    ids: [u32; 4],
+    #[serde(with = "serde_arrays")]
+    deltas: [i8; 4],
+}
+
+#[test]
+#[cfg(feature = "type-names")]
+fn type_names_appear_in_errors() {
+    let err = serde_json::from_str::<Mixed>(r#"{"ids":[1,2,3],"deltas":[1,2,3,4]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of size 4 of `u32`"));
+
+    let err = serde_json::from_str::<Mixed>(r#"{"ids":[1,2,3,4],"deltas":[1,2,3]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of size 4 of `i8`"));
+}
+
+#[test]
+#[cfg(feature = "type-names")]
+fn type_names_in_other_modules() {
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct Boxed {
+        #[serde(with = "serde_arrays::boxed")]
+        arr: Box<[u16; 2]>,
+    }
+
+    let err = serde_json::from_str::<Boxed>(r#"{"arr":[1]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 1, expected an array of size 2 of `u16`"));
+}
+
+#[test]
+#[cfg(not(feature = "type-names"))]
+fn type_names_absent_by_default() {
+    let err = serde_json::from_str::<Mixed>(r#"{"ids":[1,2,3],"deltas":[1,2,3,4]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of size 4 at line 1"));
+}
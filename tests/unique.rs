@@ -0,0 +1,58 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "std")]
+
+use serde_arrays::deserialize_unique;
+
+#[test]
+fn deserialize_unique_preserves_first_seen_order() {
+    let mut de = serde_json::Deserializer::from_str("[5,3,5,1,3,3,9,1]");
+
+    let unique = deserialize_unique::<_, u32, 8>(&mut de).unwrap();
+
+    assert_eq!(vec![5, 3, 1, 9], unique);
+}
+
+#[test]
+fn deserialize_unique_strings() {
+    let mut de = serde_json::Deserializer::from_str("[\"red\",\"red\",\"blue\",\"red\"]");
+
+    let unique = deserialize_unique::<_, String, 4>(&mut de).unwrap();
+
+    assert_eq!(vec!["red".to_string(), "blue".to_string()], unique);
+}
+
+#[test]
+fn deserialize_unique_without_clone() {
+    #[derive(serde::Deserialize, Debug, PartialEq, Eq, Hash)]
+    struct Tag(u8);
+
+    let mut de = serde_json::Deserializer::from_str("[2,7,2,7,4]");
+
+    let unique = deserialize_unique::<_, Tag, 5>(&mut de).unwrap();
+
+    assert_eq!(vec![Tag(2), Tag(7), Tag(4)], unique);
+}
+
+#[test]
+fn deserialize_unique_all_distinct() {
+    let json = format!("{:?}", (0..36).collect::<Vec<u32>>());
+    let mut de = serde_json::Deserializer::from_str(&json);
+
+    let unique = deserialize_unique::<_, u32, 36>(&mut de).unwrap();
+
+    assert_eq!((0..36).collect::<Vec<u32>>(), unique);
+}
+
+#[test]
+#[should_panic(expected = "expected an array of size 8")]
+fn deserialize_unique_requires_exact_length() {
+    let mut de = serde_json::Deserializer::from_str("[5,3,5,1,3,3,9]");
+
+    let _ = deserialize_unique::<_, u32, 8>(&mut de).unwrap();
+}
@@ -0,0 +1,59 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::{Deserialize, Serialize};
+use serde_arrays::unwrap_field::FieldName;
+
+struct V;
+
+impl FieldName for V {
+    const NAME: &'static str = "v";
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Wrapped {
+    #[serde(with = "serde_arrays::unwrap_field::UnwrapField::<V>")]
+    arr: [i32; 3],
+}
+
+#[test]
+fn unwrap_field_round_trip() {
+    let obj = Wrapped { arr: [1, -2, 3] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!("{\"arr\":[{\"v\":1},{\"v\":-2},{\"v\":3}]}", &j);
+
+    let de: Wrapped = serde_json::from_str(&j).unwrap();
+    assert_eq!(obj, de);
+}
+
+#[test]
+fn unwrap_field_ignores_other_fields() {
+    let obj: Wrapped =
+        serde_json::from_str("{\"arr\":[{\"v\":1},{\"x\":0,\"v\":2},{\"v\":3,\"y\":[]}]}").unwrap();
+
+    assert_eq!(Wrapped { arr: [1, 2, 3] }, obj);
+}
+
+#[test]
+#[should_panic(expected = "missing field `v`")]
+fn unwrap_field_missing() {
+    let _: Wrapped = serde_json::from_str("{\"arr\":[{\"v\":1},{\"w\":2},{\"v\":3}]}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "duplicate field `v`")]
+fn unwrap_field_duplicate() {
+    let _: Wrapped =
+        serde_json::from_str("{\"arr\":[{\"v\":1},{\"v\":2,\"v\":2},{\"v\":3}]}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "expected an array of size 3")]
+fn unwrap_field_wrong_length() {
+    let _: Wrapped = serde_json::from_str("{\"arr\":[{\"v\":1},{\"v\":2}]}").unwrap();
+}
@@ -0,0 +1,107 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::drops::{live, Counted};
+use serde::{Deserialize, Deserializer};
+
+fn in_range(_: usize, reading: &u16) -> Result<(), String> {
+    if *reading < 1000 {
+        Ok(())
+    } else {
+        Err(format!("{} is out of range", reading))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Packet([u16; 32]);
+
+impl<'de> Deserialize<'de> for Packet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        serde_arrays::deserialize_validated(deserializer, in_range).map(Packet)
+    }
+}
+
+fn readings() -> Vec<u16> {
+    (0..32).map(|i| i * 10).collect()
+}
+
+#[test]
+fn validated_accepts_valid() {
+    let j = serde_json::to_string(&readings()).unwrap();
+    let de: Packet = serde_json::from_str(&j).unwrap();
+    assert_eq!(&readings()[..], &de.0[..]);
+
+    // Arrays are tuples, so bincode doesn't write a length prefix for them
+    let b = bincode::serialize(&readings()).unwrap();
+    let de: Packet = bincode::deserialize(&b[8..]).unwrap();
+    assert_eq!(&readings()[..], &de.0[..]);
+}
+
+#[test]
+fn validated_rejects_invalid_element() {
+    let mut values = readings();
+    values[5] = 5000;
+    let j = serde_json::to_string(&values).unwrap();
+
+    let err = serde_json::from_str::<Packet>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid element at index 5: 5000 is out of range"));
+}
+
+#[test]
+fn validated_stops_at_first_invalid_element() {
+    let mut seen = Vec::new();
+    let mut de = serde_json::Deserializer::from_str("[1, 2, 3, -1, 5, 6]");
+
+    let res = serde_arrays::deserialize_validated::<_, i32, _, _, 6>(&mut de, |idx, &val| {
+        seen.push(idx);
+        if val < 0 {
+            Err("negative")
+        } else {
+            Ok(())
+        }
+    });
+
+    assert!(res.is_err());
+    assert_eq!(vec![0, 1, 2, 3], seen);
+}
+
+#[test]
+fn validated_checks_length() {
+    let j = serde_json::to_string(&readings()[..31]).unwrap();
+    let err = serde_json::from_str::<Packet>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 31, expected an array of size 32"));
+
+    let j = serde_json::to_string(&vec![1; 33]).unwrap();
+    let err = serde_json::from_str::<Packet>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 33, expected an array of size 32"));
+}
+
+#[test]
+fn validated_drops_built_elements() {
+    let mut values = readings();
+    values[5] = 5000;
+    let j = serde_json::to_string(&values).unwrap();
+    let mut de = serde_json::Deserializer::from_str(&j);
+
+    let res =
+        serde_arrays::deserialize_validated::<_, Counted<u16>, _, _, 32>(&mut de, |_, val| {
+            in_range(0, &val.0)
+        });
+
+    assert!(res.is_err());
+    assert_eq!(0, live());
+}
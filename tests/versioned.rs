@@ -0,0 +1,94 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod common;
+use common::ramp;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct V1 {
+    #[serde(with = "serde_arrays::versioned::Versioned::<1>")]
+    arr: [u32; 40],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct V2 {
+    #[serde(with = "serde_arrays::versioned::Versioned::<2>")]
+    arr: [u32; 40],
+}
+
+fn sample() -> V1 {
+    V1 { arr: ramp(5) }
+}
+
+#[test]
+fn versioned_json_round_trip() {
+    let j = serde_json::to_string(&sample()).unwrap();
+
+    assert!(j.starts_with(r#"{"arr":{"v":1,"data":[0,5,10,"#));
+    assert_eq!(sample(), serde_json::from_str(&j).unwrap());
+}
+
+#[test]
+fn versioned_bincode_round_trip() {
+    let b = bincode::serialize(&sample()).unwrap();
+
+    // The version is written first, followed by the bare elements
+    assert_eq!(&b[..4], &1u32.to_le_bytes());
+    assert_eq!(b.len(), 4 + 40 * 4);
+    assert_eq!(sample(), bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn versioned_json_accepts_fields_in_any_order() {
+    let j = r#"{"arr":{"data":[1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,33,34,35,36,37,38,39,40],"v":1}}"#;
+    let v: V1 = serde_json::from_str(j).unwrap();
+
+    assert_eq!(v.arr[39], 40);
+}
+
+#[test]
+fn versioned_json_rejects_other_versions() {
+    let j = serde_json::to_string(&sample()).unwrap();
+    let err = serde_json::from_str::<V2>(&j).unwrap_err().to_string();
+
+    assert!(
+        err.starts_with("unsupported version 1 (expected 2)"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn versioned_bincode_rejects_other_versions() {
+    let b = bincode::serialize(&sample()).unwrap();
+    let err = bincode::deserialize::<V2>(&b).unwrap_err().to_string();
+
+    assert_eq!(err, "unsupported version 1 (expected 2)");
+}
+
+#[test]
+fn versioned_json_rejects_missing_version() {
+    let j = r#"{"arr":{"data":[]}}"#;
+    let err = serde_json::from_str::<V1>(j).unwrap_err().to_string();
+
+    assert!(err.contains("invalid length 0"), "{}", err);
+
+    let j = r#"{"arr":{"data":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0]}}"#;
+    let err = serde_json::from_str::<V1>(j).unwrap_err().to_string();
+
+    assert!(err.starts_with("missing field `v`"), "{}", err);
+}
+
+#[test]
+fn versioned_json_rejects_missing_data() {
+    let err = serde_json::from_str::<V1>(r#"{"arr":{"v":1}}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(err.starts_with("missing field `data`"), "{}", err);
+}
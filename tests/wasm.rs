@@ -0,0 +1,77 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+use common::recorder::{record, Event};
+use serde::{Deserialize, Serialize};
+use serde_arrays::raw::Pod;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Words {
+    #[serde(with = "serde_arrays::wasm")]
+    arr: [u32; 2],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Mixed {
+    #[serde(with = "serde_arrays::wasm")]
+    ints: [i16; 36],
+    #[serde(with = "serde_arrays::wasm")]
+    floats: [f64; 3],
+}
+
+#[test]
+fn wasm_exact_le_bytes() {
+    let events = record(&Words {
+        arr: [0x0102_0304, 0xA0B0_C0D0],
+    });
+
+    assert_eq!(
+        Event::Bytes(vec![0x04, 0x03, 0x02, 0x01, 0xD0, 0xC0, 0xB0, 0xA0]),
+        events[2]
+    );
+}
+
+#[test]
+fn pod_le_helpers() {
+    let mut out = [0; 4];
+    0x0102_0304u32.write_le(&mut out);
+    assert_eq!([0x04, 0x03, 0x02, 0x01], out);
+    assert_eq!(0x0102_0304, u32::read_le(&out));
+
+    // Big-endian bytes are byte-swapped when read as little-endian, whatever the host
+    assert_eq!(0x0403_0201, u32::read_le(&0x0102_0304u32.to_be_bytes()));
+}
+
+#[test]
+fn wasm_round_trip() {
+    let mut ints = [0; 36];
+    for (i, item) in ints.iter_mut().enumerate() {
+        *item = -(i as i16) * 300;
+    }
+    let obj = Mixed {
+        ints,
+        floats: [0.5, -1e300, f64::MIN_POSITIVE],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert_eq!(8 + 36 * 2 + 8 + 3 * 8, b.len());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+#[should_panic(
+    expected = "invalid length 7, expected 8 little-endian bytes holding an array of size 2"
+)]
+fn wasm_wrong_byte_count() {
+    let _: Words = serde_json::from_str(r#"{"arr":[1,0,0,0,2,0,0]}"#).unwrap();
+}
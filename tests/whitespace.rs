@@ -0,0 +1,74 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#![cfg(feature = "alloc")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct FloatVector {
+    #[serde(with = "serde_arrays::whitespace")]
+    arr: [f64; 4],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct IntVector {
+    #[serde(with = "serde_arrays::whitespace")]
+    arr: [u32; 36],
+}
+
+#[test]
+fn serialize_whitespace() {
+    let obj = FloatVector {
+        arr: [1.0, 2.5, -3.0, 0.125],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+
+    assert_eq!("{\"arr\":\"1 2.5 -3 0.125\"}", &j);
+}
+
+#[test]
+fn deserialize_irregular_whitespace() {
+    let obj: FloatVector =
+        serde_json::from_str("{\"arr\":\"  1.0 \\t2.5\\n\\n-3   0.125 \"}").unwrap();
+
+    assert_eq!(
+        FloatVector {
+            arr: [1.0, 2.5, -3.0, 0.125]
+        },
+        obj
+    );
+}
+
+#[test]
+fn whitespace_round_trip() {
+    let obj = IntVector { arr: [7; 36] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    let de: IntVector = serde_json::from_str(&j).unwrap();
+
+    assert_eq!(obj, de);
+}
+
+#[test]
+#[should_panic(expected = "expected a string of 4 whitespace-separated values")]
+fn deserialize_too_few_tokens() {
+    let _: FloatVector = serde_json::from_str("{\"arr\":\"1 2 3\"}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "expected a string of 4 whitespace-separated values")]
+fn deserialize_too_many_tokens() {
+    let _: FloatVector = serde_json::from_str("{\"arr\":\"1 2 3 4 5\"}").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "invalid value \\\"two\\\"")]
+fn deserialize_invalid_token() {
+    let _: FloatVector = serde_json::from_str("{\"arr\":\"1 two 3 4\"}").unwrap();
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Arrays written as plain sequences by other tools, e.g. from a `Vec`, must deserialize through
+//! the tuple-based `deserialize` wherever the format is self-describing.
+
+mod common;
+use common::ramp;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, PartialEq)]
+struct Data {
+    #[serde(with = "serde_arrays")]
+    arr: [u32; 36],
+}
+
+/// The same data as written by a tool that knows nothing of fixed-size arrays
+#[derive(Serialize)]
+struct SeqData {
+    arr: Vec<u32>,
+}
+
+fn data() -> (Data, SeqData) {
+    let arr = ramp(1000);
+    (Data { arr }, SeqData { arr: arr.to_vec() })
+}
+
+fn short() -> SeqData {
+    SeqData { arr: vec![1, 2, 3] }
+}
+
+#[test]
+fn wire_shapes_json_seq() {
+    let (expected, seq) = data();
+    let j = serde_json::to_string(&seq).unwrap();
+    assert_eq!(expected, serde_json::from_str(&j).unwrap());
+
+    let j = serde_json::to_string(&short()).unwrap();
+    assert!(serde_json::from_str::<Data>(&j).is_err());
+}
+
+#[test]
+fn wire_shapes_msgpack_seq() {
+    let (expected, seq) = data();
+    let m = rmp_serde::to_vec(&seq).unwrap();
+    assert_eq!(expected, rmp_serde::from_slice(&m).unwrap());
+
+    let m = rmp_serde::to_vec_named(&seq).unwrap();
+    assert_eq!(expected, rmp_serde::from_slice(&m).unwrap());
+
+    let m = rmp_serde::to_vec(&short()).unwrap();
+    assert!(rmp_serde::from_slice::<Data>(&m).is_err());
+}
+
+#[test]
+fn wire_shapes_cbor_seq() {
+    let (expected, seq) = data();
+    let mut c = Vec::new();
+    ciborium::into_writer(&seq, &mut c).unwrap();
+    assert_eq!(expected, ciborium::from_reader(&c[..]).unwrap());
+
+    let mut c = Vec::new();
+    ciborium::into_writer(&short(), &mut c).unwrap();
+    assert!(ciborium::from_reader::<Data, _>(&c[..]).is_err());
+}
+
+#[test]
+fn wire_shapes_yaml_seq() {
+    let (expected, seq) = data();
+    let y = serde_yaml::to_string(&seq).unwrap();
+    assert_eq!(expected, serde_yaml::from_str(&y).unwrap());
+
+    let y = serde_yaml::to_string(&short()).unwrap();
+    assert!(serde_yaml::from_str::<Data>(&y).is_err());
+}
+
+#[test]
+fn wire_shapes_bincode_seq_needs_seq_module() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct SeqArray {
+        #[serde(with = "serde_arrays::seq")]
+        arr: [u32; 36],
+    }
+
+    // Bincode isn't self-describing: a sequence carries a length prefix that a tuple doesn't, so
+    // the two shapes can't be told apart and a sequence must be read with `seq` instead
+    let (expected, seq) = data();
+    let b = bincode::serialize(&seq).unwrap();
+    assert_ne!(
+        Some(&expected),
+        bincode::deserialize::<Data>(&b).ok().as_ref()
+    );
+    assert_eq!(
+        expected.arr,
+        bincode::deserialize::<SeqArray>(&b).unwrap().arr
+    );
+}
@@ -0,0 +1,111 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use serde::Deserialize;
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct Config {
+    #[serde(with = "serde_arrays")]
+    defaults: [u32; 4],
+    #[serde(with = "serde_arrays")]
+    current: [u32; 4],
+}
+
+#[derive(Deserialize, Debug, PartialEq, Eq)]
+struct Corners {
+    #[serde(with = "serde_arrays")]
+    points: [Point; 4],
+}
+
+#[test]
+fn whole_array_alias() {
+    let yaml = "
+defaults: &defaults [1, 2, 3, 4]
+current: *defaults
+";
+
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!([1, 2, 3, 4], config.defaults);
+    assert_eq!(config.defaults, config.current);
+}
+
+#[test]
+fn element_aliases() {
+    let yaml = "
+defaults: [&one 1, &two 2, *one, *two]
+current: [*two, *two, *one, 7]
+";
+
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!([1, 2, 1, 2], config.defaults);
+    assert_eq!([2, 2, 1, 7], config.current);
+}
+
+#[test]
+fn struct_element_aliases() {
+    let yaml = "
+points:
+  - &origin {x: 0, y: 0}
+  - {x: 5, y: 0}
+  - *origin
+  - {x: 0, y: 5}
+";
+
+    let corners: Corners = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(Point { x: 0, y: 0 }, corners.points[0]);
+    assert_eq!(corners.points[0], corners.points[2]);
+}
+
+#[test]
+fn large_array_alias() {
+    #[derive(Deserialize)]
+    struct Large {
+        #[serde(with = "serde_arrays")]
+        a: [u8; 40],
+        #[serde(with = "serde_arrays")]
+        b: [u8; 40],
+    }
+
+    let yaml = format!("a: &a {:?}\nb: *a\n", [9u8; 40]);
+
+    let large: Large = serde_yaml::from_str(&yaml).unwrap();
+
+    assert_eq!([9; 40], large.a);
+    assert_eq!(large.a, large.b);
+}
+
+#[test]
+fn alias_with_wrong_length() {
+    #[derive(Deserialize, Debug)]
+    #[allow(dead_code)]
+    struct Longer {
+        #[serde(with = "serde_arrays")]
+        defaults: [u32; 4],
+        #[serde(with = "serde_arrays")]
+        current: [u32; 5],
+    }
+
+    let yaml = "
+defaults: &defaults [1, 2, 3, 4]
+current: *defaults
+";
+
+    let err = serde_yaml::from_str::<Longer>(yaml).unwrap_err();
+
+    assert!(err
+        .to_string()
+        .contains("invalid length 4, expected an array of size 5"));
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// Zero-length arrays skip the element loop entirely, so these tests exercise the edges of the
+// array-building code. Run them under Miri to check for undefined behavior:
+//
+//     cargo +nightly miri test --test zero_length miri_
+
+use serde::{Deserialize, Serialize};
+
+mod common;
+use common::recorder::{record, Event};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Empty {
+    #[serde(with = "serde_arrays")]
+    arr: [u32; 0],
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct EmptyStrings {
+    #[serde(with = "serde_arrays")]
+    arr: [String; 0],
+}
+
+#[test]
+fn miri_zero_length_round_trip() {
+    let obj = Empty { arr: [] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(r#"{"arr":[]}"#, j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert!(b.is_empty());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn zero_length_serializes_empty_tuple() {
+    assert_eq!(
+        vec![
+            Event::Struct("Empty", 1),
+            Event::Field("arr"),
+            Event::Tuple(0),
+            Event::End,
+            Event::End,
+        ],
+        record(&Empty { arr: [] })
+    );
+}
+
+#[test]
+fn miri_zero_length_rejects_elements() {
+    let err = serde_json::from_str::<Empty>(r#"{"arr":[1,2,3]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected an array of size 0"));
+
+    let err = serde_json::from_str::<EmptyStrings>(r#"{"arr":["a"]}"#).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 1, expected an array of size 0"));
+}
+
+#[test]
+fn miri_zero_length_drop_types() {
+    let obj: EmptyStrings = serde_json::from_str(r#"{"arr":[]}"#).unwrap();
+    assert_eq!(0, obj.arr.len());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn miri_zero_length_boxed_and_nested() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Shapes {
+        #[serde(with = "serde_arrays::boxed")]
+        boxed: Box<[String; 0]>,
+        #[serde(with = "serde_arrays::nested")]
+        no_rows: [[u8; 40]; 0],
+        #[serde(with = "serde_arrays::nested")]
+        empty_rows: [[u8; 0]; 40],
+    }
+
+    let obj = Shapes {
+        boxed: Box::new([]),
+        no_rows: [],
+        empty_rows: [[]; 40],
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert!(j.starts_with(r#"{"boxed":[],"no_rows":[],"empty_rows":[[],[],"#));
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
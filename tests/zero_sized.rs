@@ -0,0 +1,106 @@
+// Copyright 2021 Travis Veazey
+//
+// Licensed under the Apache License, Version 2.0, <LICENSE-APACHE or
+// https://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// https://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+// Arrays of zero-sized elements take up no memory at all, so every pointer the array-building
+// code writes through is the same dangling one. Run these under Miri to check for undefined
+// behavior and leaks:
+//
+//     cargo +nightly miri test --test zero_sized miri_
+
+mod common;
+use common::drops::{live, Counted};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Units {
+    #[serde(with = "serde_arrays")]
+    arr: [(); 40],
+}
+
+#[test]
+fn miri_unit_array_round_trip() {
+    let obj = Units { arr: [(); 40] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(format!(r#"{{"arr":[null{}]}}"#, ",null".repeat(39)), j);
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert!(b.is_empty());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}
+
+#[test]
+fn miri_unit_array_wrong_length() {
+    let j = format!(r#"{{"arr":[null{}]}}"#, ",null".repeat(38));
+    let err = serde_json::from_str::<Units>(&j).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 39, expected an array of size 40"));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Marker;
+
+#[test]
+fn miri_unit_struct_array_round_trip() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Markers {
+        #[serde(with = "serde_arrays")]
+        arr: [Marker; 36],
+    }
+
+    let obj = Markers {
+        arr: serde_arrays::try_from_fn(|_| Ok::<_, ()>(Marker)).unwrap(),
+    };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+}
+
+#[derive(Deserialize, Debug)]
+struct CountedArray {
+    #[serde(with = "serde_arrays")]
+    #[allow(dead_code)]
+    arr: [Counted; 40],
+}
+
+#[test]
+fn miri_zero_sized_drops() {
+    let j = format!(r#"{{"arr":[null{}]}}"#, ",null".repeat(39));
+    let obj: CountedArray = serde_json::from_str(&j).unwrap();
+    assert_eq!(40, live());
+    drop(obj);
+    assert_eq!(0, live());
+
+    // Failing part way through drops the elements already built
+    let j = format!(r#"{{"arr":[null{},1]}}"#, ",null".repeat(19));
+    assert!(serde_json::from_str::<CountedArray>(&j).is_err());
+    assert_eq!(0, live());
+
+    let j = format!(r#"{{"arr":[null{}]}}"#, ",null".repeat(20));
+    assert!(serde_json::from_str::<CountedArray>(&j).is_err());
+    assert_eq!(0, live());
+}
+
+#[test]
+fn unit_array_large() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+    struct Many {
+        #[serde(with = "serde_arrays")]
+        arr: [(); 100_000],
+    }
+
+    let obj = Many { arr: [(); 100_000] };
+
+    let j = serde_json::to_string(&obj).unwrap();
+    assert_eq!(obj, serde_json::from_str(&j).unwrap());
+
+    let b = bincode::serialize(&obj).unwrap();
+    assert!(b.is_empty());
+    assert_eq!(obj, bincode::deserialize(&b).unwrap());
+}